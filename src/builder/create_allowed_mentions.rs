@@ -0,0 +1,113 @@
+use internal::prelude::*;
+use model::{RoleId, UserId};
+
+/// A builder to specify which mentions in a message may notify users, for use
+/// with [`CreateMessage::allowed_mentions`].
+///
+/// By default, no mentions notify anyone. Mentions of `@everyone` and
+/// `@here` can be allowed through [`everyone`], while mentions of specific
+/// users and roles can be allowed through [`users`] and [`roles`].
+///
+/// # Examples
+///
+/// Echo a user's input, only allowing mentions of the user themselves:
+///
+/// ```rust,no_run
+/// # use serenity::model::{ChannelId, UserId};
+/// #
+/// # let (channel_id, user_id) = (ChannelId(7), UserId(8));
+/// #
+/// let _ = channel_id.send_message(|m| m
+///     .content("<@8> said: @everyone")
+///     .allowed_mentions(|am| am.users(vec![user_id])));
+/// ```
+///
+/// [`CreateMessage::allowed_mentions`]: struct.CreateMessage.html#method.allowed_mentions
+/// [`everyone`]: #method.everyone
+/// [`roles`]: #method.roles
+/// [`users`]: #method.users
+#[derive(Clone, Debug)]
+pub struct CreateAllowedMentions(pub JsonMap);
+
+impl CreateAllowedMentions {
+    /// Suppresses all mentions, removing anything that was previously
+    /// allowed.
+    pub fn empty(mut self) -> Self {
+        self.0.clear();
+        self.0.insert("parse".to_string(), Value::Array(vec![]));
+
+        self
+    }
+
+    /// Sets whether mentions of `@everyone` and `@here` notify users.
+    pub fn everyone(self, everyone: bool) -> Self { self.parse("everyone", everyone) }
+
+    /// Sets whether the author of the message being replied to is notified.
+    ///
+    /// Refer to [`CreateMessage::reference_message`] for replying to a
+    /// message.
+    ///
+    /// [`CreateMessage::reference_message`]: struct.CreateMessage.html#method.reference_message
+    pub fn replied_user(mut self, mention: bool) -> Self {
+        self.0
+            .insert("replied_user".to_string(), Value::Bool(mention));
+
+        self
+    }
+
+    /// Sets the roles whose mentions notify their members.
+    pub fn roles<R: Into<RoleId>, It: IntoIterator<Item=R>>(self, roles: It) -> Self {
+        let roles = roles
+            .into_iter()
+            .map(|role| Value::String(role.into().0.to_string()))
+            .collect();
+
+        self.ids("roles", roles)
+    }
+
+    /// Sets the users whose mentions notify them.
+    pub fn users<U: Into<UserId>, It: IntoIterator<Item=U>>(self, users: It) -> Self {
+        let users = users
+            .into_iter()
+            .map(|user| Value::String(user.into().0.to_string()))
+            .collect();
+
+        self.ids("users", users)
+    }
+
+    // Discord rejects a mention type being both parsed and given explicit
+    // Ids, so parsing of the type is disabled.
+    fn ids(mut self, kind: &str, ids: Vec<Value>) -> Self {
+        self = self.parse(kind, false);
+        self.0.insert(kind.to_string(), Value::Array(ids));
+
+        self
+    }
+
+    fn parse(mut self, kind: &str, allow: bool) -> Self {
+        let mut parse = match self.0.remove("parse") {
+            Some(Value::Array(parse)) => parse,
+            _ => vec![],
+        };
+
+        parse.retain(|value| value.as_str() != Some(kind));
+
+        if allow {
+            parse.push(Value::String(kind.to_string()));
+        }
+
+        self.0.insert("parse".to_string(), Value::Array(parse));
+
+        self
+    }
+}
+
+impl Default for CreateAllowedMentions {
+    /// Creates a builder that suppresses all mentions.
+    fn default() -> CreateAllowedMentions {
+        let mut map = Map::new();
+        map.insert("parse".to_string(), Value::Array(vec![]));
+
+        CreateAllowedMentions(map)
+    }
+}
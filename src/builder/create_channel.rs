@@ -0,0 +1,126 @@
+use internal::prelude::*;
+use model::PermissionOverwrite;
+use serde_json;
+
+/// A builder to create a [`GuildChannel`] for use via
+/// [`ChannelCategory::create_channel`].
+///
+/// The name and type of the channel are given to the method creating the
+/// channel, so all of the settings of the builder are optional.
+///
+/// # Examples
+///
+/// Create a voice channel in a category, limited to two users:
+///
+/// ```rust,ignore
+/// use serenity::model::ChannelType;
+///
+/// // assuming a category has already been bound
+/// let channel = category.create_channel("duo", ChannelType::Voice, |c| c
+///     .bitrate(64000)
+///     .user_limit(2))?;
+/// ```
+///
+/// [`ChannelCategory::create_channel`]: ../model/struct.ChannelCategory.html#method.create_channel
+/// [`GuildChannel`]: ../model/struct.GuildChannel.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateChannel(pub JsonMap);
+
+impl CreateChannel {
+    /// The bitrate of the channel in bits.
+    ///
+    /// This is for [voice] channels only.
+    ///
+    /// [voice]: ../model/enum.ChannelType.html#variant.Voice
+    pub fn bitrate(mut self, bitrate: u64) -> Self {
+        self.0
+            .insert("bitrate".to_string(), Value::Number(Number::from(bitrate)));
+
+        self
+    }
+
+    /// Whether the channel is age-restricted.
+    ///
+    /// This is for [text] channels only.
+    ///
+    /// [text]: ../model/enum.ChannelType.html#variant.Text
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.0.insert("nsfw".to_string(), Value::Bool(nsfw));
+
+        self
+    }
+
+    /// The permission overwrites of the channel.
+    ///
+    /// If this is not given, the channel is created with the overwrites of
+    /// its category.
+    pub fn permissions<It>(mut self, overwrites: It) -> Self
+        where It: IntoIterator<Item=PermissionOverwrite> {
+        // Serializing an overwrite does not fail.
+        let overwrites = overwrites
+            .into_iter()
+            .filter_map(|overwrite| serde_json::to_value(overwrite).ok())
+            .collect();
+
+        self.0
+            .insert("permission_overwrites".to_string(), Value::Array(overwrites));
+
+        self
+    }
+
+    /// The position of the channel in the channel list.
+    pub fn position(mut self, position: u64) -> Self {
+        self.0
+            .insert("position".to_string(), Value::Number(Number::from(position)));
+
+        self
+    }
+
+    /// The number of seconds a user has to wait between sending messages,
+    /// also known as slowmode. A value of `0` disables slowmode.
+    ///
+    /// This is for [text] channels only.
+    ///
+    /// **Note**: This field is capped to 21600 seconds (6 hours) due to a
+    /// Discord limitation. If a larger amount is supplied, it will be reduced.
+    ///
+    /// [text]: ../model/enum.ChannelType.html#variant.Text
+    pub fn slowmode(mut self, seconds: u64) -> Self {
+        let seconds = if seconds > 21_600 { 21_600 } else { seconds };
+
+        self.0.insert(
+            "rate_limit_per_user".to_string(),
+            Value::Number(Number::from(seconds)),
+        );
+
+        self
+    }
+
+    /// The topic of the channel.
+    ///
+    /// Must be between 0 and 1024 characters long.
+    ///
+    /// This is for [text] channels only.
+    ///
+    /// [text]: ../model/enum.ChannelType.html#variant.Text
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.0
+            .insert("topic".to_string(), Value::String(topic.to_string()));
+
+        self
+    }
+
+    /// The number of users that may be in the channel simultaneously.
+    ///
+    /// This is for [voice] channels only.
+    ///
+    /// [voice]: ../model/enum.ChannelType.html#variant.Voice
+    pub fn user_limit(mut self, user_limit: u64) -> Self {
+        self.0.insert(
+            "user_limit".to_string(),
+            Value::Number(Number::from(user_limit)),
+        );
+
+        self
+    }
+}
@@ -15,8 +15,8 @@
 //! [`ExecuteWebhook::embeds`]: struct.ExecuteWebhook.html#method.embeds
 //! [here]: https://discordapp.com/developers/docs/resources/channel#embed-object
 
-use chrono::{DateTime, TimeZone};
-use serde_json::Value;
+use chrono::{DateTime, NaiveDateTime, TimeZone};
+use serde_json::{self, Error as JsonError, Value};
 use std::default::Default;
 use std::fmt::Display;
 use internal::prelude::*;
@@ -65,6 +65,8 @@ impl CreateEmbed {
     pub fn color<C: Into<Colour>>(self, colour: C) -> Self { self.colour(colour.into()) }
 
     /// Set the colour of the left-hand side of the embed.
+    ///
+    /// A raw `u32`, such as `0xDEA584`, can be passed as well.
     #[cfg(feature = "utils")]
     pub fn colour<C: Into<Colour>>(mut self, colour: C) -> Self {
         self.0.insert(
@@ -108,16 +110,26 @@ impl CreateEmbed {
     /// Set a field. Note that this will not overwrite other fields, and will
     /// add to them.
     ///
-    /// Refer to the documentation for [`CreateEmbedField`] for more
-    /// information.
-    ///
     /// **Note**: Maximum amount of characters you can put is 256 in a field
-    /// name and 1024 in a field value and a field is inline by default.
+    /// name and 1024 in a field value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::Embed;
+    ///
+    /// let embed = Embed::fake(|e| e
+    ///     .field("Inlined", "Shown next to other inlined fields.", true)
+    ///     .field("Not inlined", "Shown on its own line.", false));
     ///
-    /// [`CreateEmbedField`]: struct.CreateEmbedField.html
-    pub fn field<F>(mut self, f: F) -> Self
-        where F: FnOnce(CreateEmbedField) -> CreateEmbedField {
-        let field = f(CreateEmbedField::default()).0;
+    /// assert_eq!(embed["fields"].as_array().unwrap().len(), 2);
+    /// ```
+    pub fn field<T: Display, U: Display>(mut self, name: T, value: U, inline: bool) -> Self {
+        let field = CreateEmbedField::default()
+            .name(name)
+            .value(value)
+            .inline(inline)
+            .0;
 
         {
             let key = "fields".to_string();
@@ -180,6 +192,101 @@ impl CreateEmbed {
         CreateEmbed(self.0)
     }
 
+    /// Creates a builder from a Discord-shaped embed object, such as an embed
+    /// template loaded from a file.
+    ///
+    /// Only the keys that the builder can set are read, and any other keys -
+    /// such as the `type` or a `provider` - are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate serde_json;
+    /// extern crate serenity;
+    ///
+    /// use serenity::builder::CreateEmbed;
+    ///
+    /// # fn main() {
+    /// let embed = CreateEmbed::from_json(json!({
+    ///     "title": "Rules",
+    ///     "fields": [{"name": "1.", "value": "Be nice."}],
+    /// })).unwrap();
+    ///
+    /// assert_eq!(embed.to_value()["fields"][0]["value"], "Be nice.");
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not an object, or if a key that is
+    /// read has an invalid value, such as a field without a `name`.
+    pub fn from_json(value: Value) -> StdResult<CreateEmbed, JsonError> {
+        let embed = serde_json::from_value::<EmbedTemplate>(value)?;
+        let mut b = CreateEmbed::default();
+
+        if let Some(author) = embed.author {
+            b = b.author(move |mut a| {
+                a = a.name(&author.name);
+
+                if let Some(icon_url) = author.icon_url {
+                    a = a.icon_url(&icon_url);
+                }
+
+                if let Some(url) = author.url {
+                    a = a.url(&url);
+                }
+
+                a
+            });
+        }
+
+        if let Some(colour) = embed.colour {
+            b = b.colour(colour);
+        }
+
+        if let Some(description) = embed.description {
+            b = b.description(&description);
+        }
+
+        for field in embed.fields {
+            b = b.field(field.name, field.value, field.inline);
+        }
+
+        if let Some(footer) = embed.footer {
+            b = b.footer(move |mut f| {
+                f = f.text(&footer.text);
+
+                if let Some(icon_url) = footer.icon_url {
+                    f = f.icon_url(&icon_url);
+                }
+
+                f
+            });
+        }
+
+        if let Some(image) = embed.image {
+            b = b.image(&image.url);
+        }
+
+        if let Some(timestamp) = embed.timestamp {
+            b = b.timestamp(timestamp);
+        }
+
+        if let Some(thumbnail) = embed.thumbnail {
+            b = b.thumbnail(&thumbnail.url);
+        }
+
+        if let Some(url) = embed.url {
+            b = b.url(&url);
+        }
+
+        if let Some(title) = embed.title {
+            b = b.title(&title);
+        }
+
+        Ok(b)
+    }
+
     /// Set the image associated with the embed. This only supports HTTP(S).
     pub fn image(mut self, url: &str) -> Self {
         let image = json!({
@@ -191,6 +298,35 @@ impl CreateEmbed {
         CreateEmbed(self.0)
     }
 
+    /// Counts the unicode code points of the embed's text in the way Discord
+    /// does for its limit of 6000 per embed: the title, description, field
+    /// names and values, footer text, and author name.
+    ///
+    /// Refer to [`Embed::total_length`] for the same count on a received
+    /// embed.
+    ///
+    /// [`Embed::total_length`]: ../model/struct.Embed.html#method.total_length
+    #[cfg_attr(feature = "cargo-clippy", allow(len_without_is_empty))]
+    pub fn len(&self) -> usize {
+        let count = |value: Option<&Value>| {
+            value
+                .and_then(Value::as_str)
+                .map_or(0, |text| text.chars().count())
+        };
+
+        let fields = match self.0.get("fields") {
+            Some(&Value::Array(ref fields)) => fields
+                .iter()
+                .map(|field| count(field.get("name")) + count(field.get("value")))
+                .sum::<usize>(),
+            _ => 0,
+        };
+        let author = self.0.get("author").map_or(0, |author| count(author.get("name")));
+        let footer = self.0.get("footer").map_or(0, |footer| count(footer.get("text")));
+
+        count(self.0.get("title")) + count(self.0.get("description")) + fields + author + footer
+    }
+
     /// Set the thumbnail of the embed. This only supports HTTP(S).
     pub fn thumbnail(mut self, url: &str) -> Self {
         let thumbnail = json!({
@@ -214,6 +350,11 @@ impl CreateEmbed {
     ///
     /// You can also pass anything that implements `chrono::TimeZone`.
     ///
+    /// **Note**: A string timestamp is not checked when it is set. Instead,
+    /// sending a message with an embed whose timestamp is not valid ISO-8601
+    /// returns a [`ModelError::EmbedTimestampInvalid`] before any request is
+    /// made. Use [`Timestamp::is_valid`] to check a timestamp beforehand.
+    ///
     /// # Examples
     ///
     /// Passing a string timestamp:
@@ -281,6 +422,9 @@ impl CreateEmbed {
     ///
     /// let mut client = Client::new("token", Handler); client.start().unwrap();
     /// ```
+    ///
+    /// [`ModelError::EmbedTimestampInvalid`]: ../model/enum.ModelError.html#variant.EmbedTimestampInvalid
+    /// [`Timestamp::is_valid`]: struct.Timestamp.html#method.is_valid
     pub fn timestamp<T: Into<Timestamp>>(mut self, timestamp: T) -> Self {
         self.0
             .insert("timestamp".to_string(), Value::String(timestamp.into().ts));
@@ -296,6 +440,11 @@ impl CreateEmbed {
         CreateEmbed(self.0)
     }
 
+    /// Returns the embed object that the builder has assembled, as it is sent
+    /// to Discord.
+    #[inline]
+    pub fn to_value(&self) -> Value { Value::Object(self.0.clone()) }
+
     /// Set the URL to direct to when clicking on the title.
     pub fn url(mut self, url: &str) -> Self {
         self.0
@@ -326,9 +475,11 @@ impl Default for CreateEmbed {
 }
 
 impl From<Embed> for CreateEmbed {
-    /// Converts the fields of an embed into the values for a new embed builder.
+    /// Converts the fields of an embed into the values for a new embed builder,
+    /// such as for re-sending a received embed.
     ///
-    /// Some values - such as Proxy URLs - are not preserved.
+    /// Values that Discord computes rather than accepts - such as Proxy URLs,
+    /// the kind, the provider, and the video - are not preserved.
     fn from(embed: Embed) -> CreateEmbed {
         let mut b = CreateEmbed::default().colour(embed.colour);
 
@@ -353,8 +504,18 @@ impl From<Embed> for CreateEmbed {
         }
 
         for field in embed.fields {
-            b = b.field(move |f| {
-                f.inline(field.inline).name(&field.name).value(&field.value)
+            b = b.field(field.name, field.value, field.inline);
+        }
+
+        if let Some(footer) = embed.footer {
+            b = b.footer(move |mut f| {
+                f = f.text(&footer.text);
+
+                if let Some(icon_url) = footer.icon_url {
+                    f = f.icon_url(&icon_url);
+                }
+
+                f
             });
         }
 
@@ -382,6 +543,51 @@ impl From<Embed> for CreateEmbed {
     }
 }
 
+// The parts of an embed object read by `CreateEmbed::from_json`. Unlike
+// `Embed`, values that Discord computes - such as proxy URLs - are not
+// required.
+#[derive(Deserialize)]
+struct EmbedTemplate {
+    author: Option<EmbedAuthorTemplate>,
+    #[serde(rename = "color")]
+    colour: Option<u32>,
+    description: Option<String>,
+    #[serde(default)]
+    fields: Vec<EmbedFieldTemplate>,
+    footer: Option<EmbedFooterTemplate>,
+    image: Option<EmbedUrlTemplate>,
+    thumbnail: Option<EmbedUrlTemplate>,
+    timestamp: Option<String>,
+    title: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct EmbedAuthorTemplate {
+    icon_url: Option<String>,
+    name: String,
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct EmbedFieldTemplate {
+    #[serde(default)]
+    inline: bool,
+    name: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct EmbedFooterTemplate {
+    icon_url: Option<String>,
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct EmbedUrlTemplate {
+    url: String,
+}
+
 /// A builder to create a fake [`Embed`] object's author, for use with the
 /// [`CreateEmbed::author`] method.
 ///
@@ -395,6 +601,12 @@ pub struct CreateEmbedAuthor(pub Map<String, Value>);
 
 impl CreateEmbedAuthor {
     /// Set the URL of the author's icon.
+    ///
+    /// This must be an `http(s)://` URL, or an `attachment://filename` URL
+    /// referencing a file sent alongside the embed. Other URLs are rejected
+    /// with a [`ModelError::InvalidUrl`] when the message is sent.
+    ///
+    /// [`ModelError::InvalidUrl`]: ../model/enum.ModelError.html#variant.InvalidUrl
     pub fn icon_url(mut self, icon_url: &str) -> Self {
         self.0
             .insert("icon_url".to_string(), Value::String(icon_url.to_string()));
@@ -420,13 +632,17 @@ impl CreateEmbedAuthor {
 }
 
 /// A builder to create a fake [`Embed`] object's field, for use with the
-/// [`CreateEmbed::field`] method.
+/// [`CreateEmbed::fields`] method.
 ///
 /// This does not require any field be set. `inline` is set to `true` by
 /// default.
 ///
-/// [`Embed`]: ../model/struct.Embed.html
+/// A single field can be added more simply through [`CreateEmbed::field`],
+/// which takes the name, value, and inline indicator directly.
+///
 /// [`CreateEmbed::field`]: struct.CreateEmbed.html#method.field
+/// [`CreateEmbed::fields`]: struct.CreateEmbed.html#method.fields
+/// [`Embed`]: ../model/struct.Embed.html
 #[derive(Clone, Debug)]
 pub struct CreateEmbedField(pub Map<String, Value>);
 
@@ -477,7 +693,13 @@ impl Default for CreateEmbedField {
 pub struct CreateEmbedFooter(pub Map<String, Value>);
 
 impl CreateEmbedFooter {
-    /// Set the icon URL's value. This only supports HTTP(S).
+    /// Set the icon URL's value.
+    ///
+    /// This must be an `http(s)://` URL, or an `attachment://filename` URL
+    /// referencing a file sent alongside the embed. Other URLs are rejected
+    /// with a [`ModelError::InvalidUrl`] when the message is sent.
+    ///
+    /// [`ModelError::InvalidUrl`]: ../model/enum.ModelError.html#variant.InvalidUrl
     pub fn icon_url(mut self, icon_url: &str) -> Self {
         self.0
             .insert("icon_url".to_string(), Value::String(icon_url.to_string()));
@@ -494,11 +716,36 @@ impl CreateEmbedFooter {
     }
 }
 
+/// A timestamp for an embed, created from either a string or a
+/// `chrono::DateTime`.
+///
+/// Refer to [`CreateEmbed::timestamp`] for more information.
+///
+/// [`CreateEmbed::timestamp`]: struct.CreateEmbed.html#method.timestamp
 #[derive(Clone, Debug)]
 pub struct Timestamp {
     pub ts: String,
 }
 
+impl Timestamp {
+    /// Whether the timestamp is valid ISO-8601, either with an offset - such
+    /// as `2004-06-08T16:04:23+00:00` - or without one, in which case it is
+    /// taken as UTC - such as `2004-06-08T16:04:23`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::Timestamp;
+    ///
+    /// assert!(Timestamp::from("2004-06-08T16:04:23").is_valid());
+    /// assert!(!Timestamp::from("yesterday").is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        DateTime::parse_from_rfc3339(&self.ts).is_ok()
+            || NaiveDateTime::parse_from_str(&self.ts, "%Y-%m-%dT%H:%M:%S%.f").is_ok()
+    }
+}
+
 impl From<String> for Timestamp {
     fn from(ts: String) -> Self {
         Timestamp {
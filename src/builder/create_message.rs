@@ -1,8 +1,11 @@
-use super::CreateEmbed;
-use model::ReactionType;
+use super::{CreateAllowedMentions, CreateEmbed};
+use model::{MessageId, ReactionType};
 use internal::prelude::*;
 use std::fmt::Display;
 
+#[cfg(feature = "model")]
+use model::{Message, ModelError};
+
 /// A builder to specify the contents of an [`http::send_message`] request,
 /// primarily meant for use through [`ChannelId::send_message`].
 ///
@@ -42,9 +45,39 @@ use std::fmt::Display;
 pub struct CreateMessage(pub Map<String, Value>, pub Option<Vec<ReactionType>>);
 
 impl CreateMessage {
+    /// Set which mentions in the message may notify users.
+    ///
+    /// If this is not called, Discord's default parsing of mentions is used.
+    /// Refer to [`CreateAllowedMentions`] for more information.
+    ///
+    /// [`CreateAllowedMentions`]: struct.CreateAllowedMentions.html
+    pub fn allowed_mentions<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateAllowedMentions) -> CreateAllowedMentions {
+        let mut allowed_mentions = f(CreateAllowedMentions::default()).0;
+
+        // Keep the toggle set through `mention_reply`, unless overridden.
+        if let Some(&Value::Object(ref existing)) = self.0.get("allowed_mentions") {
+            if let Some(replied_user) = existing.get("replied_user") {
+                allowed_mentions
+                    .entry("replied_user")
+                    .or_insert_with(|| replied_user.clone());
+            }
+        }
+
+        self.0
+            .insert("allowed_mentions".to_string(), Value::Object(allowed_mentions));
+
+        CreateMessage(self.0, self.1)
+    }
+
     /// Set the content of the message.
     ///
+    /// This replaces any content previously set, including lines appended
+    /// through [`content_line`].
+    ///
     /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// [`content_line`]: #method.content_line
     pub fn content<D: Display>(mut self, content: D) -> Self {
         self.0
             .insert("content".to_string(), Value::String(format!("{}", content)));
@@ -52,12 +85,136 @@ impl CreateMessage {
         CreateMessage(self.0, self.1)
     }
 
-    /// Set an embed for the message.
+    /// Append a line to the content of the message, separating it from any
+    /// existing content with a newline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::CreateMessage;
+    ///
+    /// let message = CreateMessage::default()
+    ///     .content_line("Results:")
+    ///     .content_line("1. Ferris");
+    ///
+    /// assert_eq!(message.0["content"], "Results:\n1. Ferris");
+    /// ```
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points,
+    /// which is checked against the full content when the message is sent.
+    pub fn content_line<S: AsRef<str>>(mut self, line: S) -> Self {
+        let content = match self.0.remove("content") {
+            Some(Value::String(mut content)) => {
+                content.push('\n');
+                content.push_str(line.as_ref());
+
+                content
+            },
+            _ => line.as_ref().to_string(),
+        };
+
+        self.0.insert("content".to_string(), Value::String(content));
+
+        CreateMessage(self.0, self.1)
+    }
+
+    /// Add an embed to the message.
+    ///
+    /// This can be called multiple times to add multiple embeds. Refer to
+    /// [`embeds`] to set all of the message's embeds at once.
+    ///
+    /// [`embeds`]: #method.embeds
     pub fn embed<F>(mut self, f: F) -> Self
         where F: FnOnce(CreateEmbed) -> CreateEmbed {
         let embed = Value::Object(f(CreateEmbed::default()).0);
 
-        self.0.insert("embed".to_string(), embed);
+        {
+            let embeds = self.0
+                .entry("embeds")
+                .or_insert_with(|| Value::Array(vec![]));
+
+            if let Value::Array(ref mut embeds) = *embeds {
+                embeds.push(embed);
+            }
+        }
+
+        CreateMessage(self.0, self.1)
+    }
+
+    /// Set the embeds of the message, replacing any previously added
+    /// through [`embed`].
+    ///
+    /// When editing a message, passing no embeds removes all of the message's
+    /// embeds.
+    ///
+    /// # Examples
+    ///
+    /// Remove the embeds of a message:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::{ChannelId, MessageId};
+    ///
+    /// let _ = ChannelId(7).edit_message(MessageId(8), |m| m.embeds(vec![]));
+    /// ```
+    ///
+    /// [`embed`]: #method.embed
+    pub fn embeds<It: IntoIterator<Item=CreateEmbed>>(mut self, embeds: It) -> Self {
+        let embeds = embeds
+            .into_iter()
+            .map(|embed| Value::Object(embed.0))
+            .collect();
+
+        self.0.insert("embeds".to_string(), Value::Array(embeds));
+
+        CreateMessage(self.0, self.1)
+    }
+
+    /// Set whether the author of the message being replied to through
+    /// [`reference_message`] is mentioned.
+    ///
+    /// If [`allowed_mentions`] has not been called, Discord's default
+    /// parsing of the other mentions is kept.
+    ///
+    /// [`allowed_mentions`]: #method.allowed_mentions
+    /// [`reference_message`]: #method.reference_message
+    pub fn mention_reply(mut self, mention: bool) -> Self {
+        let entry = self.0
+            .entry("allowed_mentions")
+            .or_insert_with(|| json!({"parse": ["everyone", "roles", "users"]}));
+
+        if let Value::Object(ref mut allowed_mentions) = *entry {
+            allowed_mentions.insert("replied_user".to_string(), Value::Bool(mention));
+        }
+
+        CreateMessage(self.0, self.1)
+    }
+
+    /// Set a nonce for the message, which is sent back in the
+    /// [`Message::nonce`] of the message received over the gateway.
+    ///
+    /// This can be used to tell whether a received message is one that was
+    /// sent by this builder.
+    ///
+    /// [`Message::nonce`]: ../model/struct.Message.html#structfield.nonce
+    pub fn nonce<S: Into<String>>(mut self, nonce: S) -> Self {
+        self.0
+            .insert("nonce".to_string(), Value::String(nonce.into()));
+
+        CreateMessage(self.0, self.1)
+    }
+
+    /// Set the message that this message replies to, given its Id.
+    ///
+    /// The referenced message must be in the channel that the message is sent
+    /// to. Whether the author of the referenced message is mentioned can be
+    /// set through [`mention_reply`].
+    ///
+    /// [`mention_reply`]: #method.mention_reply
+    pub fn reference_message<M: Into<MessageId>>(mut self, message_id: M) -> Self {
+        self.0.insert(
+            "message_reference".to_string(),
+            json!({"message_id": message_id.into().0}),
+        );
 
         CreateMessage(self.0, self.1)
     }
@@ -67,6 +224,11 @@ impl CreateMessage {
     /// Think carefully before setting this to `true`.
     ///
     /// Defaults to `false`.
+    ///
+    /// **Note**: Sending a text-to-speech message requires the [Send TTS
+    /// Messages] permission.
+    ///
+    /// [Send TTS Messages]: ../model/permissions/constant.SEND_TTS_MESSAGES.html
     pub fn tts(mut self, tts: bool) -> Self {
         self.0.insert("tts".to_string(), Value::Bool(tts));
 
@@ -79,6 +241,98 @@ impl CreateMessage {
 
         CreateMessage(self.0, self.1)
     }
+
+    /// Checks the message for every problem that would cause sending it to
+    /// fail, such as content that is too long or an embed that is too large.
+    ///
+    /// Unlike [`ChannelId::send_message`], which fails on the first problem,
+    /// all of the problems are collected and returned together.
+    ///
+    /// **Note**: Files are not part of the builder, and so are not checked.
+    /// Use [`validate_with_files`] to also check the number of files to send.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::CreateMessage;
+    ///
+    /// let message = CreateMessage::default().content("a".repeat(2001));
+    ///
+    /// assert_eq!(message.validate().unwrap_err().len(), 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::AllowedMentionsConflict`] if the allowed
+    /// mentions both parse and list a type of mention, a
+    /// [`ModelError::MessageTooLong`] if the content is too long,
+    /// a [`ModelError::EmbedTooLarge`] if the embeds are too large in total or
+    /// a part of an embed - such as its title - is too long, a
+    /// [`ModelError::EmbedTimestampInvalid`] if an embed's timestamp is not
+    /// valid ISO-8601, and a
+    /// [`ModelError::InvalidUrl`] if the icon of an embed's author or footer
+    /// is not an `http(s)://` or `attachment://` URL.
+    ///
+    /// [`ChannelId::send_message`]: ../model/struct.ChannelId.html#method.send_message
+    /// [`ModelError::AllowedMentionsConflict`]: ../model/enum.ModelError.html#variant.AllowedMentionsConflict
+    /// [`ModelError::EmbedTimestampInvalid`]: ../model/enum.ModelError.html#variant.EmbedTimestampInvalid
+    /// [`ModelError::EmbedTooLarge`]: ../model/enum.ModelError.html#variant.EmbedTooLarge
+    /// [`ModelError::InvalidUrl`]: ../model/enum.ModelError.html#variant.InvalidUrl
+    /// [`ModelError::MessageTooLong`]: ../model/enum.ModelError.html#variant.MessageTooLong
+    /// [`validate_with_files`]: #method.validate_with_files
+    #[cfg(feature = "model")]
+    pub fn validate(&self) -> StdResult<(), Vec<ModelError>> { self.validate_with_files(0) }
+
+    /// Checks the message like [`validate`], and additionally checks that the
+    /// given number of files to send along with it is within Discord's limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::CreateMessage;
+    ///
+    /// let message = CreateMessage::default().content("some files");
+    ///
+    /// assert!(message.validate_with_files(10).is_ok());
+    /// assert!(message.validate_with_files(11).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`validate`], as well as a
+    /// [`ModelError::TooManyFiles`] if there are too many files.
+    ///
+    /// [`ModelError::TooManyFiles`]: ../model/enum.ModelError.html#variant.TooManyFiles
+    /// [`validate`]: #method.validate
+    #[cfg(feature = "model")]
+    pub fn validate_with_files(&self, files: usize) -> StdResult<(), Vec<ModelError>> {
+        let checks: [fn(&JsonMap) -> Result<()>; 6] = [
+            Message::check_allowed_mentions,
+            Message::check_content_length,
+            Message::check_embed_length,
+            Message::check_embed_parts,
+            Message::check_embed_timestamp,
+            Message::check_embed_urls,
+        ];
+
+        let mut errors = checks
+            .iter()
+            .filter_map(|check| match check(&self.0) {
+                Err(Error::Model(why)) => Some(why),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if let Err(Error::Model(why)) = Message::check_file_count(files) {
+            errors.push(why);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 impl Default for CreateMessage {
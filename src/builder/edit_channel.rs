@@ -1,4 +1,6 @@
 use internal::prelude::*;
+use model::{ChannelId, PermissionOverwrite};
+use serde_json;
 
 /// A builder to edit a [`GuildChannel`] for use via [`GuildChannel::edit`]
 ///
@@ -33,6 +35,19 @@ impl EditChannel {
         self
     }
 
+    /// The category that the channel is in, or `None` to remove the channel
+    /// from its category.
+    pub fn category<C: Into<Option<ChannelId>>>(mut self, category: C) -> Self {
+        let category = match category.into() {
+            Some(category) => Value::String(category.0.to_string()),
+            None => Value::Null,
+        };
+
+        self.0.insert("parent_id".to_string(), category);
+
+        self
+    }
+
     /// The name of the channel.
     ///
     /// Must be between 2 and 100 characters long.
@@ -43,6 +58,44 @@ impl EditChannel {
         self
     }
 
+    /// Whether the channel is age-restricted.
+    ///
+    /// This is for [text] channels only.
+    ///
+    /// [text]: ../model/enum.ChannelType.html#variant.Text
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.0.insert("nsfw".to_string(), Value::Bool(nsfw));
+
+        self
+    }
+
+    /// The permission overwrites of the channel, replacing all of its
+    /// existing overwrites.
+    ///
+    /// # Examples
+    ///
+    /// Reset a channel's overwrites to those of its category:
+    ///
+    /// ```rust,ignore
+    /// // assuming a channel and its category have already been bound
+    /// let overwrites = category.permission_overwrites.clone();
+    ///
+    /// channel.edit(|c| c.permissions(overwrites));
+    /// ```
+    pub fn permissions<It>(mut self, overwrites: It) -> Self
+        where It: IntoIterator<Item=PermissionOverwrite> {
+        // Serializing an overwrite does not fail.
+        let overwrites = overwrites
+            .into_iter()
+            .filter_map(|overwrite| serde_json::to_value(overwrite).ok())
+            .collect();
+
+        self.0
+            .insert("permission_overwrites".to_string(), Value::Array(overwrites));
+
+        self
+    }
+
     /// The position of the channel in the channel list.
     pub fn position(mut self, position: u64) -> Self {
         self.0
@@ -51,6 +104,26 @@ impl EditChannel {
         self
     }
 
+    /// The number of seconds a user has to wait between sending messages,
+    /// also known as slowmode. A value of `0` disables slowmode.
+    ///
+    /// This is for [text] channels only.
+    ///
+    /// **Note**: This field is capped to 21600 seconds (6 hours) due to a
+    /// Discord limitation. If a larger amount is supplied, it will be reduced.
+    ///
+    /// [text]: ../model/enum.ChannelType.html#variant.Text
+    pub fn slowmode(mut self, seconds: u64) -> Self {
+        let seconds = if seconds > 21_600 { 21_600 } else { seconds };
+
+        self.0.insert(
+            "rate_limit_per_user".to_string(),
+            Value::Number(Number::from(seconds)),
+        );
+
+        self
+    }
+
     /// The topic of the channel. Can be empty.
     ///
     /// Must be between 0 and 1024 characters long.
@@ -1,5 +1,6 @@
 use serde_json::Value;
 use std::default::Default;
+use super::CreateEmbed;
 use internal::prelude::*;
 
 /// A builder to create the inner content of a [`Webhook`]'s execution.
@@ -35,14 +36,16 @@ use internal::prelude::*;
 ///     .title("Rust Resources")
 ///     .description("A few resources to help with learning Rust")
 ///     .colour(0xDEA584)
-///     .field(|f| f
-///         .inline(false)
-///         .name("The Rust Book")
-///         .value("A comprehensive resource for all topics related to Rust"))
-///     .field(|f| f
-///         .inline(false)
-///         .name("Rust by Example")
-///         .value("A collection of Rust examples on topics, useable in-browser")));
+///     .field(
+///         "The Rust Book",
+///         "A comprehensive resource for all topics related to Rust",
+///         false,
+///     )
+///     .field(
+///         "Rust by Example",
+///         "A collection of Rust examples on topics, useable in-browser",
+///         false,
+///     ));
 ///
 /// let _ = webhook.execute(false, |w| w
 ///     .content("Here's some information on Rust:")
@@ -109,6 +112,44 @@ impl ExecuteWebhook {
         self
     }
 
+    /// Add an embed to the message, built through a [`CreateEmbed`].
+    ///
+    /// This can be called multiple times to send multiple embeds, and may be
+    /// combined with [`embeds`].
+    ///
+    /// # Examples
+    ///
+    /// Sending a webhook with two embeds:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http;
+    /// #
+    /// # let webhook = http::get_webhook_with_token(0, "").unwrap();
+    /// #
+    /// let _ = webhook.execute(false, |w| w
+    ///     .embed(|e| e.title("First"))
+    ///     .embed(|e| e.title("Second")));
+    /// ```
+    ///
+    /// [`CreateEmbed`]: struct.CreateEmbed.html
+    /// [`embeds`]: #method.embeds
+    pub fn embed<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateEmbed) -> CreateEmbed {
+        let embed = Value::Object(f(CreateEmbed::default()).0);
+
+        {
+            let embeds = self.0
+                .entry("embeds")
+                .or_insert_with(|| Value::Array(vec![]));
+
+            if let Value::Array(ref mut embeds) = *embeds {
+                embeds.push(embed);
+            }
+        }
+
+        self
+    }
+
     /// Set the embeds associated with the message.
     ///
     /// This should be used in combination with [`Embed::fake`], creating one
@@ -1,5 +1,6 @@
 use std::collections::BTreeMap;
-use model::MessageId;
+use model::{MessageId, ModelError};
+use internal::prelude::*;
 
 /// Builds a request for a request to the API to retrieve messages.
 ///
@@ -11,8 +12,9 @@ use model::MessageId;
 /// - `before`
 /// - `most_recent`
 ///
-/// These can not be mixed, and the first in the list alphabetically will be
-/// used. If one is not specified, `most_recent` will be used.
+/// These can not be mixed: setting one of them replaces any that was
+/// previously set, so the most recently set one is used. If one is not
+/// specified, `most_recent` will be used.
 ///
 /// The fourth parameter is to specify the number of messages to retrieve. This
 /// does not _need_ to be called and defaults to a value of 50.
@@ -55,48 +57,131 @@ pub struct GetMessages(pub BTreeMap<String, u64>);
 impl GetMessages {
     /// Indicates to retrieve the messages after a specific message, given by
     /// its Id.
-    pub fn after<M: Into<MessageId>>(&mut self, message_id: M) -> &mut Self {
-        self.0.insert("after".to_string(), message_id.into().0);
-
-        self
+    ///
+    /// This replaces any previously set `around` or `before`.
+    pub fn after<M: Into<MessageId>>(self, message_id: M) -> Self {
+        self.anchor("after", message_id.into())
     }
 
     /// Indicates to retrieve the messages _around_ a specific message in either
     /// direction (before+after) the given message.
-    pub fn around<M: Into<MessageId>>(&mut self, message_id: M) -> &mut Self {
-        self.0.insert("around".to_string(), message_id.into().0);
-
-        self
+    ///
+    /// This replaces any previously set `after` or `before`.
+    pub fn around<M: Into<MessageId>>(self, message_id: M) -> Self {
+        self.anchor("around", message_id.into())
     }
 
     /// Indicates to retrieve the messages before a specific message, given by
     /// its Id.
-    pub fn before<M: Into<MessageId>>(&mut self, message_id: M) -> &mut Self {
-        self.0.insert("before".to_string(), message_id.into().0);
+    ///
+    /// This replaces any previously set `after` or `around`.
+    pub fn before<M: Into<MessageId>>(self, message_id: M) -> Self {
+        self.anchor("before", message_id.into())
+    }
+
+    /// Removes any `after`, `around`, or `before` that was set, keeping the
+    /// `limit`.
+    ///
+    /// This is the same as [`most_recent`], and is useful when reusing a
+    /// builder.
+    ///
+    /// [`most_recent`]: #method.most_recent
+    pub fn clear_anchor(mut self) -> Self {
+        for key in &ANCHORS {
+            self.0.remove(*key);
+        }
 
         self
     }
 
+    /// Retrieves which of `after`, `around`, or `before` is set, along with
+    /// the Id of the message it was set to.
+    ///
+    /// Returns `None` if none is set, in which case the most recent messages
+    /// are retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::GetMessages;
+    /// use serenity::model::MessageId;
+    ///
+    /// let builder = GetMessages::default().before(MessageId(7));
+    ///
+    /// assert_eq!(builder.get_anchor(), Some(("before", MessageId(7))));
+    /// ```
+    pub fn get_anchor(&self) -> Option<(&'static str, MessageId)> {
+        ANCHORS
+            .iter()
+            .filter_map(|key| self.0.get(*key).map(|id| (*key, MessageId(*id))))
+            .next()
+    }
+
+    /// Retrieves the number of messages that will be retrieved, which is 50
+    /// if no [`limit`] was set.
+    ///
+    /// [`limit`]: #method.limit
+    pub fn get_limit(&self) -> u64 { self.0.get("limit").cloned().unwrap_or(50) }
+
     /// The maximum number of messages to retrieve for the query.
     ///
     /// If this is not specified, a default value of 50 is used.
     ///
     /// **Note**: This field is capped to 100 messages due to a Discord
     /// limitation. If an amount larger than 100 is supplied, it will be
-    /// reduced.
-    pub fn limit(&mut self, limit: u64) -> &mut Self {
+    /// reduced. Use [`limit_checked`] to be given an error instead.
+    ///
+    /// [`limit_checked`]: #method.limit_checked
+    pub fn limit(mut self, limit: u64) -> Self {
         self.0
             .insert("limit".to_string(), if limit > 100 { 100 } else { limit });
 
         self
     }
 
+    /// The maximum number of messages to retrieve for the query, like
+    /// [`limit`], but without reducing amounts larger than 100.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::GetMessages;
+    /// use serenity::model::ModelError;
+    ///
+    /// assert!(GetMessages::default().limit_checked(25).is_ok());
+    /// assert_eq!(
+    ///     GetMessages::default().limit_checked(101).unwrap_err(),
+    ///     ModelError::MessageLimitAmount(101)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageLimitAmount`] if the amount is `0` or
+    /// over 100.
+    ///
+    /// [`ModelError::MessageLimitAmount`]: ../model/enum.ModelError.html#variant.MessageLimitAmount
+    /// [`limit`]: #method.limit
+    pub fn limit_checked(self, limit: u64) -> StdResult<Self, ModelError> {
+        if limit == 0 || limit > 100 {
+            return Err(ModelError::MessageLimitAmount(limit));
+        }
+
+        Ok(self.limit(limit))
+    }
+
     /// This is a function that is here for completeness. You do not need to
     /// call this - except to clear previous calls to `after`, `around`, and
     /// `before` - as it is the default value.
-    pub fn most_recent(self) -> Self { self }
+    #[inline]
+    pub fn most_recent(self) -> Self { self.clear_anchor() }
 
-    pub fn build(&self) -> Self {
-        self.clone()
+    fn anchor(self, key: &str, message_id: MessageId) -> Self {
+        let mut builder = self.clear_anchor();
+        builder.0.insert(key.to_string(), message_id.0);
+
+        builder
     }
 }
+
+const ANCHORS: [&'static str; 3] = ["after", "around", "before"];
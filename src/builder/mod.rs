@@ -5,6 +5,8 @@
 //! optional, and/or sane default values for required parameters can be applied
 //! by a builder.
 
+mod create_allowed_mentions;
+mod create_channel;
 mod create_embed;
 mod create_invite;
 mod create_message;
@@ -16,7 +18,15 @@ mod edit_role;
 mod execute_webhook;
 mod get_messages;
 
-pub use self::create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedField, CreateEmbedFooter};
+pub use self::create_allowed_mentions::CreateAllowedMentions;
+pub use self::create_channel::CreateChannel;
+pub use self::create_embed::{
+    CreateEmbed,
+    CreateEmbedAuthor,
+    CreateEmbedField,
+    CreateEmbedFooter,
+    Timestamp,
+};
 pub use self::create_invite::CreateInvite;
 pub use self::create_message::CreateMessage;
 pub use self::edit_channel::EditChannel;
@@ -42,7 +42,7 @@
 //! [`CACHE`]: ../struct.CACHE.html
 //! [`http`]: ../http/index.html
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::default::Default;
 use std::sync::{Arc, RwLock};
 use model::*;
@@ -90,6 +90,17 @@ pub struct Cache {
     /// [`Emoji`]: ../model/struct.Emoji.html
     /// [`Role`]: ../model/struct.Role.html
     pub guilds: HashMap<GuildId, Arc<RwLock<Guild>>>,
+    /// A map of the most recent messages received in each channel, from
+    /// oldest to newest.
+    ///
+    /// Messages are added when a [`MessageCreateEvent`] is received, and
+    /// updated or removed by the respective update and delete events. Only
+    /// the latest [`MESSAGE_CACHE_LIMIT`] messages of each channel are kept,
+    /// and messages sent before the cache was started are never present.
+    ///
+    /// [`MESSAGE_CACHE_LIMIT`]: ../constants/constant.MESSAGE_CACHE_LIMIT.html
+    /// [`MessageCreateEvent`]: ../model/event/struct.MessageCreateEvent.html
+    pub messages: HashMap<ChannelId, VecDeque<Message>>,
     /// A map of notes that a user has made for individual users.
     ///
     /// An empty note is equivalent to having no note, and creating an empty
@@ -153,6 +164,15 @@ pub struct Cache {
     /// [`PresenceUpdateEvent`]: ../model/event/struct.PresenceUpdateEvent.html
     /// [`ReadyEvent`]: ../model/event/struct.ReadyEvent.html
     pub users: HashMap<UserId, Arc<RwLock<User>>>,
+    /// A map of the voice states of users connected to calls outside of
+    /// guilds, such as in [`Group`]s, keyed by the Id of the user.
+    ///
+    /// Voice states of users in guilds are stored in the guild's
+    /// [`voice_states`][`Guild::voice_states`] instead.
+    ///
+    /// [`Group`]: ../model/struct.Group.html
+    /// [`Guild::voice_states`]: ../model/struct.Guild.html#structfield.voice_states
+    pub voice_states: HashMap<UserId, VoiceState>,
 }
 
 impl Cache {
@@ -521,6 +541,32 @@ impl Cache {
         self.private_channels.get(&channel_id.into()).cloned()
     }
 
+    /// Removes a channel from the cache: from the channel map and its guild's
+    /// channels, the categories, the private channels, or the groups,
+    /// whichever holds it, along with its cached messages.
+    ///
+    /// This is used when a channel is deleted, both by the
+    /// [`ChannelDeleteEvent`] and by [`ChannelId::delete`].
+    ///
+    /// [`ChannelDeleteEvent`]: ../model/event/struct.ChannelDeleteEvent.html
+    /// [`ChannelId::delete`]: ../model/struct.ChannelId.html#method.delete
+    pub fn remove_channel<C: Into<ChannelId>>(&mut self, channel_id: C) {
+        let channel_id = channel_id.into();
+
+        if let Some(channel) = self.channels.remove(&channel_id) {
+            let guild_id = channel.read().unwrap().guild_id;
+
+            if let Some(guild) = self.guilds.get(&guild_id) {
+                guild.write().unwrap().channels.remove(&channel_id);
+            }
+        }
+
+        self.categories.remove(&channel_id);
+        self.private_channels.remove(&channel_id);
+        self.groups.remove(&channel_id);
+        self.messages.remove(&channel_id);
+    }
+
     /// Retrieves a [`Guild`]'s role by their Ids.
     ///
     /// **Note**: This will clone the entire role. Instead, retrieve the guild
@@ -624,6 +670,7 @@ impl Default for Cache {
             categories: HashMap::default(),
             groups: HashMap::with_capacity(128),
             guilds: HashMap::default(),
+            messages: HashMap::default(),
             notes: HashMap::default(),
             presences: HashMap::default(),
             private_channels: HashMap::with_capacity(128),
@@ -631,6 +678,7 @@ impl Default for Cache {
             unavailable_guilds: HashSet::default(),
             user: CurrentUser::default(),
             users: HashMap::default(),
+            voice_states: HashMap::default(),
         }
     }
 }
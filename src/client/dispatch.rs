@@ -49,6 +49,10 @@ pub fn dispatch<H: EventHandler + 'static>(event: Event,
                                            event_handler: Arc<H>) {
     match event {
         Event::MessageCreate(event) => {
+            #[cfg(feature = "cache")]
+            let mut event = event;
+            update!(event);
+
             let context = context(conn, data);
             dispatch_message(
                 context.clone(),
@@ -71,6 +75,10 @@ pub fn dispatch<H: EventHandler + 'static>(event: Event,
                                            event_handler: Arc<H>) {
     match event {
         Event::MessageCreate(event) => {
+            #[cfg(feature = "cache")]
+            let mut event = event;
+            update!(event);
+
             let context = context(conn, data);
             dispatch_message(context, event.message, event_handler);
         },
@@ -78,16 +86,18 @@ pub fn dispatch<H: EventHandler + 'static>(event: Event,
     }
 }
 
-#[allow(unused_mut)]
 fn dispatch_message<H>(
     context: Context,
-    mut message: Message,
+    message: Message,
     event_handler: Arc<H>
 ) where H: EventHandler + 'static {
     #[cfg(feature = "model")]
-    {
+    let message = {
+        let mut message = message;
         message.transform_content();
-    }
+
+        message
+    };
 
     event_handler.on_message(context, message);
 }
@@ -345,16 +355,22 @@ fn handle_event<H: EventHandler + 'static>(event: Event,
         // Already handled by the framework check macro
         Event::MessageCreate(_) => {},
         Event::MessageDeleteBulk(mut event) => {
+            update!(event);
+
             let context = context(conn, data);
 
             event_handler.on_message_delete_bulk(context, event.channel_id, event.ids);
         },
         Event::MessageDelete(mut event) => {
+            update!(event);
+
             let context = context(conn, data);
 
             event_handler.on_message_delete(context, event.channel_id, event.message_id);
         },
         Event::MessageUpdate(mut event) => {
+            update!(event);
+
             let context = context(conn, data);
 
             event_handler.on_message_update(context, event);
@@ -1,14 +1,47 @@
 //! A set of constants used by the library.
 
+/// The maximum unicode code points allowed within a channel's topic.
+pub const CHANNEL_TOPIC_LENGTH: u16 = 1024;
+/// The Discord epoch - the first second of 2015 - in milliseconds since the
+/// Unix epoch, from which the timestamps of snowflake Ids are counted.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+/// The maximum unicode code points allowed within an embed's author name.
+pub const EMBED_AUTHOR_NAME_LENGTH: u16 = 256;
+/// The maximum unicode code points allowed within an embed's description.
+pub const EMBED_DESCRIPTION_LENGTH: u16 = 4096;
+/// The maximum unicode code points allowed within the name of an embed's
+/// field.
+pub const EMBED_FIELD_NAME_LENGTH: u16 = 256;
+/// The maximum unicode code points allowed within the value of an embed's
+/// field.
+pub const EMBED_FIELD_VALUE_LENGTH: u16 = 1024;
+/// The maximum unicode code points allowed within an embed's footer text.
+pub const EMBED_FOOTER_TEXT_LENGTH: u16 = 2048;
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: u16 = 6000;
+/// The maximum unicode code points allowed within an embed's title.
+pub const EMBED_TITLE_LENGTH: u16 = 256;
 /// The gateway version used by the library. The gateway URI is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 6;
+/// The maximum number of recipients in a group, including the current user.
+pub const GROUP_RECIPIENT_LIMIT: u8 = 10;
 /// The large threshold to send on identify.
 pub const LARGE_THRESHOLD: u8 = 250;
+/// The maximum number of messages the cache keeps for each channel.
+pub const MESSAGE_CACHE_LIMIT: u8 = 100;
 /// The maximum unicode code points allowed within a message by Discord.
 pub const MESSAGE_CODE_LIMIT: u16 = 2000;
+/// The maximum number of files that may be attached to a message.
+pub const MESSAGE_FILE_LIMIT: u8 = 10;
+/// The maximum number of messages that may be pinned in a channel.
+pub const PIN_LIMIT: u16 = 50;
+/// The number of users retrieved per request for a reaction if no limit is
+/// given.
+pub const REACTION_USERS_DEFAULT_LIMIT: u8 = 50;
+/// The maximum number of users that may be retrieved per request for a
+/// reaction.
+pub const REACTION_USERS_MAX_LIMIT: u8 = 100;
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ../hyper/header/struct.UserAgent.html
@@ -17,6 +50,13 @@ pub const USER_AGENT: &'static str = concat!(
     env!("CARGO_PKG_VERSION"),
     ")"
 );
+/// The maximum bitrate of a voice channel, in bits per second.
+pub const VOICE_BITRATE_MAX: u32 = 96_000;
+/// The minimum bitrate of a voice channel, in bits per second.
+pub const VOICE_BITRATE_MIN: u32 = 8_000;
+/// The maximum user limit of a voice channel. A limit of `0` means that the
+/// number of users is unlimited.
+pub const VOICE_USER_LIMIT_MAX: u8 = 99;
 
 /// List of messages Discord shows on member join.
 pub static JOIN_MESSAGES: &'static [&'static str] = &[
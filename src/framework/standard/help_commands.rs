@@ -157,26 +157,28 @@ pub fn with_embeds(_: &mut Context,
                         }
 
                         if let Some(ref usage) = command.usage {
-                            embed = embed.field(|f| {
-                                f.name("Usage")
-                                    .value(&format!("`{} {}`", command_name, usage))
-                            });
+                            embed = embed.field(
+                                "Usage",
+                                format!("`{} {}`", command_name, usage),
+                                true,
+                            );
                         }
 
                         if let Some(ref example) = command.example {
-                            embed = embed.field(|f| {
-                                f.name("Sample usage")
-                                    .value(&format!("`{} {}`", command_name, example))
-                            });
+                            embed = embed.field(
+                                "Sample usage",
+                                format!("`{} {}`", command_name, example),
+                                true,
+                            );
                         }
 
                         if group_name != "Ungrouped" {
-                            embed = embed.field(|f| f.name("Group").value(&group_name));
+                            embed = embed.field("Group", &group_name, true);
                         }
 
                         if !command.aliases.is_empty() {
                             let aliases = command.aliases.join(", ");
-                            embed = embed.field(|f| f.name("Aliases").value(&aliases));
+                            embed = embed.field("Aliases", &aliases, true);
                         }
 
                         let available = if command.dm_only {
@@ -187,7 +189,7 @@ pub fn with_embeds(_: &mut Context,
                             "In DM and guilds"
                         };
 
-                        embed = embed.field(|f| f.name("Available").value(available));
+                        embed = embed.field("Available", available, true);
 
                         embed
                     })
@@ -240,7 +242,7 @@ pub fn with_embeds(_: &mut Context,
                 }
 
                 if has_commands {
-                    e = e.field(|f| f.name(group_name).value(&desc));
+                    e = e.field(group_name, &desc, true);
                 }
             }
             e
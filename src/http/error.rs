@@ -1,4 +1,5 @@
 use hyper::status::StatusCode;
+use super::ratelimiting::RateLimit;
 use std::error::Error as StdError;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -12,6 +13,12 @@ pub enum Error {
     /// When the decoding of a ratelimit header could not be properly decoded
     /// from UTF-8.
     RateLimitUtf8,
+    /// When a request was ratelimited more times than allowed through
+    /// [`ratelimiting::set_max_retries`], containing the state of the
+    /// route's ratelimit at the time.
+    ///
+    /// [`ratelimiting::set_max_retries`]: ratelimiting/fn.set_max_retries.html
+    RateLimited(RateLimit),
     /// When a status is received, but the verification to ensure the response
     /// is valid does not recognize the status.
     UnknownStatus(u16),
@@ -27,6 +34,7 @@ impl StdError for Error {
             Error::InvalidRequest(_) => "Received an unexpected status code",
             Error::RateLimitI64 => "Error decoding a header into an i64",
             Error::RateLimitUtf8 => "Error decoding a header from UTF-8",
+            Error::RateLimited(_) => "Request ratelimited too many times",
             Error::UnknownStatus(_) => "Verification does not understand status",
         }
     }
@@ -40,13 +40,14 @@ use hyper_native_tls::NativeTlsClient;
 use multipart::client::Multipart;
 use self::ratelimiting::Route;
 use serde_json;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::default::Default;
 use std::fmt::Write as FmtWrite;
 use std::fs::File;
 use std::io::{ErrorKind as IoErrorKind, Read};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use constants;
 use internal::prelude::*;
 use model::*;
@@ -70,8 +71,12 @@ pub enum LightMethod {
     Put,
 }
 
+/// The maximum number of sent messages remembered for deduplicating nonces.
+const SENT_NONCES_LIMIT: usize = 100;
+
 lazy_static! {
     static ref TOKEN: Arc<Mutex<String>> = Arc::new(Mutex::new(String::default()));
+    static ref SENT_NONCES: Mutex<SentNonces> = Mutex::new(SentNonces::default());
 }
 
 /// Sets the token to be used across all requests which require authentication.
@@ -100,6 +105,35 @@ lazy_static! {
 /// # }
 pub fn set_token(token: &str) { TOKEN.lock().unwrap().clone_from(&token.to_string()); }
 
+/// Sets how long messages sent with a nonce are remembered, so that sending a
+/// message with the same nonce to the same channel again returns the
+/// remembered [`Message`] instead of posting a duplicate.
+///
+/// This guards against a message being sent twice when a send is retried,
+/// such as after a network error, beyond Discord's own handling of nonces.
+/// Only the latest 100 messages sent with a nonce are remembered, and sends
+/// performed at the same time are not deduplicated against each other.
+///
+/// A zero duration - the default - disables deduplication.
+///
+/// # Examples
+///
+/// Deduplicate messages sent with the same nonce within a minute:
+///
+/// ```rust,no_run
+/// use serenity::http;
+/// use std::time::Duration;
+///
+/// http::set_nonce_dedupe_window(Duration::from_secs(60));
+/// ```
+///
+/// [`Message`]: ../model/struct.Message.html
+pub fn set_nonce_dedupe_window(window: Duration) {
+    let mut sent_nonces = SENT_NONCES.lock().expect("sent nonces poisoned");
+    sent_nonces.window = window;
+    sent_nonces.expire();
+}
+
 /// Adds a [`User`] as a recipient to a [`Group`].
 ///
 /// **Note**: Groups have a limit of 10 recipients, including the current user.
@@ -368,7 +402,7 @@ pub fn create_reaction(channel_id: u64,
             "/channels/{}/messages/{}/reactions/{}/@me",
             channel_id,
             message_id,
-            reaction_type.as_data()
+            reaction_type.as_url_encoded()
         ),
     )
 }
@@ -430,6 +464,21 @@ pub fn create_webhook(channel_id: u64, map: &Value) -> Result<Webhook> {
         .map_err(From::from)
 }
 
+/// Crossposts a message in a news channel to the guilds following the
+/// channel, returning the updated message.
+pub fn crosspost_message(channel_id: u64, message_id: u64) -> Result<Message> {
+    let response = request!(
+        Route::ChannelsIdMessagesIdCrosspost(channel_id),
+        post,
+        "/channels/{}/messages/{}/crosspost",
+        channel_id,
+        message_id
+    );
+
+    serde_json::from_reader::<HyperResponse, Message>(response)
+        .map_err(From::from)
+}
+
 /// Deletes a private channel or a channel in a guild.
 pub fn delete_channel(channel_id: u64) -> Result<Channel> {
     let response = request!(
@@ -517,6 +566,28 @@ pub fn delete_messages(channel_id: u64, map: &Value) -> Result<()> {
     )
 }
 
+/// Deletes all of the [`Reaction`]s of the given emoji associated with a
+/// [`Message`].
+///
+/// [`Message`]: ../model/struct.Message.html
+/// [`Reaction`]: ../model/struct.Reaction.html
+pub fn delete_message_reaction_emoji(channel_id: u64,
+                                     message_id: u64,
+                                     reaction_type: &ReactionType)
+                                     -> Result<()> {
+    verify(
+        204,
+        request!(
+            Route::ChannelsIdMessagesIdReactions(channel_id),
+            delete,
+            "/channels/{}/messages/{}/reactions/{}",
+            channel_id,
+            message_id,
+            reaction_type.as_url_encoded()
+        ),
+    )
+}
+
 /// Deletes all of the [`Reaction`]s associated with a [`Message`].
 ///
 /// # Examples
@@ -580,7 +651,7 @@ pub fn delete_reaction(channel_id: u64,
             "/channels/{}/messages/{}/reactions/{}/{}",
             channel_id,
             message_id,
-            reaction_type.as_data(),
+            reaction_type.as_url_encoded(),
             user
         ),
     )
@@ -688,6 +759,20 @@ pub fn edit_emoji(guild_id: u64, emoji_id: u64, map: &Value) -> Result<Emoji> {
         .map_err(From::from)
 }
 
+/// Changes group information, such as its name or icon.
+pub fn edit_group(group_id: u64, map: &JsonMap) -> Result<Group> {
+    let body = serde_json::to_string(map)?;
+    let response = request!(
+        Route::ChannelsId(group_id),
+        patch(body),
+        "/channels/{}",
+        group_id
+    );
+
+    serde_json::from_reader::<HyperResponse, Group>(response)
+        .map_err(From::from)
+}
+
 /// Changes guild information.
 pub fn edit_guild(guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
     let body = serde_json::to_string(map)?;
@@ -702,6 +787,29 @@ pub fn edit_guild(guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
         .map_err(From::from)
 }
 
+/// Changes the positions of a [`Guild`]'s channels.
+///
+/// The map is an array of objects, each with the `id` of a channel and its
+/// new `position`.
+///
+/// **Note**: Requires the [Manage Channels] permission.
+///
+/// [`Guild`]: ../model/struct.Guild.html
+/// [Manage Channels]: ../model/permissions/constant.MANAGE_CHANNELS.html
+pub fn edit_guild_channel_positions(guild_id: u64, map: &Value) -> Result<()> {
+    let body = map.to_string();
+
+    verify(
+        204,
+        request!(
+            Route::GuildsIdChannels(guild_id),
+            patch(body),
+            "/guilds/{}/channels",
+            guild_id
+        ),
+    )
+}
+
 /// Edits a [`Guild`]'s embed setting.
 ///
 /// [`Guild`]: ../model/struct.Guild.html
@@ -993,6 +1101,21 @@ pub fn execute_webhook(webhook_id: u64,
         .map_err(From::from)
 }
 
+/// Follows a news channel into the channel given through the map's
+/// `webhook_channel_id`, creating a webhook in that channel.
+pub fn follow_news_channel(channel_id: u64, map: &Value) -> Result<FollowedChannel> {
+    let body = map.to_string();
+    let response = request!(
+        Route::ChannelsIdFollowers(channel_id),
+        post(body),
+        "/channels/{}/followers",
+        channel_id
+    );
+
+    serde_json::from_reader::<HyperResponse, FollowedChannel>(response)
+        .map_err(From::from)
+}
+
 /// Gets the active maintenances from Discord's Status API.
 ///
 /// Does not require authentication.
@@ -1421,7 +1544,7 @@ pub fn get_reaction_users(channel_id: u64,
         "/channels/{}/messages/{}/reactions/{}?limit={}",
         channel_id,
         message_id,
-        reaction_type.as_data(),
+        reaction_type.as_url_encoded(),
         limit
     );
 
@@ -1613,6 +1736,57 @@ pub fn remove_group_recipient(group_id: u64, user_id: u64) -> Result<()> {
 /// [`HttpError::InvalidRequest`]: enum.HttpError.html#variant.InvalidRequest
 pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, map: JsonMap) -> Result<Message>
     where T: Into<AttachmentType<'a>> {
+    send_files_with_progress(channel_id, files, map, |_, _| {})
+}
+
+/// Sends file(s) to a channel, reporting the upload's progress.
+///
+/// The `progress` callback is called with the number of bytes of the files
+/// sent so far and the total number of bytes of the files as the files are
+/// written to the request.
+///
+/// # Errors
+///
+/// Returns an
+/// [`HttpError::InvalidRequest(PayloadTooLarge)`][`HttpError::InvalidRequest`]
+/// if the file is too large to send.
+///
+/// [`HttpError::InvalidRequest`]: enum.HttpError.html#variant.InvalidRequest
+pub fn send_files_with_progress<'a, T, It, P>(channel_id: u64,
+                                              files: It,
+                                              map: JsonMap,
+                                              mut progress: P)
+                                              -> Result<Message>
+    where T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T>, P: FnMut(u64, u64) {
+    // Open all of the files up-front, so that the total size is known before
+    // anything is sent.
+    let mut streams: Vec<Box<Read + 'a>> = vec![];
+    let mut filenames = vec![];
+    let mut total = 0;
+
+    for file in files {
+        match file.into() {
+            AttachmentType::Bytes((bytes, filename)) => {
+                total += bytes.len() as u64;
+                streams.push(Box::new(bytes));
+                filenames.push(Some(filename));
+            },
+            AttachmentType::File((f, filename)) => {
+                total += f.metadata()?.len();
+                streams.push(Box::new(f));
+                filenames.push(Some(filename));
+            },
+            AttachmentType::Path(p) => {
+                let f = File::open(p)?;
+                total += f.metadata()?.len();
+                streams.push(Box::new(f));
+                filenames.push(p.file_name().and_then(|name| name.to_str()));
+            },
+        }
+    }
+
+    let fields = multipart_fields(filenames, map);
+
     let uri = format!(api!("/channels/{}/messages"), channel_id);
     let url = match Url::parse(&uri) {
         Ok(url) => url,
@@ -1630,37 +1804,21 @@ pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, m
         .set(header::UserAgent(constants::USER_AGENT.to_string()));
 
     let mut request = Multipart::from_request(request)?;
-    let mut file_num = "0".to_string();
-
-    for file in files {
-        match file.into() {
-            AttachmentType::Bytes((mut bytes, filename)) => {
-                request
-                    .write_stream(&file_num, &mut bytes, Some(filename), None)?;
-            },
-            AttachmentType::File((mut f, filename)) => {
-                request
-                    .write_stream(&file_num, &mut f, Some(filename), None)?;
-            },
-            AttachmentType::Path(p) => {
-                request.write_file(&file_num, &p)?;
-            },
-        }
+    let mut sent = 0;
+
+    for ((name, filename), stream) in fields.files.into_iter().zip(streams) {
+        let mut stream = ProgressReader {
+            inner: stream,
+            progress: &mut progress,
+            sent: &mut sent,
+            total: total,
+        };
 
-        unsafe {
-            let vec = file_num.as_mut_vec();
-            vec[0] += 1;
-        }
+        request.write_stream(&name, &mut stream, filename, None)?;
     }
 
-    for (k, v) in map {
-        match v {
-            Value::Bool(false) => request.write_text(&k, "false")?,
-            Value::Bool(true) => request.write_text(&k, "true")?,
-            Value::Number(inner) => request.write_text(&k, inner.to_string())?,
-            Value::String(inner) => request.write_text(&k, inner)?,
-            _ => continue,
-        };
+    for (name, value) in fields.text {
+        request.write_text(name, value)?;
     }
 
     let response = request.send()?;
@@ -1674,17 +1832,26 @@ pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, m
 }
 
 /// Sends a message to a channel.
+///
+/// If the message has a nonce which was sent to the channel within the
+/// window given to [`set_nonce_dedupe_window`], then the message sent back
+/// then is returned instead, without sending the message again.
+///
+/// [`set_nonce_dedupe_window`]: fn.set_nonce_dedupe_window.html
 pub fn send_message(channel_id: u64, map: &Value) -> Result<Message> {
-    let body = map.to_string();
-    let response = request!(
-        Route::ChannelsIdMessages(channel_id),
-        post(body),
-        "/channels/{}/messages",
-        channel_id
-    );
+    let nonce = map.get("nonce").map(|nonce| nonce.to_string());
 
-    serde_json::from_reader::<HyperResponse, Message>(response)
-        .map_err(From::from)
+    send_deduplicated(&SENT_NONCES, channel_id, nonce, || {
+        let body = map.to_string();
+        let response = request!(
+            Route::ChannelsIdMessages(channel_id),
+            post(body),
+            "/channels/{}/messages",
+            channel_id
+        );
+
+        serde_json::from_reader::<HyperResponse, Message>(response).map_err(From::from)
+    })
 }
 
 /// Pins a message in a channel.
@@ -1780,6 +1947,32 @@ pub fn unpin_message(channel_id: u64, message_id: u64) -> Result<()> {
     )
 }
 
+// The fields of a multipart message: the file parts, as their field names
+// and filenames, and the text fields.
+struct MultipartFields<'a> {
+    files: Vec<(String, Option<&'a str>)>,
+    text: Vec<(&'static str, String)>,
+}
+
+// Builds the fields of a multipart message, numbering the file parts in the
+// order of the files.
+//
+// Values such as embeds can not be sent as plain form fields, so the whole
+// message is sent as JSON in a `payload_json` field alongside the files. This
+// lets embeds refer to the files through `attachment://` URLs.
+fn multipart_fields<'a>(filenames: Vec<Option<&'a str>>, map: JsonMap) -> MultipartFields<'a> {
+    let files = filenames
+        .into_iter()
+        .enumerate()
+        .map(|(num, filename)| (num.to_string(), filename))
+        .collect();
+
+    MultipartFields {
+        files: files,
+        text: vec![("payload_json", Value::Object(map).to_string())],
+    }
+}
+
 fn request<'a, F>(route: Route, f: F) -> Result<HyperResponse>
     where F: Fn() -> RequestBuilder<'a> {
     let response = ratelimiting::perform(route, || {
@@ -1807,6 +2000,40 @@ pub(crate) fn retry<'a, F>(f: F) -> HyperResult<HyperResponse>
     }
 }
 
+// Sends a message through `send`, unless a message with the same nonce was
+// already sent to the channel within the window of `sent_nonces`, in which
+// case that message is returned instead.
+fn send_deduplicated<F>(sent_nonces: &Mutex<SentNonces>,
+                        channel_id: u64,
+                        nonce: Option<String>,
+                        send: F)
+                        -> Result<Message>
+    where F: FnOnce() -> Result<Message> {
+    if let Some(ref nonce) = nonce {
+        let sent = sent_nonces
+            .lock()
+            .expect("sent nonces poisoned")
+            .get(channel_id, nonce);
+
+        if let Some(message) = sent {
+            debug!("Deduplicated message with nonce {} in {}", nonce, channel_id);
+
+            return Ok(message);
+        }
+    }
+
+    let message = send()?;
+
+    if let Some(nonce) = nonce {
+        sent_nonces
+            .lock()
+            .expect("sent nonces poisoned")
+            .insert(channel_id, nonce, &message);
+    }
+
+    Ok(message)
+}
+
 fn verify(expected_status_code: u16, mut response: HyperResponse) -> Result<()> {
     let expected_status = match expected_status_code {
         200 => StatusCode::Ok,
@@ -1833,13 +2060,111 @@ fn verify(expected_status_code: u16, mut response: HyperResponse) -> Result<()>
     Err(Error::Http(HttpError::InvalidRequest(response.status)))
 }
 
-/// Enum that allows a user to pass a `Path` or a `File` type to `send_files`
+/// A reader that reports the number of bytes read through it to a callback,
+/// used for reporting the progress of file uploads.
+struct ProgressReader<'p, R, P: 'p> {
+    inner: R,
+    progress: &'p mut P,
+    sent: &'p mut u64,
+    total: u64,
+}
+
+impl<'p, R: Read, P: FnMut(u64, u64)> Read for ProgressReader<'p, R, P> {
+    fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+
+        if read > 0 {
+            *self.sent += read as u64;
+            (self.progress)(*self.sent, self.total);
+        }
+
+        Ok(read)
+    }
+}
+
+/// The messages recently sent with a nonce, from oldest to newest, used by
+/// [`send_message`] to deduplicate messages.
+///
+/// [`send_message`]: fn.send_message.html
+#[derive(Default)]
+struct SentNonces {
+    sent: VecDeque<SentNonce>,
+    window: Duration,
+}
+
+struct SentNonce {
+    at: Instant,
+    channel_id: u64,
+    message: Message,
+    nonce: String,
+}
+
+impl SentNonces {
+    fn expire(&mut self) {
+        let window = self.window;
+
+        while self.sent.front().map_or(false, |sent| sent.at.elapsed() >= window) {
+            self.sent.pop_front();
+        }
+    }
+
+    fn get(&mut self, channel_id: u64, nonce: &str) -> Option<Message> {
+        self.expire();
+
+        self.sent
+            .iter()
+            .find(|sent| sent.channel_id == channel_id && sent.nonce == nonce)
+            .map(|sent| sent.message.clone())
+    }
+
+    fn insert(&mut self, channel_id: u64, nonce: String, message: &Message) {
+        if self.window == Duration::from_secs(0) {
+            return;
+        }
+
+        self.expire();
+        self.sent.push_back(SentNonce {
+            at: Instant::now(),
+            channel_id: channel_id,
+            message: message.clone(),
+            nonce: nonce,
+        });
+
+        while self.sent.len() > SENT_NONCES_LIMIT {
+            self.sent.pop_front();
+        }
+    }
+}
+
+/// Enum that allows a user to pass a `Path`, a `File`, or in-memory bytes to
+/// `send_files`.
+///
+/// # Examples
+///
+/// Upload a file generated at runtime, without writing it to disk first:
+///
+/// ```rust,no_run
+/// use serenity::model::ChannelId;
+///
+/// let chart: Vec<u8> = vec![]; // Some rendered PNG.
+///
+/// let _ = ChannelId(7).send_files(vec![(&chart[..], "chart.png")], |m| m.content("here"));
+/// ```
 pub enum AttachmentType<'a> {
     /// Indicates that the `AttachmentType` is a byte slice with a filename.
+    ///
+    /// The given filename is used for the upload, and the data is sent with
+    /// a generic `application/octet-stream` content type.
     Bytes((&'a [u8], &'a str)),
     /// Indicates that the `AttachmentType` is a `File`
     File((&'a File, &'a str)),
-    /// Indicates that the `AttachmentType` is a `Path`
+    /// Indicates that the `AttachmentType` is a `Path`.
+    ///
+    /// The file is opened and read when the request is sent, and the filename
+    /// is taken from the path's file name. Failing to open the file results
+    /// in an [`Error::Io`].
+    ///
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
     Path(&'a Path),
 }
 
@@ -1869,3 +2194,151 @@ pub enum GuildPagination {
     /// The Id to get the guilds before.
     Before(GuildId),
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multipart_fields_embed_and_file() {
+        let map = object(json!({
+            "content": "logo",
+            "embeds": [{
+                "image": {
+                    "url": "attachment://logo.png",
+                },
+            }],
+        }));
+
+        let fields = multipart_fields(vec![Some("logo.png")], map);
+        assert_eq!(fields.files, vec![("0".to_string(), Some("logo.png"))]);
+        assert_eq!(fields.text.len(), 1);
+        assert_eq!(fields.text[0].0, "payload_json");
+
+        let payload = serde_json::from_str::<Value>(&fields.text[0].1).unwrap();
+        assert_eq!(payload["content"], "logo");
+        assert_eq!(payload["embeds"][0]["image"]["url"], "attachment://logo.png");
+        assert!(payload.get("embed").is_none());
+    }
+
+    #[test]
+    fn multipart_fields_legacy_embed() {
+        let map = object(json!({
+            "embed": {
+                "image": {
+                    "url": "attachment://logo.png",
+                },
+            },
+        }));
+
+        let fields = multipart_fields(vec![Some("logo.png")], map);
+
+        let payload = serde_json::from_str::<Value>(&fields.text[0].1).unwrap();
+        assert_eq!(payload["embed"]["image"]["url"], "attachment://logo.png");
+    }
+
+    fn message(id: u64) -> Message {
+        let f = File::open("./tests/resources/message_footer_1.json").unwrap();
+        let mut message = serde_json::from_reader::<File, Message>(f).unwrap();
+        message.id = MessageId(id);
+
+        message
+    }
+
+    fn object(value: Value) -> JsonMap {
+        match value {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    fn sent_nonces(window: Duration) -> Mutex<SentNonces> {
+        Mutex::new(SentNonces {
+            sent: VecDeque::new(),
+            window: window,
+        })
+    }
+
+    // Sends a message with the given nonce, returning the sent message's Id
+    // and counting each post.
+    fn send(sent_nonces: &Mutex<SentNonces>,
+            channel_id: u64,
+            nonce: &str,
+            posts: &mut u64)
+            -> MessageId {
+        let id = *posts + 1;
+        let sent = send_deduplicated(sent_nonces, channel_id, Some(nonce.to_string()), || {
+            *posts += 1;
+
+            Ok(message(id))
+        });
+
+        sent.unwrap().id
+    }
+
+    #[test]
+    fn nonce_dedupe_within_window() {
+        let sent_nonces = sent_nonces(Duration::from_secs(60));
+        let mut posts = 0;
+
+        assert_eq!(send(&sent_nonces, 1, "a", &mut posts), MessageId(1));
+        assert_eq!(send(&sent_nonces, 1, "a", &mut posts), MessageId(1));
+        assert_eq!(posts, 1);
+
+        assert_eq!(send(&sent_nonces, 1, "b", &mut posts), MessageId(2));
+        assert_eq!(posts, 2);
+    }
+
+    #[test]
+    fn nonce_dedupe_window_expires() {
+        let sent_nonces = sent_nonces(Duration::from_millis(1));
+        let mut posts = 0;
+
+        send(&sent_nonces, 1, "a", &mut posts);
+        ::std::thread::sleep(Duration::from_millis(10));
+        send(&sent_nonces, 1, "a", &mut posts);
+        assert_eq!(posts, 2);
+    }
+
+    #[test]
+    fn nonce_dedupe_zero_window_disables() {
+        let sent_nonces = sent_nonces(Duration::from_secs(0));
+        let mut posts = 0;
+
+        send(&sent_nonces, 1, "a", &mut posts);
+        send(&sent_nonces, 1, "a", &mut posts);
+        assert_eq!(posts, 2);
+        assert!(sent_nonces.lock().unwrap().sent.is_empty());
+    }
+
+    #[test]
+    fn nonce_dedupe_per_channel() {
+        let sent_nonces = sent_nonces(Duration::from_secs(60));
+        let mut posts = 0;
+
+        send(&sent_nonces, 1, "a", &mut posts);
+        send(&sent_nonces, 2, "a", &mut posts);
+        assert_eq!(posts, 2);
+
+        send(&sent_nonces, 2, "a", &mut posts);
+        assert_eq!(posts, 2);
+    }
+
+    #[test]
+    fn nonce_dedupe_limit() {
+        let sent_nonces = sent_nonces(Duration::from_secs(60));
+        let mut posts = 0;
+
+        for nonce in 0..SENT_NONCES_LIMIT + 1 {
+            send(&sent_nonces, 1, &nonce.to_string(), &mut posts);
+        }
+        assert_eq!(sent_nonces.lock().unwrap().sent.len(), SENT_NONCES_LIMIT);
+
+        // The oldest nonce was forgotten, while the newest is still known.
+        send(&sent_nonces, 1, "0", &mut posts);
+        assert_eq!(posts, SENT_NONCES_LIMIT as u64 + 2);
+
+        send(&sent_nonces, 1, &SENT_NONCES_LIMIT.to_string(), &mut posts);
+        assert_eq!(posts, SENT_NONCES_LIMIT as u64 + 2);
+    }
+}
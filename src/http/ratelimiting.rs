@@ -46,8 +46,8 @@ use hyper::header::Headers;
 use hyper::status::StatusCode;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
-use std::{str, thread, i64};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{cmp, str, thread, i64};
 use super::{HttpError, LightMethod};
 use internal::prelude::*;
 
@@ -92,6 +92,8 @@ lazy_static! {
     pub static ref ROUTES: Arc<Mutex<HashMap<Route, Arc<Mutex<RateLimit>>>>> = {
         Arc::new(Mutex::new(HashMap::default()))
     };
+    static ref MAX_RETRIES: Mutex<Option<u32>> = Mutex::new(Some(1));
+    static ref REACTIONS: Mutex<HashMap<Route, Instant>> = Mutex::new(HashMap::default());
 }
 
 /// A representation of all routes registered within the library. These are safe
@@ -107,6 +109,12 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/struct.ChannelId.html
     ChannelsId(u64),
+    /// Route for the `/channels/:channel_id/followers` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/struct.ChannelId.html
+    ChannelsIdFollowers(u64),
     /// Route for the `/channels/:channel_id/invites` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -146,6 +154,13 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/struct.ChannelId.html
     ChannelsIdMessagesIdAck(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/crosspost`
+    /// path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/struct.ChannelId.html
+    ChannelsIdMessagesIdCrosspost(u64),
     /// Route for the `/channels/:channel_id/messages/:message_id/reactions`
     /// path.
     ///
@@ -347,8 +362,123 @@ pub enum Route {
     None,
 }
 
+/// Discord allows one reaction to be added or removed per channel every 250
+/// milliseconds, which is not reflected in the ratelimit headers.
+const REACTION_DELAY_MS: u64 = 250;
+
+/// Retrieves how long the next request on the given route would currently have
+/// to wait for its ratelimit, or a zero duration if it could be performed
+/// immediately.
+///
+/// This includes the pacing of reactions, and is based on the ratelimit
+/// headers of the route's last response, so it is reliable right after a
+/// request on the route.
+///
+/// # Examples
+///
+/// Check how long until another reaction can be added in a channel:
+///
+/// ```rust,no_run
+/// use serenity::http::ratelimiting::{self, Route};
+///
+/// let delay = ratelimiting::next_delay(Route::ChannelsIdMessagesIdReactionsUserIdType(7));
+///
+/// println!("Next reaction in {:?}", delay);
+/// ```
+pub fn next_delay(route: Route) -> Duration {
+    let now = Instant::now();
+
+    let reaction_delay = REACTIONS
+        .lock()
+        .expect("reactions poisoned")
+        .get(&route)
+        .map(|&last| last + Duration::from_millis(REACTION_DELAY_MS))
+        .and_then(|at| if at > now { Some(at - now) } else { None })
+        .unwrap_or_else(|| Duration::from_millis(0));
+
+    let bucket = ROUTES
+        .lock()
+        .expect("routes poisoned")
+        .get(&route)
+        .map(Arc::clone);
+
+    // Like in the pre-hook, an exhausted bucket waits until its reset.
+    let bucket_delay = bucket.map_or(0, |bucket| {
+        let ratelimit = bucket.lock().unwrap();
+        let current_time = Utc::now().timestamp();
+
+        if ratelimit.remaining == 0 && ratelimit.reset > current_time {
+            (ratelimit.reset - current_time) as u64 * 1000
+        } else {
+            0
+        }
+    });
+
+    cmp::max(reaction_delay, Duration::from_millis(bucket_delay))
+}
+
+/// Retrieves a copy of the ratelimit of every route that has been requested,
+/// for debugging purposes.
+///
+/// **Note**: This blocks while a request on one of the routes is waiting for
+/// its ratelimit to reset.
+///
+/// # Examples
+///
+/// Print the routes that have no requests remaining:
+///
+/// ```rust,no_run
+/// use serenity::http::ratelimiting;
+///
+/// for (route, ratelimit) in ratelimiting::ratelimits() {
+///     if ratelimit.remaining == 0 {
+///         println!("{:?} resets at {}", route, ratelimit.reset);
+///     }
+/// }
+/// ```
+pub fn ratelimits() -> HashMap<Route, RateLimit> {
+    // The buckets are locked after releasing the routes, so that requests are
+    // not blocked while waiting on a bucket.
+    let buckets = ROUTES
+        .lock()
+        .expect("routes poisoned")
+        .iter()
+        .map(|(route, bucket)| (*route, Arc::clone(bucket)))
+        .collect::<Vec<_>>();
+
+    buckets
+        .into_iter()
+        .map(|(route, bucket)| (route, bucket.lock().unwrap().clone()))
+        .collect()
+}
+
+/// Sets the maximum number of times a request is retried after being
+/// ratelimited, before an [`HttpError::RateLimited`] is returned.
+///
+/// By default, a ratelimited request is retried once. When `None` is given,
+/// ratelimited requests are retried until they succeed. Every retry waits for
+/// the time given by Discord, plus a small random delay so that many requests
+/// do not all retry at once.
+///
+/// # Examples
+///
+/// Give up on a request after it is ratelimited three times:
+///
+/// ```rust,no_run
+/// use serenity::http::ratelimiting;
+///
+/// ratelimiting::set_max_retries(Some(3));
+/// ```
+///
+/// [`HttpError::RateLimited`]: ../enum.HttpError.html#variant.RateLimited
+pub fn set_max_retries(max_retries: Option<u32>) {
+    *MAX_RETRIES.lock().expect("max retries poisoned") = max_retries;
+}
+
 pub(crate) fn perform<'a, F>(route: Route, f: F) -> Result<Response>
     where F: Fn() -> RequestBuilder<'a> {
+    let mut retries = 0;
+
     loop {
         // This will block if another thread already has the global
         // unlocked already (due to receiving an x-ratelimit-global).
@@ -377,6 +507,10 @@ pub(crate) fn perform<'a, F>(route: Route, f: F) -> Result<Response>
         let mut lock = bucket.lock().unwrap();
         lock.pre_hook(&route);
 
+        if let Route::ChannelsIdMessagesIdReactionsUserIdType(_) = route {
+            reaction_delay(route);
+        }
+
         let response = super::retry(&f)?;
 
         // Check if the request got ratelimited by checking for status 429,
@@ -394,28 +528,43 @@ pub(crate) fn perform<'a, F>(route: Route, f: F) -> Result<Response>
         // header. If the limit was 5 and is now 7, add 2 to the 'remaining'
         if route == Route::None {
             return Ok(response);
+        }
+
+        let global = response.headers.get_raw("x-ratelimit-global").is_some();
+
+        let retry_after = if global {
+            parse_header(&response.headers, "retry-after")
         } else {
-            let redo = if response.headers.get_raw("x-ratelimit-global").is_some() {
-                let _ = GLOBAL.lock().expect("global route lock poisoned");
-
-                Ok(
-                    if let Some(retry_after) = parse_header(&response.headers, "retry-after")? {
-                        debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
-                        thread::sleep(Duration::from_millis(retry_after as u64));
-
-                        true
-                    } else {
-                        false
-                    },
-                )
-            } else {
-                lock.post_hook(&response, &route)
-            };
-
-            if !redo.unwrap_or(true) {
-                return Ok(response);
-            }
+            lock.post_hook(&response)
+        };
+
+        // A header that could not be decoded is retried immediately.
+        let retry_after = match retry_after {
+            Ok(Some(retry_after)) => retry_after as u64,
+            Ok(None) => return Ok(response),
+            Err(_) => 0,
+        };
+
+        let max_retries = *MAX_RETRIES.lock().expect("max retries poisoned");
+
+        if max_retries.map_or(false, |max_retries| retries >= max_retries) {
+            return Err(Error::Http(HttpError::RateLimited(lock.clone())));
         }
+
+        retries += 1;
+
+        let delay = retry_after + jitter();
+        debug!("Ratelimited on route {:?} for {:?}ms", route, delay);
+
+        // Hold the global mutex while waiting out a global ratelimit,
+        // blocking all other requests until it is over.
+        let _global = if global {
+            Some(GLOBAL.lock().expect("global route lock poisoned"))
+        } else {
+            None
+        };
+
+        thread::sleep(Duration::from_millis(delay));
     }
 }
 
@@ -430,7 +579,7 @@ pub(crate) fn perform<'a, F>(route: Route, f: F) -> Result<Response>
 /// [`ROUTES`]: struct.ROUTES.html
 /// [`Route`]: enum.Route.html
 /// [Discord docs]: https://discordapp.com/developers/docs/topics/rate-limits
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RateLimit {
     /// The total number of requests that can be made in a period of time.
     pub limit: i64,
@@ -477,7 +626,10 @@ impl RateLimit {
         self.remaining -= 1;
     }
 
-    pub(crate) fn post_hook(&mut self, response: &Response, route: &Route) -> Result<bool> {
+    /// Updates the ratelimit from the response's headers, returning the
+    /// number of milliseconds to wait before retrying if the request was
+    /// ratelimited.
+    pub(crate) fn post_hook(&mut self, response: &Response) -> Result<Option<i64>> {
         if let Some(limit) = parse_header(&response.headers, "x-ratelimit-limit")? {
             self.limit = limit;
         }
@@ -490,19 +642,44 @@ impl RateLimit {
             self.reset = reset;
         }
 
-        Ok(if response.status != StatusCode::TooManyRequests {
-            false
-        } else if let Some(retry_after) = parse_header(&response.headers, "retry-after")? {
-            debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
-            thread::sleep(Duration::from_millis(retry_after as u64));
-
-            true
+        if response.status == StatusCode::TooManyRequests {
+            parse_header(&response.headers, "retry-after")
         } else {
-            false
-        })
+            Ok(None)
+        }
+    }
+}
+
+fn reaction_delay(route: Route) {
+    let now = Instant::now();
+
+    // The time of this request is reserved before sleeping, so that other
+    // channels' reactions are not blocked in the meantime.
+    let at = {
+        let mut reactions = REACTIONS.lock().expect("reactions poisoned");
+        let at = reactions.get(&route).map_or(now, |&last| {
+            cmp::max(now, last + Duration::from_millis(REACTION_DELAY_MS))
+        });
+        reactions.insert(route, at);
+
+        at
+    };
+
+    if at > now {
+        debug!("Pre-emptive reaction ratelimit on route {:?}", route);
+        thread::sleep(at - now);
     }
 }
 
+// Up to 100 milliseconds, taken from the clock as a cheap source of
+// randomness.
+fn jitter() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| u64::from(now.subsec_nanos() % 100_000_000) / 1_000_000)
+        .unwrap_or(0)
+}
+
 fn parse_header(headers: &Headers, header: &str) -> Result<Option<i64>> {
     headers.get_raw(header).map_or(Ok(None), |header| {
         str::from_utf8(&header[0])
@@ -1,7 +1,11 @@
 #[cfg(feature = "model")]
 use hyper::Client as HyperClient;
 #[cfg(feature = "model")]
-use std::io::Read;
+use http::{HttpError, StatusClass};
+#[cfg(feature = "model")]
+use std::io::{self, Write};
+#[cfg(feature = "model")]
+use std::path::Path;
 #[cfg(feature = "model")]
 use internal::prelude::*;
 
@@ -12,6 +16,9 @@ use internal::prelude::*;
 pub struct Attachment {
     /// The unique ID given to this attachment.
     pub id: String,
+    /// The media type of the file, such as `image/png`, if Discord provided
+    /// one.
+    pub content_type: Option<String>,
     /// The filename of the file that was uploaded. This is equivalent to what
     /// the uploader had their file named.
     pub filename: String,
@@ -31,13 +38,43 @@ pub struct Attachment {
 impl Attachment {
     /// If this attachment is an image, then a tuple of the width and height
     /// in pixels is returned.
+    ///
+    /// Returns `None` for attachments that are not images, such as text
+    /// files.
     pub fn dimensions(&self) -> Option<(u64, u64)> {
         self.width
             .and_then(|width| self.height.map(|height| (width, height)))
     }
 
+    /// Retrieves the extension of the attachment's [`filename`], such as
+    /// `"png"`.
+    ///
+    /// Returns `None` if the filename has no extension.
+    ///
+    /// [`filename`]: #structfield.filename
+    pub fn extension(&self) -> Option<&str> {
+        Path::new(&self.filename)
+            .extension()
+            .and_then(|extension| extension.to_str())
+    }
+
+    /// Whether the attachment is an image.
+    ///
+    /// Discord only provides the [`dimensions`] of attachments that are
+    /// images, so this does not inspect the [`filename`] or the
+    /// [`content_type`].
+    ///
+    /// [`content_type`]: #structfield.content_type
+    /// [`dimensions`]: #method.dimensions
+    /// [`filename`]: #structfield.filename
+    #[inline]
+    pub fn is_image(&self) -> bool { self.dimensions().is_some() }
+
     /// Downloads the attachment, returning back a vector of bytes.
     ///
+    /// The whole attachment is held in memory. Refer to [`download_to`] for
+    /// writing it elsewhere, such as to a file, as it is downloaded.
+    ///
     /// # Examples
     ///
     /// Download all of the attachments associated with a [`Message`]:
@@ -102,16 +139,57 @@ impl Attachment {
     /// Returns an [`Error::Hyper`] when there is a problem retrieving the
     /// attachment.
     ///
+    /// Returns an [`HttpError::InvalidRequest`] if the attachment could not
+    /// be retrieved, such as when it was deleted.
+    ///
     /// [`Error::Hyper`]: ../enum.Error.html#variant.Hyper
+    /// [`HttpError::InvalidRequest`]: ../http/enum.HttpError.html#variant.InvalidRequest
     /// [`Error::Io`]: ../enum.Error.html#variant.Io
     /// [`Message`]: struct.Message.html
+    /// [`download_to`]: #method.download_to
     pub fn download(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(self.size as usize);
+        self.download_to(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Downloads the attachment, writing its contents to the given writer as
+    /// they are received, and returning the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// Save an attachment to a file, without holding it in memory:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::Attachment;
+    /// # use std::error::Error;
+    /// use std::fs::File;
+    ///
+    /// # fn try_main(attachment: Attachment) -> Result<(), Box<Error>> {
+    /// let mut file = File::create(&attachment.filename)?;
+    /// let written = attachment.download_to(&mut file)?;
+    ///
+    /// println!("Saved {} bytes", written);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`download`], with an [`Error::Io`] also
+    /// being returned when writing to the writer fails.
+    ///
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    /// [`download`]: #method.download
+    pub fn download_to<W: Write>(&self, writer: &mut W) -> Result<u64> {
         let hyper = request_client!();
         let mut response = hyper.get(&self.url).send()?;
 
-        let mut bytes = vec![];
-        response.read_to_end(&mut bytes)?;
+        if response.status.class() != StatusClass::Success {
+            return Err(Error::Http(HttpError::InvalidRequest(response.status)));
+        }
 
-        Ok(bytes)
+        Ok(io::copy(&mut response, writer)?)
     }
 }
@@ -1,11 +1,15 @@
 use model::*;
 
 #[cfg(all(feature = "builder", feature = "model"))]
-use builder::EditChannel;
+use builder::{CreateChannel, EditChannel};
 #[cfg(all(feature = "builder", feature = "model"))]
 use http;
 #[cfg(all(feature = "model", feature = "utils"))]
 use utils as serenity_utils;
+#[cfg(all(feature = "cache", feature = "model"))]
+use std::sync::{Arc, RwLock};
+#[cfg(all(feature = "cache", feature = "model"))]
+use CACHE;
 
 /// A category of [`GuildChannel`]s.
 ///
@@ -17,6 +21,9 @@ pub struct ChannelCategory {
     /// If this category belongs to another category.
     #[serde(rename = "parent_id")]
     pub category_id: Option<ChannelId>,
+    /// The Id of the guild the category belongs to, if it was given.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
     /// The position of this category.
     pub position: i64,
     /// Indicator of the type of channel this is.
@@ -39,6 +46,116 @@ pub struct ChannelCategory {
 
 #[cfg(feature = "model")]
 impl ChannelCategory {
+    /// Retrieves the [`GuildChannel`]s within this category from the cache,
+    /// sorted by their position.
+    ///
+    /// Returns an empty list if the category's guild is not in the cache.
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    #[cfg(feature = "cache")]
+    pub fn channels(&self) -> Vec<Arc<RwLock<GuildChannel>>> {
+        let guild = match self.find_guild_id().and_then(|id| CACHE.read().unwrap().guild(id)) {
+            Some(guild) => guild,
+            None => return vec![],
+        };
+        let guild = guild.read().unwrap();
+
+        let mut channels = guild
+            .channels
+            .values()
+            .filter(|channel| channel.read().unwrap().category_id == Some(self.id))
+            .cloned()
+            .collect::<Vec<_>>();
+        channels.sort_by_key(|channel| channel.read().unwrap().position);
+
+        channels
+    }
+
+    /// Retrieves the [`GuildChannel`]s within this category over the REST API,
+    /// sorted by their position.
+    ///
+    /// Returns a [`ModelError::GuildNotFound`] if the category's guild is not
+    /// known.
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    /// [`ModelError::GuildNotFound`]: enum.ModelError.html#variant.GuildNotFound
+    pub fn channels_http(&self) -> Result<Vec<GuildChannel>> {
+        let guild_id = match self.find_guild_id() {
+            Some(guild_id) => guild_id,
+            None => return Err(Error::Model(ModelError::GuildNotFound)),
+        };
+
+        let mut channels = guild_id
+            .channels()?
+            .into_iter()
+            .map(|(_, channel)| channel)
+            .filter(|channel| channel.category_id == Some(self.id))
+            .collect::<Vec<_>>();
+        channels.sort_by_key(|channel| channel.position);
+
+        Ok(channels)
+    }
+
+    /// Creates a [`GuildChannel`] with the given name and kind inside the
+    /// category.
+    ///
+    /// Refer to [`CreateChannel`]'s documentation for the other settings that
+    /// can be given to the new channel.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Examples
+    ///
+    /// Create a text channel with a topic in the category:
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::ChannelType;
+    ///
+    /// let channel = category.create_channel("ticket-1", ChannelType::Text, |c| c
+    ///     .topic("Support ticket"))?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::GuildNotFound`] if the category's guild is not
+    /// known.
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`CreateChannel`]: ../builder/struct.CreateChannel.html
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    /// [`ModelError::GuildNotFound`]: enum.ModelError.html#variant.GuildNotFound
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    #[cfg(all(feature = "builder", feature = "model"))]
+    pub fn create_channel<F>(&self, name: &str, kind: ChannelType, f: F) -> Result<GuildChannel>
+        where F: FnOnce(CreateChannel) -> CreateChannel {
+        let guild_id = match self.find_guild_id() {
+            Some(guild_id) => guild_id,
+            None => return Err(Error::Model(ModelError::GuildNotFound)),
+        };
+
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_CHANNELS;
+
+            if !utils::user_has_perms(self.id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        let mut map = Map::new();
+        map.insert("name".to_string(), Value::String(name.to_string()));
+        map.insert("type".to_string(), Value::Number(Number::from(kind as u64)));
+
+        let mut map = f(CreateChannel(map)).0;
+        map.insert("parent_id".to_string(), Value::String(self.id.0.to_string()));
+
+        http::create_channel(guild_id.0, &Value::Object(map))
+    }
+
     /// Adds a permission overwrite to the category's channels.
     #[inline]
     pub fn create_permission(&self, target: &PermissionOverwrite) -> Result<()> {
@@ -110,6 +227,7 @@ impl ChannelCategory {
             let GuildChannel {
                 id,
                 category_id,
+                guild_id,
                 permission_overwrites,
                 nsfw,
                 name,
@@ -121,6 +239,7 @@ impl ChannelCategory {
             *self = ChannelCategory {
                 id,
                 category_id,
+                guild_id: Some(guild_id),
                 permission_overwrites,
                 nsfw,
                 name,
@@ -139,4 +258,27 @@ impl ChannelCategory {
 
     /// Returns the name of the category.
     pub fn name(&self) -> &str { &self.name }
+
+    /// Finds the Id of the category's guild, falling back to searching the
+    /// cache for a guild containing the category.
+    pub(crate) fn find_guild_id(&self) -> Option<GuildId> {
+        if self.guild_id.is_some() {
+            return self.guild_id;
+        }
+
+        feature_cache! {{
+            let cache = CACHE.read().unwrap();
+
+            let guild_id = cache
+                .guilds
+                .values()
+                .map(|guild| guild.read().unwrap())
+                .find(|guild| guild.channels.contains_key(&self.id))
+                .map(|guild| guild.id);
+
+            guild_id
+        } else {
+            None
+        }}
+    }
 }
@@ -1,16 +1,28 @@
 use model::*;
 use internal::RwLockExt;
 
+#[cfg(feature = "model")]
+use chrono::{Duration, Utc};
+#[cfg(feature = "model")]
+use constants;
 #[cfg(feature = "model")]
 use std::borrow::Cow;
 #[cfg(feature = "model")]
-use std::fmt::Write as FmtWrite;
+use std::cmp;
 #[cfg(feature = "model")]
-use builder::{CreateMessage, EditChannel, GetMessages};
+use std::fmt::{Display, Write as FmtWrite};
+#[cfg(feature = "model")]
+use builder::{CreateEmbed, CreateMessage, EditChannel, GetMessages};
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
 #[cfg(feature = "model")]
 use http::{self, AttachmentType};
+#[cfg(feature = "model")]
+use http::ratelimiting::{self, Route};
+#[cfg(feature = "model")]
+use std::time::Duration as StdDuration;
+#[cfg(feature = "model")]
+use serde_json;
 
 #[cfg(feature = "model")]
 impl ChannelId {
@@ -37,6 +49,46 @@ impl ChannelId {
     #[inline]
     pub fn broadcast_typing(&self) -> Result<()> { http::broadcast_typing(self.0) }
 
+    /// Counts how many of the channel's most recent messages match the given
+    /// filter, looking at no more than `scan_limit` messages.
+    ///
+    /// This is a scan performed by the client, not a server-side query:
+    /// messages are fetched in pages of 100 through [`messages_iter`], so this
+    /// costs one request per 100 messages scanned.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// # Examples
+    ///
+    /// Count how many of the last 500 messages were sent by a user:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::{ChannelId, UserId};
+    ///
+    /// let user_id = UserId(7);
+    ///
+    /// let _ = ChannelId(7).count_messages(500, |message| message.author.id == user_id);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of fetching a page of messages, if one failed.
+    ///
+    /// [`messages_iter`]: #method.messages_iter
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn count_messages<F>(&self, scan_limit: usize, filter: F) -> Result<usize>
+        where F: Fn(&Message) -> bool {
+        let mut count = 0;
+
+        for message in self.messages_iter().take(scan_limit) {
+            if filter(&message?) {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
     /// Creates a [permission overwrite][`PermissionOverwrite`] for either a
     /// single [`Member`] or [`Role`] within the channel.
     ///
@@ -51,19 +103,7 @@ impl ChannelId {
     /// [`Role`]: struct.Role.html
     /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
     pub fn create_permission(&self, target: &PermissionOverwrite) -> Result<()> {
-        let (id, kind) = match target.kind {
-            PermissionOverwriteType::Member(id) => (id.0, "member"),
-            PermissionOverwriteType::Role(id) => (id.0, "role"),
-        };
-
-        let map = json!({
-            "allow": target.allow.bits(),
-            "deny": target.deny.bits(),
-            "id": id,
-            "type": kind,
-        });
-
-        http::create_permission(self.0, id, &map)
+        http::create_permission(self.0, target.kind.id(), &serde_json::to_value(target)?)
     }
 
     /// React to a [`Message`] with a custom [`Emoji`] or unicode character.
@@ -84,9 +124,110 @@ impl ChannelId {
         http::create_reaction(self.0, message_id.into().0, &reaction_type.into())
     }
 
+    /// React to a [`Message`] like [`create_reaction`], returning how long the
+    /// next reaction in the channel would have to wait for its ratelimit.
+    ///
+    /// This is useful for pacing reaction menus, such as to sleep until the
+    /// next reaction can be added instead of guessing. The duration is zero if
+    /// another reaction could be added immediately. Refer to
+    /// [`ratelimiting::next_delay`] for more information.
+    ///
+    /// Requires the [Add Reactions] permission, _if_ the current user is the
+    /// first user to perform a react with a certain emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    /// use std::thread;
+    ///
+    /// let channel_id = ChannelId(7);
+    ///
+    /// for reaction in vec!['1', '2', '3'] {
+    ///     let delay = channel_id.create_reaction_timed(8, reaction).unwrap();
+    ///
+    ///     thread::sleep(delay);
+    /// }
+    /// ```
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`create_reaction`]: #method.create_reaction
+    /// [`ratelimiting::next_delay`]: ../http/ratelimiting/fn.next_delay.html
+    /// [Add Reactions]: permissions/constant.ADD_REACTIONS.html
+    pub fn create_reaction_timed<M, R>(&self,
+                                       message_id: M,
+                                       reaction_type: R)
+                                       -> Result<StdDuration>
+        where M: Into<MessageId>, R: Into<ReactionType> {
+        self.create_reaction(message_id, reaction_type)?;
+
+        let route = Route::ChannelsIdMessagesIdReactionsUserIdType(self.0);
+
+        Ok(ratelimiting::next_delay(route))
+    }
+
+    /// React to a [`Message`] with multiple reactions, applying them one after
+    /// the other in the given order.
+    ///
+    /// The order is preserved, as Discord orders reactions by when they were
+    /// first applied. The reactions are paced by the ratelimiter, which waits
+    /// between them as reported by [`create_reaction_timed`], so no delay
+    /// needs to be added by the caller.
+    ///
+    /// Requires the [Add Reactions] permission, _if_ the current user is the
+    /// first user to perform a react with a certain emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let _ = ChannelId(7).create_reactions(8, vec!['1', '2', '3']);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If applying a reaction fails, the remaining reactions are not applied,
+    /// and the number of reactions that were applied is returned alongside
+    /// the error.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`create_reaction_timed`]: #method.create_reaction_timed
+    /// [Add Reactions]: permissions/constant.ADD_REACTIONS.html
+    pub fn create_reactions<M, R, It>(&self,
+                                      message_id: M,
+                                      reactions: It)
+                                      -> StdResult<(), (usize, Error)>
+        where M: Into<MessageId>, R: Into<ReactionType>, It: IntoIterator<Item=R> {
+        let message_id = message_id.into();
+
+        for (applied, reaction_type) in reactions.into_iter().enumerate() {
+            if let Err(why) = self.create_reaction(message_id, reaction_type) {
+                return Err((applied, why));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
-    #[inline]
-    pub fn delete(&self) -> Result<Channel> { http::delete_channel(self.0) }
+    ///
+    /// If the `cache` is enabled, the channel is also removed from the
+    /// [`Cache`] once the deletion succeeded, through
+    /// [`Cache::remove_channel`]. This is a best-effort local consistency
+    /// measure; the cache is still updated by the gateway's events as well,
+    /// which may arrive afterwards.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`Cache::remove_channel`]: ../cache/struct.Cache.html#method.remove_channel
+    pub fn delete(&self) -> Result<Channel> {
+        let channel = http::delete_channel(self.0)?;
+
+        #[cfg(feature = "cache")]
+        CACHE.write().unwrap().remove_channel(*self);
+
+        Ok(channel)
+    }
 
     /// Deletes a [`Message`] given its Id.
     ///
@@ -115,32 +256,74 @@ impl ChannelId {
     ///
     /// **Note**: Messages that are older than 2 weeks can't be deleted using this method.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::BulkDeleteAmount`] if fewer than 2 or more than
+    /// 100 Ids are given.
+    ///
     /// [`Channel::delete_messages`]: enum.Channel.html#method.delete_messages
+    /// [`ModelError::BulkDeleteAmount`]: enum.ModelError.html#variant.BulkDeleteAmount
     /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
-    pub fn delete_messages<T: AsRef<MessageId>, It: IntoIterator<Item=T>>(&self, message_ids: It) -> Result<()> {
+    pub fn delete_messages<T, It>(&self, message_ids: It) -> Result<()>
+        where T: Into<MessageId>, It: IntoIterator<Item=T> {
         let ids = message_ids
             .into_iter()
-            .map(|message_id| message_id.as_ref().0)
+            .map(|message_id| message_id.into().0)
             .collect::<Vec<u64>>();
 
+        if ids.len() < 2 || ids.len() > 100 {
+            return Err(Error::Model(ModelError::BulkDeleteAmount));
+        }
+
         let map = json!({ "messages": ids });
 
         http::delete_messages(self.0, &map)
     }
 
+    /// Deletes the messages by the given Ids that can be bulk deleted,
+    /// returning the number of messages deleted and the Ids that were
+    /// skipped.
+    ///
+    /// Messages older than 2 weeks can not be bulk deleted, and a single such
+    /// message would fail the whole [`delete_messages`] request. The age of
+    /// each message is read from its Id, so no messages are retrieved, and
+    /// the older ones are skipped instead. The remaining messages are deleted
+    /// in batches of up to 100.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// **Note**: This uses bulk delete endpoint which is not available
+    /// for user accounts.
+    ///
+    /// [`delete_messages`]: #method.delete_messages
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn delete_messages_partitioned(&self,
+                                       message_ids: &[MessageId])
+                                       -> Result<(usize, Vec<MessageId>)> {
+        let cutoff = Utc::now().naive_utc() - Duration::weeks(2);
+        let (recent, old): (Vec<MessageId>, Vec<MessageId>) = message_ids
+            .iter()
+            .cloned()
+            .partition(|id| id.created_at() > cutoff);
+
+        for chunk in recent.chunks(100) {
+            if chunk.len() == 1 {
+                self.delete_message(chunk[0])?;
+            } else {
+                self.delete_messages(chunk)?;
+            }
+        }
+
+        Ok((recent.len(), old))
+    }
+
     /// Deletes all permission overrides in the channel from a member or role.
     ///
     /// **Note**: Requires the [Manage Channel] permission.
     ///
     /// [Manage Channel]: permissions/constant.MANAGE_CHANNELS.html
     pub fn delete_permission(&self, permission_type: PermissionOverwriteType) -> Result<()> {
-        http::delete_permission(
-            self.0,
-            match permission_type {
-                PermissionOverwriteType::Member(id) => id.0,
-                PermissionOverwriteType::Role(id) => id.0,
-            },
-        )
+        http::delete_permission(self.0, permission_type.id())
     }
 
     /// Deletes the given [`Reaction`] from the channel.
@@ -164,6 +347,46 @@ impl ChannelId {
         )
     }
 
+    /// Deletes all [`Reaction`]s of the given emoji on a message in the
+    /// channel.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// [`Reaction`]: struct.Reaction.html
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn delete_reaction_emoji<M, R>(&self, message_id: M, reaction_type: R) -> Result<()>
+        where M: Into<MessageId>, R: Into<ReactionType> {
+        http::delete_message_reaction_emoji(self.0, message_id.into().0, &reaction_type.into())
+    }
+
+    /// Deletes the given [`Reaction`] of the targeted user from the channel.
+    ///
+    /// This is the same as [`delete_reaction`], but makes it explicit whose
+    /// reaction is deleted.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission, _if_ the target
+    /// is not the current user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::{ChannelId, ReactionTarget};
+    ///
+    /// let _ = ChannelId(7).delete_reaction_target(8, ReactionTarget::Me, '🍎');
+    /// ```
+    ///
+    /// [`Reaction`]: struct.Reaction.html
+    /// [`delete_reaction`]: #method.delete_reaction
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[inline]
+    pub fn delete_reaction_target<M, R>(&self,
+                                        message_id: M,
+                                        target: ReactionTarget,
+                                        reaction_type: R)
+                                        -> Result<()>
+        where M: Into<MessageId>, R: Into<ReactionType> {
+        self.delete_reaction(message_id, target.user_id(), reaction_type)
+    }
 
     /// Edits the settings of a [`Channel`], optionally setting new values.
     ///
@@ -211,13 +434,35 @@ impl ChannelId {
         where F: FnOnce(CreateMessage) -> CreateMessage, M: Into<MessageId> {
         let map = f(CreateMessage::default()).0;
 
-        if let Some(content) = map.get("content") {
-            if let Value::String(ref content) = *content {
-                if let Some(length_over) = Message::overflow_length(content) {
-                    return Err(Error::Model(ModelError::MessageTooLong(length_over)));
-                }
-            }
-        }
+        // Checked after the builder has run, so that the final content is
+        // what is counted.
+        Message::check_content_length(&map)?;
+
+        http::edit_message(self.0, message_id.into().0, &Value::Object(map))
+    }
+
+    /// Edits the embed of a [`Message`] in the channel given its Id, replacing
+    /// the message's embeds with the one built.
+    ///
+    /// Only the embeds are sent, so the content of the message - and all
+    /// other message data - is left untouched. This is useful for repeatedly
+    /// updating an embed without resending the message's text.
+    ///
+    /// **Note**: Requires that the current user be the author of the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmbedTooLarge`] if the embed is over Discord's
+    /// limits, a [`ModelError::EmbedTimestampInvalid`] if its timestamp is
+    /// invalid, or a [`ModelError::InvalidUrl`] if an icon URL is invalid.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`ModelError::EmbedTimestampInvalid`]: enum.ModelError.html#variant.EmbedTimestampInvalid
+    /// [`ModelError::EmbedTooLarge`]: enum.ModelError.html#variant.EmbedTooLarge
+    /// [`ModelError::InvalidUrl`]: enum.ModelError.html#variant.InvalidUrl
+    pub fn edit_message_embed<F, M>(&self, message_id: M, f: F) -> Result<Message>
+        where F: FnOnce(CreateEmbed) -> CreateEmbed, M: Into<MessageId> {
+        let map = ChannelId::embed_edit_map(f)?;
 
         http::edit_message(self.0, message_id.into().0, &Value::Object(map))
     }
@@ -226,6 +471,51 @@ impl ChannelId {
     #[cfg(feature = "cache")]
     pub fn find(&self) -> Option<Channel> { CACHE.read().unwrap().channel(*self) }
 
+    /// Calls the given function with each of the channel's messages, from
+    /// newest to oldest, until it returns `false` or no messages are left.
+    ///
+    /// Messages are fetched in pages of 100 through [`messages_iter`], and
+    /// are not kept once the function has been called, so entire histories
+    /// can be scanned without buffering them.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// # Examples
+    ///
+    /// Find the most recent message mentioning `serenity`:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let mut found = None;
+    ///
+    /// let _ = ChannelId(7).for_each_message(|message| {
+    ///     if message.content.contains("serenity") {
+    ///         found = Some(message);
+    ///
+    ///         return false;
+    ///     }
+    ///
+    ///     true
+    /// });
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of fetching a page of messages, if one failed.
+    ///
+    /// [`messages_iter`]: #method.messages_iter
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn for_each_message<F: FnMut(Message) -> bool>(&self, mut f: F) -> Result<()> {
+        for message in self.messages_iter() {
+            if !f(message?) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Search the cache for the channel. If it can't be found, the channel is
     /// requested over REST.
     pub fn get(&self) -> Result<Channel> {
@@ -261,31 +551,83 @@ impl ChannelId {
             })
     }
 
+    /// Gets a message from the channel along with up to `context` messages
+    /// sent before and after it, using the `around` parameter of
+    /// [`GetMessages`].
+    ///
+    /// The messages are ordered from newest to oldest, as with
+    /// [`messages`]. At most 100 messages are retrieved, so a `context` above
+    /// 49 is reduced accordingly.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// [`GetMessages`]: ../builder/struct.GetMessages.html
+    /// [`messages`]: #method.messages
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn message_with_context<M>(&self, message_id: M, context: u8) -> Result<Vec<Message>>
+        where M: Into<MessageId> {
+        let limit = cmp::min(u64::from(context) * 2 + 1, 100);
+        let query = format!("?limit={}&around={}", limit, message_id.into().0);
+
+        http::get_messages(self.0, &query).map(|msgs| {
+            msgs.into_iter()
+                .map(|mut msg| {
+                    msg.transform_content();
+
+                    msg
+                })
+                .collect::<Vec<Message>>()
+        })
+    }
+
     /// Gets messages from the channel.
     ///
     /// Refer to [`Channel::messages`] for more information.
     ///
+    /// When retrieving messages `around` a message with a `limit` of `1`, only
+    /// that message - if it exists - is returned.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve the 50 messages surrounding a reported message:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::{ChannelId, MessageId};
+    ///
+    /// let reported = MessageId(158339864557912064);
+    ///
+    /// let _messages = ChannelId(81384788765712384)
+    ///     .messages(|g| g.around(reported).limit(50));
+    /// ```
+    ///
     /// Requires the [Read Message History] permission.
     ///
     /// [`Channel::messages`]: enum.Channel.html#method.messages
     /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
     pub fn messages<F>(&self, f: F) -> Result<Vec<Message>>
-        where F: FnOnce(&GetMessages) -> &GetMessages {
-        let builder = GetMessages::default();
-        let filtered = f(&builder).build();
-        let mut map = filtered.0;
-        let mut query = format!("?limit={}", map.remove("limit").unwrap_or(50));
+        where F: FnOnce(GetMessages) -> GetMessages {
+        let mut map = f(GetMessages::default()).0;
+        let limit = map.remove("limit").unwrap_or(50);
+        let mut query = format!("?limit={}", limit);
+        let mut anchor = None;
 
         if let Some(after) = map.remove("after") {
             write!(query, "&after={}", after)?;
         } else if let Some(around) = map.remove("around") {
             write!(query, "&around={}", around)?;
+
+            // Discord may include a neighbouring message rather than the
+            // anchor itself, so filter down to the anchor.
+            if limit == 1 {
+                anchor = Some(around);
+            }
         } else if let Some(before) = map.remove("before") {
             write!(query, "&before={}", before)?;
         }
 
         http::get_messages(self.0, &query).map(|msgs| {
             msgs.into_iter()
+                .filter(|msg| anchor.map_or(true, |id| msg.id.0 == id))
                 .map(|mut msg| {
                     msg.transform_content();
 
@@ -295,6 +637,89 @@ impl ChannelId {
         })
     }
 
+    /// Gets all of the messages sent strictly between the two given messages,
+    /// in chronological order.
+    ///
+    /// Messages are fetched in pages of 100 forward from `after`, until the
+    /// `before` message is reached. If `after` is not older than `before`, no
+    /// messages are fetched and an empty list is returned.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// # Examples
+    ///
+    /// Archive a span of a conversation:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let channel_id = ChannelId(81384788765712384);
+    ///
+    /// if let Ok(messages) = channel_id.messages_between(381880193251409931, 381880193700069377) {
+    ///     for message in messages {
+    ///         println!("{}: {}", message.author.name, message.content);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn messages_between<A, B>(&self, after: A, before: B) -> Result<Vec<Message>>
+        where A: Into<MessageId>, B: Into<MessageId> {
+        let (mut after, before) = (after.into(), before.into());
+        let mut messages = vec![];
+
+        while after < before {
+            let page = self.messages(|g| g.after(after).limit(100))?;
+            let last_page = page.len() < 100 || page.iter().any(|message| message.id >= before);
+
+            let mut page = page
+                .into_iter()
+                .filter(|message| message.id > after && message.id < before)
+                .collect::<Vec<Message>>();
+            page.sort_by_key(|message| message.id);
+
+            match page.last() {
+                Some(message) => after = message.id,
+                None => break,
+            }
+
+            messages.extend(page);
+
+            if last_page {
+                break;
+            }
+        }
+
+        Ok(messages)
+    }
+
+    /// Creates an iterator over the channel's messages, from newest to
+    /// oldest.
+    ///
+    /// Messages are lazily fetched in pages of 100 as the iterator is
+    /// advanced. If fetching a page fails, the error is yielded and the
+    /// iterator ends.
+    ///
+    /// # Examples
+    ///
+    /// Count the messages in a channel sent by bots:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let count = ChannelId(81384788765712384)
+    ///     .messages_iter()
+    ///     .filter_map(|message| message.ok())
+    ///     .filter(|message| message.author.bot)
+    ///     .count();
+    /// ```
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    #[inline]
+    pub fn messages_iter(&self) -> MessagesIter { MessagesIter::new(*self) }
+
     /// Returns the name of whatever channel this id holds.
     #[cfg(feature = "model")]
     pub fn name(&self) -> Option<String> {
@@ -325,44 +750,224 @@ impl ChannelId {
 
     /// Pins a [`Message`] to the channel.
     ///
+    /// This does not check whether the channel is at Discord's limit of 50
+    /// pinned messages. Refer to [`pin_checked`] for that.
+    ///
     /// [`Message`]: struct.Message.html
+    /// [`pin_checked`]: #method.pin_checked
     #[inline]
     pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
         http::pin_message(self.0, message_id.into().0)
     }
 
-    /// Gets the list of [`Message`]s which are pinned to the channel.
+    /// Pins a [`Message`] to the channel, and then retrieves the channel's
+    /// pinned messages, including the newly pinned one.
+    ///
+    /// Refer to [`pin`] for more information.
+    ///
+    /// Requires the [Manage Messages] permission.
     ///
     /// [`Message`]: struct.Message.html
-    #[inline]
-    pub fn pins(&self) -> Result<Vec<Message>> { http::get_pins(self.0) }
+    /// [`pin`]: #method.pin
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn pin_and_list<M: Into<MessageId>>(&self, message_id: M) -> Result<Vec<Message>> {
+        self.pin(message_id)?;
 
-    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
-    /// certain [`Emoji`].
+        self.pins()
+    }
+
+    /// Pins a [`Message`] to the channel, first checking that the channel is
+    /// not already at Discord's limit of 50 pinned messages.
     ///
-    /// Refer to [`Channel::reaction_users`] for more information.
+    /// The check costs an extra request, retrieving the channel's pins. Use
+    /// [`pin`] to skip it.
     ///
-    /// **Note**: Requires the [Read Message History] permission.
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has the
+    /// maximum number of pinned messages, without attempting to pin the
+    /// message.
     ///
-    /// [`Channel::reaction_users`]: enum.Channel.html#method.reaction_users
-    /// [`Emoji`]: struct.Emoji.html
     /// [`Message`]: struct.Message.html
-    /// [`User`]: struct.User.html
-    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
-    pub fn reaction_users<M, R, U>(&self,
-                                   message_id: M,
+    /// [`ModelError::TooManyPins`]: enum.ModelError.html#variant.TooManyPins
+    /// [`pin`]: #method.pin
+    pub fn pin_checked<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        if self.pin_count()? >= constants::PIN_LIMIT as usize {
+            return Err(Error::Model(ModelError::TooManyPins));
+        }
+
+        self.pin(message_id)
+    }
+
+    /// Retrieves the number of [`Message`]s which are pinned to the channel.
+    ///
+    /// [`Message`]: struct.Message.html
+    #[inline]
+    pub fn pin_count(&self) -> Result<usize> { self.pins().map(|pins| pins.len()) }
+
+    /// Gets the list of [`Message`]s which are pinned to the channel.
+    ///
+    /// [`Message`]: struct.Message.html
+    #[inline]
+    pub fn pins(&self) -> Result<Vec<Message>> { http::get_pins(self.0) }
+
+    /// Deletes up to `limit` of the channel's most recent messages that match
+    /// the given predicate, returning the number of messages deleted.
+    ///
+    /// Messages are fetched in pages of 100. Matching messages younger than 2
+    /// weeks are removed using the bulk delete endpoint in batches of 100,
+    /// while older messages - which can not be bulk deleted - are deleted one
+    /// at a time.
+    ///
+    /// Requires the [Read Message History] and [Manage Messages] permissions.
+    ///
+    /// # Examples
+    ///
+    /// Delete the last 50 messages sent by bots:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let _ = ChannelId(7).purge(50, |m| m.author.bot);
+    /// ```
+    ///
+    /// System messages that can not be deleted, such as group recipient
+    /// additions, are skipped. Use [`purge_with`] to find out which messages
+    /// were skipped.
+    ///
+    /// [`purge_with`]: #method.purge_with
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    #[inline]
+    pub fn purge<F>(&self, limit: usize, predicate: F) -> Result<usize>
+        where F: Fn(&Message) -> bool {
+        self.purge_with(limit, false, predicate).map(|report| report.deleted)
+    }
+
+    /// Deletes up to `limit` of the channel's most recent messages.
+    ///
+    /// Refer to [`purge`] for more information.
+    ///
+    /// [`purge`]: #method.purge
+    #[inline]
+    pub fn purge_all(&self, limit: usize) -> Result<usize> { self.purge(limit, |_| true) }
+
+    /// Deletes up to `limit` of the channel's most recent messages that match
+    /// the given predicate, like [`purge`], returning a [`PurgeReport`].
+    ///
+    /// If `skip_system` is `true`, all system messages - those whose
+    /// [`MessageType`] is not [`MessageType::Regular`] - are skipped.
+    /// Otherwise, only system messages that Discord refuses to delete are
+    /// skipped, so that the purge does not fail midway. Skipped messages are
+    /// included in the report.
+    ///
+    /// Requires the [Read Message History] and [Manage Messages] permissions.
+    ///
+    /// # Examples
+    ///
+    /// Delete the last 100 messages, leaving any system messages in place:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// if let Ok(report) = ChannelId(7).purge_with(100, true, |_| true) {
+    ///     println!("Deleted {}, skipped {}", report.deleted, report.skipped.len());
+    /// }
+    /// ```
+    ///
+    /// [`MessageType`]: enum.MessageType.html
+    /// [`MessageType::Regular`]: enum.MessageType.html#variant.Regular
+    /// [`PurgeReport`]: struct.PurgeReport.html
+    /// [`purge`]: #method.purge
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn purge_with<F>(&self,
+                         limit: usize,
+                         skip_system: bool,
+                         predicate: F)
+                         -> Result<PurgeReport>
+        where F: Fn(&Message) -> bool {
+        let mut matching = vec![];
+        let mut skipped = vec![];
+        let mut remaining = limit;
+        let mut before: Option<MessageId> = None;
+
+        while remaining > 0 {
+            let amount = cmp::min(remaining, 100);
+            let mut query = format!("?limit={}", amount);
+
+            if let Some(before) = before {
+                write!(query, "&before={}", before)?;
+            }
+
+            let messages = http::get_messages(self.0, &query)?;
+            let received = messages.len();
+
+            before = messages.last().map(|m| m.id);
+            remaining -= cmp::min(remaining, received);
+
+            for message in messages.iter().filter(|m| predicate(m)) {
+                let is_system = message.is_system();
+
+                if !message.kind.is_deletable() || (skip_system && is_system) {
+                    skipped.push(message.id);
+                } else {
+                    matching.push(message.id);
+                }
+            }
+
+            if received < amount {
+                break;
+            }
+        }
+
+        let cutoff = Utc::now().naive_utc() - Duration::weeks(2);
+        let (recent, old): (Vec<MessageId>, Vec<MessageId>) = matching
+            .into_iter()
+            .partition(|id| id.created_at() > cutoff);
+
+        for chunk in recent.chunks(100) {
+            if chunk.len() == 1 {
+                self.delete_message(chunk[0])?;
+            } else {
+                self.delete_messages(chunk)?;
+            }
+        }
+
+        for id in &old {
+            self.delete_message(*id)?;
+        }
+
+        Ok(PurgeReport {
+            deleted: recent.len() + old.len(),
+            skipped: skipped,
+        })
+    }
+
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
+    /// certain [`Emoji`].
+    ///
+    /// Refer to [`Channel::reaction_users`] for more information.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [`Channel::reaction_users`]: enum.Channel.html#method.reaction_users
+    /// [`Emoji`]: struct.Emoji.html
+    /// [`Message`]: struct.Message.html
+    /// [`User`]: struct.User.html
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn reaction_users<M, R, U>(&self,
+                                   message_id: M,
                                    reaction_type: R,
                                    limit: Option<u8>,
                                    after: Option<U>)
                                    -> Result<Vec<User>>
         where M: Into<MessageId>, R: Into<ReactionType>, U: Into<UserId> {
-        let limit = limit.map_or(50, |x| if x > 100 { 100 } else { x });
-
         http::get_reaction_users(
             self.0,
             message_id.into().0,
             &reaction_type.into(),
-            limit,
+            Reaction::users_limit(limit),
             after.map(|u| u.into().0),
         )
     }
@@ -425,37 +1030,94 @@ impl ChannelId {
     /// let _ = channel_id.send_files(files, |m| m.content("a file"));
     /// ```
     ///
+    /// Send an embed whose thumbnail is an uploaded file, referring to it
+    /// through an `attachment://` URL:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let _ = ChannelId(7).send_files(vec!["./logo.png"], |m| m
+    ///     .embed(|e| e
+    ///         .title("Our logo")
+    ///         .thumbnail("attachment://logo.png")));
+    /// ```
+    ///
+    /// The message, including its embeds, is sent as JSON in the same
+    /// request as the files, so that such URLs resolve.
+    ///
     /// # Errors
     ///
-    /// If the content of the message is over the above limit, then a
-    /// [`ClientError::MessageTooLong`] will be returned, containing the number
-    /// of unicode code points over the limit.
+    /// Returns the same errors as [`send_message`] if the content or embeds
+    /// of the message are invalid, such as a [`ModelError::MessageTooLong`]
+    /// if the content is over the above limit.
+    ///
+    /// Returns a [`ModelError::TooManyFiles`] if more than 10 files are given.
     ///
     /// Returns an
     /// [`HttpError::InvalidRequest(PayloadTooLarge)`][`HttpError::InvalidRequest`]
     /// if the file is too large to send.
     ///
-    /// [`ClientError::MessageTooLong`]: ../client/enum.ClientError.html#variant.MessageTooLong
     /// [`HttpError::InvalidRequest`]: ../http/enum.HttpError.html#variant.InvalidRequest
     /// [`CreateMessage::content`]: ../utils/builder/struct.CreateMessage.html#method.content
     /// [`GuildChannel`]: struct.GuildChannel.html
+    /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
+    /// [`ModelError::TooManyFiles`]: enum.ModelError.html#variant.TooManyFiles
+    /// [`send_message`]: #method.send_message
     /// [Attach Files]: permissions/constant.ATTACH_FILES.html
     /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[inline]
     pub fn send_files<'a, F, T, It: IntoIterator<Item=T>>(&self, files: It, f: F) -> Result<Message>
         where F: FnOnce(CreateMessage) -> CreateMessage, T: Into<AttachmentType<'a>> {
-        let mut map = f(CreateMessage::default()).0;
+        self.send_files_with_progress(files, |_, _| {}, f)
+    }
 
-        if let Some(content) = map.get("content") {
-            if let Value::String(ref content) = *content {
-                if let Some(length_over) = Message::overflow_length(content) {
-                    return Err(Error::Model(ModelError::MessageTooLong(length_over)));
-                }
-            }
-        }
+    /// Sends file(s) along with optional message contents, reporting the
+    /// upload's progress.
+    ///
+    /// This is the same as [`send_files`], except that `progress` is called
+    /// with the number of bytes sent so far and the total number of bytes of
+    /// the files as the files are uploaded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let channel_id = ChannelId(7);
+    ///
+    /// let paths = vec!["/path/to/file.jpg", "path/to/file2.jpg"];
+    ///
+    /// let _ = channel_id.send_files_with_progress(paths, |sent, total| {
+    ///     println!("Sent {}/{} bytes", sent, total);
+    /// }, |m| m.content("some files"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Refer to [`send_files`] for the errors that may be returned.
+    ///
+    /// [`send_files`]: #method.send_files
+    pub fn send_files_with_progress<'a, F, P, T, It>(&self,
+                                                     files: It,
+                                                     progress: P,
+                                                     f: F)
+                                                     -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage,
+              P: FnMut(u64, u64),
+              T: Into<AttachmentType<'a>>,
+              It: IntoIterator<Item=T> {
+        let files = files.into_iter().collect::<Vec<_>>();
+        let map = f(CreateMessage::default()).0;
 
-        let _ = map.remove("embed");
+        Message::check_file_count(files.len())?;
+        Message::check_allowed_mentions(&map)?;
+        Message::check_content_length(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_embed_parts(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_embed_urls(&map)?;
 
-        http::send_files(self.0, files, map)
+        http::send_files_with_progress(self.0, files, map, progress)
     }
 
     /// Sends a message to the channel.
@@ -473,16 +1135,118 @@ impl ChannelId {
     /// is over the above limit, containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::EmbedTooLarge`] if the embeds are over
+    /// Discord's total length limit, or if a part of an embed - such as a
+    /// field's value - is over its own limit.
+    ///
+    /// Returns a [`ModelError::EmbedTimestampInvalid`] if the timestamp of
+    /// the embed is not valid ISO-8601.
+    ///
     /// [`Channel`]: enum.Channel.html
+    /// [`ModelError::EmbedTimestampInvalid`]: enum.ModelError.html#variant.EmbedTimestampInvalid
+    /// [`ModelError::EmbedTooLarge`]: enum.ModelError.html#variant.EmbedTooLarge
     /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
     /// [`CreateMessage`]: ../builder/struct.CreateMessage.html
     /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[inline]
     pub fn send_message<F>(&self, f: F) -> Result<Message>
         where F: FnOnce(CreateMessage) -> CreateMessage {
-        let CreateMessage(map, reactions) = f(CreateMessage::default());
+        self.send_built(f(CreateMessage::default()))
+    }
+
+    /// Sends a message to the channel with the given nonce, ensuring that
+    /// Discord echoed the nonce back in the returned [`Message`].
+    ///
+    /// The nonce is also sent back in the [`Message::nonce`] of the message
+    /// received over the gateway, so a message can be shown before it is
+    /// confirmed and then matched against the received message. Any nonce set
+    /// through the builder is replaced.
+    ///
+    /// If a window was set through [`http::set_nonce_dedupe_window`], sending
+    /// the same nonce to the channel again within it returns the message that
+    /// was already sent, so that retrying a send does not post it twice.
+    ///
+    /// Refer to [`send_message`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let _ = ChannelId(7).send_message_with_nonce(1234, |m| m.content("Hello!"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`send_message`].
+    ///
+    /// Returns a [`ModelError::NonceMismatch`] if the returned message does
+    /// not have the nonce. The message has still been sent in this case, but
+    /// should not be correlated by its nonce.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`Message::nonce`]: struct.Message.html#structfield.nonce
+    /// [`ModelError::NonceMismatch`]: enum.ModelError.html#variant.NonceMismatch
+    /// [`http::set_nonce_dedupe_window`]: ../http/fn.set_nonce_dedupe_window.html
+    /// [`send_message`]: #method.send_message
+    pub fn send_message_with_nonce<F>(&self, nonce: u64, f: F) -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage {
+        let message = self.send_built(f(CreateMessage::default()).nonce(nonce.to_string()))?;
 
+        // Discord may echo the nonce back as either a string or a number.
+        let echoed = match message.nonce {
+            Value::Number(ref number) => number.as_u64() == Some(nonce),
+            Value::String(ref string) => string.parse::<u64>().ok() == Some(nonce),
+            _ => false,
+        };
+
+        if echoed {
+            Ok(message)
+        } else {
+            Err(Error::Model(ModelError::NonceMismatch))
+        }
+    }
+
+    /// Sends a message to the channel from an already constructed
+    /// [`CreateMessage`] builder.
+    ///
+    /// This is useful when the message is built separately from where it is
+    /// sent. Refer to [`send_message`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::builder::CreateMessage;
+    /// use serenity::model::ChannelId;
+    ///
+    /// let message = CreateMessage::default().content("Hello!");
+    ///
+    /// let _ = ChannelId(7).send_built(message);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`send_message`].
+    ///
+    /// [`CreateMessage`]: ../builder/struct.CreateMessage.html
+    /// [`send_message`]: #method.send_message
+    pub fn send_built(&self, message: CreateMessage) -> Result<Message> {
+        let CreateMessage(mut map, reactions) = message;
+
+        // References to messages are made within the channel the message is
+        // sent to, unless another channel is given.
+        if let Some(&mut Value::Object(ref mut reference)) = map.get_mut("message_reference") {
+            reference
+                .entry("channel_id")
+                .or_insert_with(|| Value::Number(Number::from(self.0)));
+        }
+
+        Message::check_allowed_mentions(&map)?;
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_embed_parts(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_embed_urls(&map)?;
 
         let message = http::send_message(self.0, &Value::Object(map))?;
 
@@ -495,6 +1259,100 @@ impl ChannelId {
         Ok(message)
     }
 
+    /// Sends a message with the given initial content, such as `"Working…"`,
+    /// and then edits it with the result of some work.
+    ///
+    /// The work returns the builder to edit the message with. If the work
+    /// fails, the message is instead edited to display the error, and the
+    /// error is returned.
+    ///
+    /// Requires the [Send Messages] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::builder::CreateMessage;
+    /// use serenity::model::ChannelId;
+    /// use serenity::Error;
+    ///
+    /// let result = ChannelId(7).send_then_edit("Counting…", || {
+    ///     let count = 2 + 2;
+    ///
+    ///     Ok::<_, Error>(move |m: CreateMessage| m.content(count))
+    /// });
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the work if it fails, even if editing the message
+    /// to display it also failed. Otherwise, returns any error from sending
+    /// or editing the message, converted into the work's error type.
+    ///
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn send_then_edit<E, F, G>(&self, initial: &str, work: F) -> StdResult<Message, E>
+        where E: Display + From<Error>,
+              F: FnOnce() -> StdResult<G, E>,
+              G: FnOnce(CreateMessage) -> CreateMessage {
+        let message = self.say(initial)?;
+
+        match work() {
+            Ok(f) => Ok(self.edit_message(message.id, f)?),
+            Err(why) => {
+                let content = format!("Error: {}", why);
+                let _ = self.edit_message(message.id, |m| m.content(content));
+
+                Err(why)
+            },
+        }
+    }
+
+    /// Broadcasts that the current user is typing in the channel until the
+    /// returned guard is dropped or stopped.
+    ///
+    /// Unlike [`broadcast_typing`], which shows the typing indicator once for
+    /// roughly 10 seconds, typing is rebroadcast until the guard is done.
+    /// Refer to [`Typing`] for more information.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Examples
+    ///
+    /// Keep typing while a message is prepared, stopping early once it is
+    /// ready:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let channel_id = ChannelId(7);
+    /// let typing = channel_id.start_typing();
+    ///
+    /// // Prepare the message.
+    ///
+    /// if let Ok(typing) = typing {
+    ///     typing.stop();
+    /// }
+    ///
+    /// let _ = channel_id.say("Done!");
+    /// ```
+    ///
+    /// [`broadcast_typing`]: #method.broadcast_typing
+    /// [`Typing`]: struct.Typing.html
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[inline]
+    pub fn start_typing(&self) -> Result<Typing> { Typing::start(*self) }
+
+    /// Broadcasts that the current user is typing in the channel until the
+    /// returned guard is dropped.
+    ///
+    /// This is an alias of [`start_typing`].
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// [`start_typing`]: #method.start_typing
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[inline]
+    pub fn typing(&self) -> Result<Typing> { self.start_typing() }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
@@ -506,6 +1364,19 @@ impl ChannelId {
         http::unpin_message(self.0, message_id.into().0)
     }
 
+    /// Unpins a [`Message`] in the channel given by its Id, and then
+    /// retrieves the channel's remaining pinned messages.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn unpin_and_list<M: Into<MessageId>>(&self, message_id: M) -> Result<Vec<Message>> {
+        self.unpin(message_id)?;
+
+        self.pins()
+    }
+
     /// Retrieves the channel's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -513,6 +1384,130 @@ impl ChannelId {
     /// [Manage Webhooks]: permissions/constant.MANAGE_WEBHOOKS.html
     #[inline]
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { http::get_channel_webhooks(self.0) }
+
+    /// Broadcasts that the current user is typing in the channel while the
+    /// given closure runs, returning the closure's result.
+    ///
+    /// Typing is stopped once the closure returns, or if it panics.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Examples
+    ///
+    /// Show the bot as typing while a response is prepared:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::ChannelId;
+    ///
+    /// let channel_id = ChannelId(7);
+    ///
+    /// if let Ok(content) = channel_id.with_typing(|| "Done!".to_string()) {
+    ///     let _ = channel_id.say(&content);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if typing could not be started, in which case the
+    /// closure is not run.
+    ///
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn with_typing<T, F: FnOnce() -> T>(&self, f: F) -> Result<T> {
+        // The guard is dropped on unwind, so typing stops even if `f` panics.
+        let typing = self.start_typing()?;
+        let result = f();
+        typing.stop();
+
+        Ok(result)
+    }
+
+    // Builds the body of an edit of only a message's embeds, so that its
+    // content is left untouched.
+    fn embed_edit_map<F: FnOnce(CreateEmbed) -> CreateEmbed>(f: F) -> Result<JsonMap> {
+        let embed = Value::Object(f(CreateEmbed::default()).0);
+
+        let mut map = Map::new();
+        map.insert("embeds".to_string(), Value::Array(vec![embed]));
+
+        Message::check_embed_length(&map)?;
+        Message::check_embed_parts(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_embed_urls(&map)?;
+
+        Ok(map)
+    }
+}
+
+/// The outcome of a [`ChannelId::purge_with`] call.
+///
+/// [`ChannelId::purge_with`]: struct.ChannelId.html#method.purge_with
+#[cfg(feature = "model")]
+#[derive(Clone, Debug, Default)]
+pub struct PurgeReport {
+    /// The number of messages that were deleted.
+    pub deleted: usize,
+    /// The Ids of matching system messages that were skipped, either because
+    /// system messages were excluded or because they can not be deleted.
+    pub skipped: Vec<MessageId>,
+}
+
+/// An iterator over the messages of a channel, from newest to oldest.
+///
+/// This is created by [`ChannelId::messages_iter`].
+///
+/// [`ChannelId::messages_iter`]: struct.ChannelId.html#method.messages_iter
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+pub struct MessagesIter {
+    channel_id: ChannelId,
+    // The fetched messages that are yet to be yielded, from oldest to newest.
+    buffer: Vec<Message>,
+    before: Option<MessageId>,
+    exhausted: bool,
+}
+
+#[cfg(feature = "model")]
+impl MessagesIter {
+    fn new(channel_id: ChannelId) -> MessagesIter {
+        MessagesIter {
+            channel_id: channel_id,
+            buffer: vec![],
+            before: None,
+            exhausted: false,
+        }
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        let before = self.before;
+        let mut messages = self.channel_id.messages(|g| match before {
+            Some(before) => g.before(before).limit(100),
+            None => g.limit(100),
+        })?;
+
+        self.exhausted = messages.len() < 100;
+        messages.reverse();
+        self.before = messages.first().map(|message| message.id);
+        self.buffer = messages;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "model")]
+impl Iterator for MessagesIter {
+    type Item = Result<Message>;
+
+    fn next(&mut self) -> Option<Result<Message>> {
+        if self.buffer.is_empty() && !self.exhausted {
+            if let Err(why) = self.refresh() {
+                self.exhausted = true;
+
+                return Some(Err(why));
+            }
+        }
+
+        self.buffer.pop().map(Ok)
+    }
 }
 
 impl From<Channel> for ChannelId {
@@ -553,7 +1548,56 @@ impl From<GuildChannel> for ChannelId {
     /// Gets the Id of a guild channel.
     fn from(public_channel: GuildChannel) -> ChannelId { public_channel.id }
 }
+
 impl<'a> From<&'a GuildChannel> for ChannelId {
     /// Gets the Id of a guild channel.
     fn from(public_channel: &GuildChannel) -> ChannelId { public_channel.id }
 }
+
+impl From<Group> for ChannelId {
+    /// Gets the Id of a group's channel.
+    fn from(group: Group) -> ChannelId { group.channel_id }
+}
+
+impl<'a> From<&'a Group> for ChannelId {
+    /// Gets the Id of a group's channel.
+    fn from(group: &Group) -> ChannelId { group.channel_id }
+}
+
+impl From<ChannelCategory> for ChannelId {
+    /// Gets the Id of a channel category.
+    fn from(category: ChannelCategory) -> ChannelId { category.id }
+}
+
+impl<'a> From<&'a ChannelCategory> for ChannelId {
+    /// Gets the Id of a channel category.
+    fn from(category: &ChannelCategory) -> ChannelId { category.id }
+}
+
+impl From<Message> for ChannelId {
+    /// Gets the Id of the channel that a message was sent in.
+    fn from(message: Message) -> ChannelId { message.channel_id }
+}
+
+impl<'a> From<&'a Message> for ChannelId {
+    /// Gets the Id of the channel that a message was sent in.
+    fn from(message: &Message) -> ChannelId { message.channel_id }
+}
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn embed_edit_leaves_content() {
+        let map = ChannelId::embed_edit_map(|e| e.title("status").description("up")).unwrap();
+
+        assert!(!map.contains_key("content"));
+        assert_eq!(map.len(), 1);
+
+        let embeds = map["embeds"].as_array().unwrap();
+        assert_eq!(embeds.len(), 1);
+        assert_eq!(embeds[0]["title"], "status");
+        assert_eq!(embeds[0]["description"], "up");
+    }
+}
@@ -0,0 +1,133 @@
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde_json;
+use internal::prelude::*;
+use model::*;
+
+/// A row of interactive components attached to a [`Message`], such as
+/// buttons.
+///
+/// Components are only received, and can not yet be sent.
+///
+/// [`Message`]: struct.Message.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct ActionRow {
+    /// The components within the row.
+    #[serde(default)]
+    pub components: Vec<ActionRowComponent>,
+}
+
+/// A component within an [`ActionRow`].
+///
+/// [`ActionRow`]: struct.ActionRow.html
+#[derive(Clone, Debug)]
+pub enum ActionRowComponent {
+    /// A clickable button.
+    Button(Button),
+    /// A dropdown menu of options.
+    SelectMenu(SelectMenu),
+    /// A type of component which is not yet supported, containing its type.
+    Unknown(u64),
+}
+
+impl<'de> Deserialize<'de> for ActionRowComponent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let v = JsonMap::deserialize(deserializer)?;
+        let kind = v.get("type")
+            .and_then(|kind| kind.as_u64())
+            .ok_or_else(|| DeError::missing_field("type"))?;
+
+        match kind {
+            2 => serde_json::from_value::<Button>(Value::Object(v))
+                .map(ActionRowComponent::Button)
+                .map_err(DeError::custom),
+            3 => serde_json::from_value::<SelectMenu>(Value::Object(v))
+                .map(ActionRowComponent::SelectMenu)
+                .map_err(DeError::custom),
+            other => Ok(ActionRowComponent::Unknown(other)),
+        }
+    }
+}
+
+/// A button within an [`ActionRow`].
+///
+/// [`ActionRow`]: struct.ActionRow.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct Button {
+    /// The developer-defined Id of the button, sent back when it is clicked.
+    ///
+    /// This is not present for [`ButtonStyle::Link`] buttons.
+    ///
+    /// [`ButtonStyle::Link`]: enum.ButtonStyle.html#variant.Link
+    pub custom_id: Option<String>,
+    /// Whether the button is disabled.
+    #[serde(default)]
+    pub disabled: bool,
+    /// The emoji shown on the button, if any.
+    pub emoji: Option<ReactionType>,
+    /// The text shown on the button, if any.
+    pub label: Option<String>,
+    /// The style of the button.
+    pub style: ButtonStyle,
+    /// The URL that the button leads to.
+    ///
+    /// This is only present for [`ButtonStyle::Link`] buttons.
+    ///
+    /// [`ButtonStyle::Link`]: enum.ButtonStyle.html#variant.Link
+    pub url: Option<String>,
+}
+
+enum_number!(
+    /// The style of a button, which determines its colour.
+    ButtonStyle {
+        /// A blurple button.
+        Primary = 1,
+        /// A grey button.
+        Secondary = 2,
+        /// A green button.
+        Success = 3,
+        /// A red button.
+        Danger = 4,
+        /// A grey button that leads to a URL.
+        Link = 5,
+    }
+);
+
+/// A dropdown menu within an [`ActionRow`].
+///
+/// [`ActionRow`]: struct.ActionRow.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct SelectMenu {
+    /// The developer-defined Id of the menu, sent back when an option is
+    /// chosen.
+    pub custom_id: String,
+    /// Whether the menu is disabled.
+    #[serde(default)]
+    pub disabled: bool,
+    /// The maximum number of options that may be chosen, if given.
+    pub max_values: Option<u64>,
+    /// The minimum number of options that must be chosen, if given.
+    pub min_values: Option<u64>,
+    /// The options that may be chosen.
+    #[serde(default)]
+    pub options: Vec<SelectMenuOption>,
+    /// The text shown when no option is chosen, if any.
+    pub placeholder: Option<String>,
+}
+
+/// An option within a [`SelectMenu`].
+///
+/// [`SelectMenu`]: struct.SelectMenu.html
+#[derive(Clone, Debug, Deserialize)]
+pub struct SelectMenuOption {
+    /// Whether the option is chosen by default.
+    #[serde(default)]
+    pub default: bool,
+    /// The additional description of the option, if any.
+    pub description: Option<String>,
+    /// The emoji shown with the option, if any.
+    pub emoji: Option<ReactionType>,
+    /// The text shown for the option.
+    pub label: String,
+    /// The developer-defined value of the option.
+    pub value: String,
+}
@@ -65,6 +65,20 @@ pub struct Embed {
 
 #[cfg(feature = "model")]
 impl Embed {
+    /// Retrieves the colour of the embed.
+    ///
+    /// Returns `None` if the embed has no colour. Discord omits the colour of
+    /// embeds that do not have one, which is then deserialized as `0`, so a
+    /// black colour is also treated as not having one.
+    #[cfg(feature = "utils")]
+    pub fn colour(&self) -> Option<Colour> {
+        if self.colour.0 == 0 {
+            None
+        } else {
+            Some(self.colour)
+        }
+    }
+
     /// Creates a fake Embed, giving back a `serde_json` map.
     ///
     /// This should only be useful in conjunction with [`Webhook::execute`].
@@ -81,16 +95,141 @@ impl Embed {
     /// let embed = Embed::fake(|e| e
     ///     .title("Embed title")
     ///     .description("Making a basic embed")
-    ///     .field(|f| f
-    ///         .name("A field")
-    ///         .value("Has some content.")
-    ///         .inline(false)));
+    ///     .field("A field", "Has some content.", false));
     /// ```
     #[inline]
     pub fn fake<F>(f: F) -> Value
         where F: FnOnce(CreateEmbed) -> CreateEmbed {
         Value::Object(f(CreateEmbed::default()).0)
     }
+
+    /// Finds the first field of the embed with the given name.
+    ///
+    /// The name is compared case-sensitively.
+    ///
+    /// # Examples
+    ///
+    /// Read the status out of another bot's embed:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::Embed;
+    /// #
+    /// # fn status(embed: &Embed) {
+    /// if let Some(field) = embed.field("Status") {
+    ///     println!("Status: {}", field.value);
+    /// }
+    /// # }
+    /// ```
+    pub fn field(&self, name: &str) -> Option<&EmbedField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Retrieves the text of the embed's footer, if it has one.
+    pub fn footer_text(&self) -> Option<&str> {
+        self.footer.as_ref().map(|footer| &footer.text[..])
+    }
+
+    /// Whether the embed has a field with the given name.
+    ///
+    /// Refer to [`field`] for more information.
+    ///
+    /// [`field`]: #method.field
+    #[inline]
+    pub fn has_field(&self, name: &str) -> bool { self.field(name).is_some() }
+
+    /// Retrieves the width and height of the embed's image, in pixels.
+    ///
+    /// Returns `None` if the embed has no image or if Discord did not provide
+    /// its dimensions.
+    pub fn image_dimensions(&self) -> Option<(u64, u64)> {
+        self.image
+            .as_ref()
+            .and_then(|image| dimensions(image.width, image.height))
+    }
+
+    /// Retrieves the URL of the embed's image, if it has one.
+    pub fn image_url(&self) -> Option<&str> { self.image.as_ref().map(|image| &image.url[..]) }
+
+    /// Retrieves the width and height of the embed's thumbnail, in pixels.
+    ///
+    /// Returns `None` if the embed has no thumbnail or if Discord did not
+    /// provide its dimensions.
+    pub fn thumbnail_dimensions(&self) -> Option<(u64, u64)> {
+        self.thumbnail
+            .as_ref()
+            .and_then(|thumbnail| dimensions(thumbnail.width, thumbnail.height))
+    }
+
+    /// Retrieves the URL of the embed's thumbnail, if it has one.
+    pub fn thumbnail_url(&self) -> Option<&str> {
+        self.thumbnail.as_ref().map(|thumbnail| &thumbnail.url[..])
+    }
+
+    /// Renders the embed's text as a readable plain-text summary, such as for
+    /// logging or relaying it to text-only and screen-reader-friendly places.
+    ///
+    /// Each present part is put on its own line, in this order: the author
+    /// name, the title, the description, each field as `name: value`, and the
+    /// footer text. Everything else, such as URLs and images, is left out.
+    pub fn to_plain_text(&self) -> String {
+        let mut lines = Vec::new();
+
+        if let Some(ref author) = self.author {
+            lines.push(author.name.clone());
+        }
+
+        if let Some(ref title) = self.title {
+            lines.push(title.clone());
+        }
+
+        if let Some(ref description) = self.description {
+            lines.push(description.clone());
+        }
+
+        for field in &self.fields {
+            lines.push(format!("{}: {}", field.name, field.value));
+        }
+
+        if let Some(ref footer) = self.footer {
+            lines.push(footer.text.clone());
+        }
+
+        lines.join("\n")
+    }
+
+    /// Counts the unicode code points of the embed's text in the way Discord
+    /// does for its limit of 6000 per embed: the title, description, field
+    /// names and values, footer text, and author name.
+    ///
+    /// This is useful for checking whether a received embed can be re-sent.
+    /// Refer to [`CreateEmbed::len`] for the same count on a builder.
+    ///
+    /// [`CreateEmbed::len`]: ../builder/struct.CreateEmbed.html#method.len
+    pub fn total_length(&self) -> usize {
+        let count = |text: &Option<String>| text.as_ref().map_or(0, |text| text.chars().count());
+
+        let fields = self.fields
+            .iter()
+            .map(|field| field.name.chars().count() + field.value.chars().count())
+            .sum::<usize>();
+        let author = self.author
+            .as_ref()
+            .map_or(0, |author| author.name.chars().count());
+        let footer = self.footer
+            .as_ref()
+            .map_or(0, |footer| footer.text.chars().count());
+
+        count(&self.title) + count(&self.description) + fields + author + footer
+    }
+}
+
+#[cfg(feature = "model")]
+fn dimensions(width: u64, height: u64) -> Option<(u64, u64)> {
+    if width == 0 || height == 0 {
+        None
+    } else {
+        Some((width, height))
+    }
 }
 
 /// An author object in an embed.
@@ -140,6 +279,9 @@ pub struct EmbedFooter {
 #[derive(Clone, Debug, Deserialize)]
 pub struct EmbedImage {
     /// The height of the image.
+    ///
+    /// This is `0` if Discord did not provide the height.
+    #[serde(default)]
     pub height: u64,
     /// A proxied URL of the image.
     pub proxy_url: String,
@@ -148,6 +290,9 @@ pub struct EmbedImage {
     /// This only supports HTTP(S).
     pub url: String,
     /// The width of the image.
+    ///
+    /// This is `0` if Discord did not provide the width.
+    #[serde(default)]
     pub width: u64,
 }
 
@@ -164,6 +309,9 @@ pub struct EmbedProvider {
 #[derive(Clone, Debug, Deserialize)]
 pub struct EmbedThumbnail {
     /// The height of the thumbnail in pixels.
+    ///
+    /// This is `0` if Discord did not provide the height.
+    #[serde(default)]
     pub height: u64,
     /// A proxied URL of the thumbnail.
     pub proxy_url: String,
@@ -172,6 +320,9 @@ pub struct EmbedThumbnail {
     /// This only supports HTTP(S).
     pub url: String,
     /// The width of the thumbnail in pixels.
+    ///
+    /// This is `0` if Discord did not provide the width.
+    #[serde(default)]
     pub width: u64,
 }
 
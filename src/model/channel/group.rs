@@ -4,13 +4,23 @@ use model::*;
 #[cfg(feature = "model")]
 use builder::{CreateMessage, GetMessages};
 #[cfg(feature = "model")]
+use constants;
+#[cfg(feature = "model")]
 use http::{self, AttachmentType};
 #[cfg(feature = "model")]
+use internal::prelude::*;
+#[cfg(feature = "model")]
 use internal::RwLockExt;
 #[cfg(feature = "model")]
 use std::borrow::Cow;
+#[cfg(all(feature = "model", feature = "utils"))]
+use std::io::Read;
+#[cfg(all(feature = "model", feature = "utils"))]
+use base64;
 #[cfg(feature = "model")]
 use std::fmt::Write as FmtWrite;
+#[cfg(all(feature = "cache", feature = "model"))]
+use CACHE;
 
 /// A group channel - potentially including other [`User`]s - separate from a
 /// [`Guild`].
@@ -47,7 +57,14 @@ impl Group {
     /// **Note**: Groups have a limit of 10 recipients, including the current
     /// user.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::GroupFull`] if the group already has the
+    /// maximum number of recipients, as counted by [`recipient_count`].
+    ///
+    /// [`ModelError::GroupFull`]: enum.ModelError.html#variant.GroupFull
     /// [`http::add_group_recipient`]: ../http/fn.add_group_recipient.html
+    /// [`recipient_count`]: #method.recipient_count
     pub fn add_recipient<U: Into<UserId>>(&self, user: U) -> Result<()> {
         let user = user.into();
 
@@ -56,6 +73,10 @@ impl Group {
             return Ok(());
         }
 
+        if self.recipient_count() >= constants::GROUP_RECIPIENT_LIMIT as usize {
+            return Err(Error::Model(ModelError::GroupFull));
+        }
+
         http::add_group_recipient(self.channel_id.0, user.0)
     }
 
@@ -63,6 +84,32 @@ impl Group {
     #[inline]
     pub fn broadcast_typing(&self) -> Result<()> { self.channel_id.broadcast_typing() }
 
+    /// Returns the Ids of the users connected to a call in the group, sorted
+    /// by Id.
+    ///
+    /// The participants are read from the [`Cache`]'s [`voice_states`], and
+    /// so this is always empty if the `cache` is disabled.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`voice_states`]: ../cache/struct.Cache.html#structfield.voice_states
+    pub fn call_participants(&self) -> Vec<UserId> {
+        feature_cache! {{
+            let mut participants = CACHE
+                .read()
+                .unwrap()
+                .voice_states
+                .values()
+                .filter(|state| state.channel_id == Some(self.channel_id))
+                .map(|state| state.user_id)
+                .collect::<Vec<UserId>>();
+            participants.sort();
+
+            participants
+        } else {
+            vec![]
+        }}
+    }
+
     /// React to a [`Message`] with a custom [`Emoji`] or unicode character.
     ///
     /// [`Message::react`] may be a more suited method of reacting in most
@@ -81,6 +128,22 @@ impl Group {
         self.channel_id.create_reaction(message_id, reaction_type)
     }
 
+    /// React to a [`Message`] with multiple reactions, applying them one after
+    /// the other in the given order.
+    ///
+    /// Refer to [`ChannelId::create_reactions`] for more information.
+    ///
+    /// [`ChannelId::create_reactions`]: struct.ChannelId.html#method.create_reactions
+    /// [`Message`]: struct.Message.html
+    #[inline]
+    pub fn create_reactions<M, R, It>(&self,
+                                      message_id: M,
+                                      reactions: It)
+                                      -> StdResult<(), (usize, Error)>
+        where M: Into<MessageId>, R: Into<ReactionType>, It: IntoIterator<Item=R> {
+        self.channel_id.create_reactions(message_id, reactions)
+    }
+
     /// Deletes all messages by Ids from the given vector in the channel.
     ///
     /// Refer to [`Channel::delete_messages`] for more information.
@@ -96,7 +159,8 @@ impl Group {
     /// [`Channel::delete_messages`]: enum.Channel.html#method.delete_messages
     /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
     #[inline]
-    pub fn delete_messages<T: AsRef<MessageId>, It: IntoIterator<Item=T>>(&self, message_ids: It) -> Result<()> {
+    pub fn delete_messages<T, It>(&self, message_ids: It) -> Result<()>
+        where T: Into<MessageId>, It: IntoIterator<Item=T> {
         self.channel_id.delete_messages(message_ids)
     }
 
@@ -154,6 +218,77 @@ impl Group {
         self.channel_id.edit_message(message_id, f)
     }
 
+    /// Sets or removes the icon of the group, updating the local [`icon`] on
+    /// success.
+    ///
+    /// The image is read and base64-encoded from the given reader. Passing
+    /// `None` removes the icon.
+    ///
+    /// # Examples
+    ///
+    /// Set the icon of a group from an image on the filesystem:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::Group;
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main(group: &mut Group) -> Result<(), Box<Error>> {
+    /// use std::fs::File;
+    ///
+    /// group.edit_icon(Some(File::open("./icon.png")?))?;
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if the image could not be read.
+    ///
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    /// [`icon`]: #structfield.icon
+    #[cfg(feature = "utils")]
+    pub fn edit_icon<R: Read>(&mut self, icon: Option<R>) -> Result<()> {
+        let icon = match icon {
+            Some(mut reader) => {
+                let mut bytes = vec![];
+                reader.read_to_end(&mut bytes)?;
+
+                Value::String(format!(
+                    "data:image/{};base64,{}",
+                    image_format(&bytes),
+                    base64::encode(&bytes)
+                ))
+            },
+            None => Value::Null,
+        };
+
+        let mut map = Map::new();
+        map.insert("icon".to_string(), icon);
+
+        self.edit(map)
+    }
+
+    /// Renames the group, updating the local [`name`] on success.
+    ///
+    /// [`name`]: #structfield.name
+    pub fn edit_name(&mut self, name: &str) -> Result<()> {
+        let mut map = Map::new();
+        map.insert("name".to_string(), Value::String(name.to_string()));
+
+        self.edit(map)
+    }
+
+    /// Whether a call is ongoing in the group, as in whether any users are
+    /// connected to it.
+    ///
+    /// Refer to [`call_participants`] for more information.
+    ///
+    /// [`call_participants`]: #method.call_participants
+    #[inline]
+    pub fn has_active_call(&self) -> bool { !self.call_participants().is_empty() }
+
     /// Returns the formatted URI of the group's icon if one exists.
     pub fn icon_url(&self) -> Option<String> {
         self.icon.as_ref().map(|icon| {
@@ -172,9 +307,28 @@ impl Group {
     #[inline]
     pub fn is_nsfw(&self) -> bool { false }
 
-    /// Leaves the group.
+    /// Whether the current user is the owner of the group, and so is able to
+    /// perform owner-only actions such as [`remove_recipient`].
+    ///
+    /// [`remove_recipient`]: #method.remove_recipient
+    #[cfg(feature = "cache")]
     #[inline]
-    pub fn leave(&self) -> Result<Group> { http::leave_group(self.channel_id.0) }
+    pub fn is_owner(&self) -> bool { self.owner_id == CACHE.read().unwrap().user.id }
+
+    /// Leaves the group.
+    ///
+    /// If the `cache` is enabled, the group is also removed from the
+    /// [`Cache`] once it was left, as a best-effort local consistency measure.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    pub fn leave(&self) -> Result<Group> {
+        let group = http::leave_group(self.channel_id.0)?;
+
+        #[cfg(feature = "cache")]
+        CACHE.write().unwrap().remove_channel(self.channel_id);
+
+        Ok(group)
+    }
 
     /// Gets a message from the channel.
     ///
@@ -193,25 +347,42 @@ impl Group {
     /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
     #[inline]
     pub fn messages<F>(&self, f: F) -> Result<Vec<Message>>
-        where F: FnOnce(&GetMessages) -> &GetMessages {
+        where F: FnOnce(GetMessages) -> GetMessages {
         self.channel_id.messages(f)
     }
 
+    /// Creates an iterator over the channel's messages, from newest to
+    /// oldest.
+    ///
+    /// Refer to [`ChannelId::messages_iter`] for more information.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// [`ChannelId::messages_iter`]: struct.ChannelId.html#method.messages_iter
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    #[inline]
+    pub fn messages_iter(&self) -> MessagesIter { self.channel_id.messages_iter() }
+
     /// Generates a name for the group.
     ///
     /// If there are no recipients in the group, the name will be "Empty Group".
     /// Otherwise, the name is generated in a Comma Separated Value list, such
-    /// as "person 1, person 2, person 3".
+    /// as "person 1, person 2, person 3", with the recipients ordered as by
+    /// [`recipients_sorted`].
+    ///
+    /// [`recipients_sorted`]: #method.recipients_sorted
     pub fn name(&self) -> Cow<str> {
         match self.name {
             Some(ref name) => Cow::Borrowed(name),
             None => {
-                let mut name = match self.recipients.values().nth(0) {
+                let recipients = self.recipients_sorted();
+
+                let mut name = match recipients.first() {
                     Some(recipient) => recipient.with(|c| c.name.clone()),
                     None => return Cow::Borrowed("Empty Group"),
                 };
 
-                for recipient in self.recipients.values().skip(1) {
+                for recipient in recipients.iter().skip(1) {
                     let _ = write!(name, ", {}", recipient.with(|r| r.name.clone()));
                 }
 
@@ -220,6 +391,47 @@ impl Group {
         }
     }
 
+    /// Retrieves the owner of the group.
+    ///
+    /// The owner is looked up in the group's [`recipients`], and - if the
+    /// `cache` is enabled - in the [`Cache`]'s users, as the current user is
+    /// not one of the recipients. Returns `None` if the owner could not be
+    /// found in either.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`recipients`]: #structfield.recipients
+    pub fn owner(&self) -> Option<Arc<RwLock<User>>> {
+        if let Some(owner) = self.recipients.get(&self.owner_id) {
+            return Some(Arc::clone(owner));
+        }
+
+        feature_cache! {{
+            CACHE.read().unwrap().user(self.owner_id)
+        } else {
+            None
+        }}
+    }
+
+    /// Pins a [`Message`] to the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPins`] if the group already has the
+    /// maximum number of pinned messages, without attempting to pin the
+    /// message.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`ModelError::TooManyPins`]: enum.ModelError.html#variant.TooManyPins
+    pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        self.channel_id.pin_checked(message_id)
+    }
+
+    /// Retrieves the number of [`Message`]s which are pinned to the group.
+    ///
+    /// [`Message`]: struct.Message.html
+    #[inline]
+    pub fn pin_count(&self) -> Result<usize> { self.channel_id.pin_count() }
+
     /// Retrieves the list of messages that have been pinned in the group.
     #[inline]
     pub fn pins(&self) -> Result<Vec<Message>> { self.channel_id.pins() }
@@ -248,6 +460,28 @@ impl Group {
             .reaction_users(message_id, reaction_type, limit, after)
     }
 
+    /// Retrieves the number of recipients in the group, including the current
+    /// user, who is not one of the group's [`recipients`].
+    ///
+    /// [`recipients`]: #structfield.recipients
+    #[inline]
+    pub fn recipient_count(&self) -> usize { self.recipients.len() + 1 }
+
+    /// Retrieves the group's recipients, sorted by their Ids.
+    ///
+    /// Unlike iterating over [`recipients`], the order is the same every time.
+    ///
+    /// [`recipients`]: #structfield.recipients
+    pub fn recipients_sorted(&self) -> Vec<Arc<RwLock<User>>> {
+        let mut recipients = self.recipients.iter().collect::<Vec<_>>();
+        recipients.sort_by_key(|&(id, _)| *id);
+
+        recipients
+            .into_iter()
+            .map(|(_, recipient)| Arc::clone(recipient))
+            .collect()
+    }
+
     /// Removes a recipient from the group. If the recipient is already not in
     /// the group, then nothing is done.
     ///
@@ -314,6 +548,15 @@ impl Group {
         self.channel_id.send_message(f)
     }
 
+    /// Broadcasts that the current user is typing in the group until the
+    /// returned guard is dropped.
+    ///
+    /// Refer to [`Typing`] for more information.
+    ///
+    /// [`Typing`]: struct.Typing.html
+    #[inline]
+    pub fn typing(&self) -> Result<Typing> { self.channel_id.typing() }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
@@ -324,4 +567,26 @@ impl Group {
     pub fn unpin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
         self.channel_id.unpin(message_id)
     }
+
+    fn edit(&mut self, map: JsonMap) -> Result<()> {
+        let group = http::edit_group(self.channel_id.0, &map)?;
+
+        self.icon = group.icon;
+        self.name = group.name;
+
+        Ok(())
+    }
+}
+
+// Discord accepts PNG, GIF and JPEG group icons, so anything that is not
+// recognised as one of the former two is sent as a JPEG.
+#[cfg(all(feature = "model", feature = "utils"))]
+fn image_format(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"\x89PNG") {
+        "png"
+    } else if bytes.starts_with(b"GIF8") {
+        "gif"
+    } else {
+        "jpeg"
+    }
 }
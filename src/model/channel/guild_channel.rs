@@ -1,13 +1,19 @@
 use chrono::{DateTime, FixedOffset};
+#[cfg(feature = "model")]
+use chrono::{Duration, Utc};
 use model::*;
 
 #[cfg(feature = "model")]
 use std::fmt::{Display, Formatter, Result as FmtResult};
 #[cfg(feature = "model")]
+use std::cmp;
+#[cfg(feature = "model")]
 use std::mem;
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
 #[cfg(feature = "model")]
+use constants;
+#[cfg(feature = "model")]
 use builder::{CreateInvite, CreateMessage, EditChannel, GetMessages};
 #[cfg(feature = "model")]
 use http::{self, AttachmentType};
@@ -61,6 +67,12 @@ pub struct GuildChannel {
     /// The default text channel will _almost always_ have a position of `-1` or
     /// `0`.
     pub position: i64,
+    /// The number of seconds a user has to wait between sending messages in
+    /// the channel, also known as slowmode.
+    ///
+    /// **Note**: This is only available for text channels.
+    #[serde(default)]
+    pub rate_limit_per_user: Option<u64>,
     /// The topic of the channel.
     ///
     /// **Note**: This is only available for text channels.
@@ -79,8 +91,61 @@ pub struct GuildChannel {
     pub nsfw: bool,
 }
 
+/// A news channel that was followed into another channel, as returned by
+/// [`GuildChannel::follow`].
+///
+/// [`GuildChannel::follow`]: struct.GuildChannel.html#method.follow
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FollowedChannel {
+    /// The Id of the news channel that was followed.
+    pub channel_id: ChannelId,
+    /// The Id of the webhook created in the target channel, which posts the
+    /// news channel's crossposted messages.
+    pub webhook_id: WebhookId,
+}
+
 #[cfg(feature = "model")]
 impl GuildChannel {
+    /// Calculates the permissions of the current user in the channel.
+    ///
+    /// Refer to [`permissions_for`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Explain why the bot can not post an embed:
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::Permissions;
+    ///
+    /// let missing = channel.missing_permissions(Permissions::EMBED_LINKS)?;
+    ///
+    /// if !missing.is_empty() {
+    ///     println!("I need these permissions here: {:?}", missing);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the current user is not yet
+    /// known to the [`Cache`], or a [`ModelError::GuildNotFound`] if the
+    /// channel's guild could not be found in it.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`ModelError::GuildNotFound`]: enum.ModelError.html#variant.GuildNotFound
+    /// [`ModelError::ItemMissing`]: enum.ModelError.html#variant.ItemMissing
+    /// [`permissions_for`]: #method.permissions_for
+    #[cfg(feature = "cache")]
+    pub fn bot_permissions(&self) -> Result<Permissions> {
+        let user_id = CACHE.read().unwrap().user.id;
+
+        // The current user is only known once the `Ready` event is received.
+        if user_id.0 == 0 {
+            return Err(Error::Model(ModelError::ItemMissing));
+        }
+
+        self.permissions_for(user_id)
+    }
+
     /// Broadcasts to the channel that the current user is typing.
     ///
     /// For bots, this is a good indicator for long-running commands.
@@ -96,6 +161,84 @@ impl GuildChannel {
     /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
     pub fn broadcast_typing(&self) -> Result<()> { self.id.broadcast_typing() }
 
+    /// Retrieves the [`ChannelCategory`] that the channel is in from the
+    /// [`Cache`], if it is in one.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`ChannelCategory`]: struct.ChannelCategory.html
+    #[cfg(feature = "cache")]
+    pub fn category(&self) -> Option<Arc<RwLock<ChannelCategory>>> {
+        self.category_id
+            .and_then(|category_id| CACHE.read().unwrap().categories(category_id))
+    }
+
+    /// Retrieves the [`ChannelCategory`] that the channel is in over the REST
+    /// API, if it is in one.
+    ///
+    /// [`ChannelCategory`]: struct.ChannelCategory.html
+    pub fn category_http(&self) -> Result<Option<ChannelCategory>> {
+        let category_id = match self.category_id {
+            Some(category_id) => category_id,
+            None => return Ok(None),
+        };
+
+        let category = match http::get_channel(category_id.0)? {
+            Channel::Category(category) => category,
+            _ => return Ok(None),
+        };
+        let category = category.read().unwrap().clone();
+
+        Ok(Some(category))
+    }
+
+    /// Creates a new channel in the same guild and category, copying the
+    /// kind, topic, NSFW indicator, [`PermissionOverwrite`]s, and slowmode of
+    /// this channel.
+    ///
+    /// Messages are not copied.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let copy = channel.clone_channel("general-2")?;
+    ///
+    /// println!("Created {}", copy.mention());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`PermissionOverwrite`]: struct.PermissionOverwrite.html
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn clone_channel(&self, name: &str) -> Result<GuildChannel> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_CHANNELS;
+
+            if !utils::user_has_perms(self.id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        let map = json!({
+            "name": name,
+            "nsfw": self.nsfw,
+            "parent_id": self.category_id,
+            "permission_overwrites": self.permission_overwrites,
+            "rate_limit_per_user": self.rate_limit_per_user.unwrap_or(0),
+            "topic": self.topic,
+            "type": self.kind,
+        });
+
+        http::create_channel(self.guild_id.0, &map)
+    }
+
     /// Creates an invite leading to the given channel.
     ///
     /// # Examples
@@ -230,6 +373,13 @@ impl GuildChannel {
     }
 
     /// Deletes this channel, returning the channel on a successful deletion.
+    ///
+    /// If the `cache` is enabled, the channel is also removed from the
+    /// [`Cache`]'s channel map and from its guild's channels once the deletion
+    /// succeeded. Refer to [`ChannelId::delete`] for more information.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`ChannelId::delete`]: struct.ChannelId.html#method.delete
     pub fn delete(&self) -> Result<Channel> {
         #[cfg(feature = "cache")]
         {
@@ -258,7 +408,8 @@ impl GuildChannel {
     /// [`Channel::delete_messages`]: enum.Channel.html#method.delete_messages
     /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
     #[inline]
-    pub fn delete_messages<'a, It: IntoIterator<Item=&'a MessageId>>(&self, message_ids: It) -> Result<()> {
+    pub fn delete_messages<T, It>(&self, message_ids: It) -> Result<()>
+        where T: Into<MessageId>, It: IntoIterator<Item=T> {
         self.id.delete_messages(message_ids)
     }
 
@@ -290,10 +441,37 @@ impl GuildChannel {
         self.id.delete_reaction(message_id, user_id, reaction_type)
     }
 
+    /// Denies permissions to a member or role in the channel, keeping the
+    /// rest of their existing permission overwrite.
+    ///
+    /// The given permissions are added to the overwrite's denied permissions
+    /// and removed from its allowed permissions, and the merged overwrite is
+    /// then sent in place of the existing one. The existing overwrite is read
+    /// from the channel's [`permission_overwrites`].
+    ///
+    /// Refer to [`create_permission`] for the permissions required.
+    ///
+    /// [`create_permission`]: #method.create_permission
+    /// [`permission_overwrites`]: #structfield.permission_overwrites
+    pub fn deny_permissions(&self,
+                            target: PermissionOverwriteType,
+                            deny: Permissions)
+                            -> Result<()> {
+        let mut overwrite = self.overwrite_or_empty(target);
+        overwrite.forbid(deny);
+
+        self.create_permission(&overwrite)
+    }
+
     /// Modifies a channel's settings, such as its position or name.
     ///
     /// Refer to `EditChannel`s documentation for a full list of methods.
     ///
+    /// The focused setters, such as [`set_topic`], send only the field they
+    /// set and leave the channel's other settings untouched.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
     /// # Examples
     ///
     /// Change a voice channels name and bitrate:
@@ -301,17 +479,11 @@ impl GuildChannel {
     /// ```rust,ignore
     /// channel.edit(|c| c.name("test").bitrate(86400));
     /// ```
+    ///
+    /// [`set_topic`]: #method.set_topic
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
     pub fn edit<F>(&mut self, f: F) -> Result<()>
         where F: FnOnce(EditChannel) -> EditChannel {
-        #[cfg(feature = "cache")]
-        {
-            let req = Permissions::MANAGE_CHANNELS;
-
-            if !utils::user_has_perms(self.id, req)? {
-                return Err(Error::Model(ModelError::InvalidPermissions(req)));
-            }
-        }
-
         let mut map = Map::new();
         map.insert("name".to_string(), Value::String(self.name.clone()));
         map.insert(
@@ -323,9 +495,9 @@ impl GuildChannel {
             Value::String(self.kind.name().to_string()),
         );
 
-        let edited = f(EditChannel(map)).0;
+        let edited = f(EditChannel(map));
 
-        match http::edit_channel(self.id.0, &edited) {
+        match self.edit_fields(&edited) {
             Ok(channel) => {
                 mem::replace(self, channel);
 
@@ -360,6 +532,153 @@ impl GuildChannel {
         self.id.edit_message(message_id, f)
     }
 
+    /// Moves the channel to a new position among its sibling channels, those
+    /// of the same kind and in the same category, shifting the siblings so
+    /// that their positions stay contiguous.
+    ///
+    /// The current ordering is retrieved from the [`Cache`] if possible, and
+    /// otherwise over the REST API. Only the channels whose positions change
+    /// are updated. Refer to [`position_updates`] for how the new ordering is
+    /// computed.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have the required permissions.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`position_updates`]: #method.position_updates
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn edit_position(&self, new_position: u64) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_CHANNELS;
+
+            if !utils::user_has_perms(self.id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        let cached: Option<Vec<GuildChannel>> = feature_cache! {{
+            self.guild().map(|guild| {
+                let guild = guild.read().unwrap();
+
+                guild
+                    .channels
+                    .values()
+                    .map(|channel| channel.read().unwrap().clone())
+                    .collect::<Vec<_>>()
+            })
+        } else {
+            None
+        }};
+
+        let channels = match cached {
+            Some(channels) => channels,
+            None => self.guild_id
+                .channels()?
+                .into_iter()
+                .map(|(_, channel)| channel)
+                .collect(),
+        };
+
+        let updates = self.position_updates(&channels, new_position);
+
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        let map = json!(updates
+            .iter()
+            .map(|&(id, position)| json!({
+                "id": id,
+                "position": position,
+            }))
+            .collect::<Vec<_>>());
+
+        http::edit_guild_channel_positions(self.guild_id.0, &map)
+    }
+
+    /// Follows the news channel into the target channel, so that messages
+    /// crossposted in this channel are posted in the target channel through a
+    /// webhook.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission in the target
+    /// channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if this channel is not a
+    /// [news channel][`is_news`].
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidChannelType`]: enum.ModelError.html#variant.InvalidChannelType
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`is_news`]: #method.is_news
+    /// [Manage Webhooks]: permissions/constant.MANAGE_WEBHOOKS.html
+    pub fn follow<C: Into<ChannelId>>(&self, target_channel: C) -> Result<FollowedChannel> {
+        if !self.is_news() {
+            return Err(Error::Model(ModelError::InvalidChannelType(self.kind)));
+        }
+
+        let target_channel = target_channel.into();
+
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_WEBHOOKS;
+
+            if !utils::user_has_perms(target_channel, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        let map = json!({
+            "webhook_channel_id": target_channel.0.to_string(),
+        });
+
+        http::follow_news_channel(self.id.0, &map)
+    }
+
+    /// Grants permissions to a member or role in the channel, keeping the
+    /// rest of their existing permission overwrite.
+    ///
+    /// The given permissions are added to the overwrite's allowed permissions
+    /// and removed from its denied permissions, and the merged overwrite is
+    /// then sent in place of the existing one. The existing overwrite is read
+    /// from the channel's [`permission_overwrites`].
+    ///
+    /// Refer to [`create_permission`] for the permissions required.
+    ///
+    /// # Examples
+    ///
+    /// Let a member send messages, without affecting whatever else their
+    /// overwrite allows or denies:
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::{PermissionOverwriteType, Permissions};
+    ///
+    /// let target = PermissionOverwriteType::Member(user_id);
+    /// channel.grant_permissions(target, Permissions::SEND_MESSAGES)?;
+    /// ```
+    ///
+    /// [`create_permission`]: #method.create_permission
+    /// [`permission_overwrites`]: #structfield.permission_overwrites
+    pub fn grant_permissions(&self,
+                             target: PermissionOverwriteType,
+                             allow: Permissions)
+                             -> Result<()> {
+        let mut overwrite = self.overwrite_or_empty(target);
+        overwrite.grant(allow);
+
+        self.create_permission(&overwrite)
+    }
+
     /// Attempts to find this channel's guild in the Cache.
     ///
     /// **Note**: Right now this performs a clone of the guild. This will be
@@ -374,6 +693,15 @@ impl GuildChannel {
     #[inline]
     pub fn invites(&self) -> Result<Vec<RichInvite>> { self.id.invites() }
 
+    /// Whether the channel is a news channel, whose messages can be
+    /// crossposted to the guilds following it.
+    ///
+    /// Refer to [`Message::crosspost`] for crossposting messages.
+    ///
+    /// [`Message::crosspost`]: struct.Message.html#method.crosspost
+    #[inline]
+    pub fn is_news(&self) -> bool { self.kind == ChannelType::News }
+
     /// Determines if the channel is NSFW.
     ///
     /// Refer to [`utils::is_nsfw`] for more details.
@@ -390,6 +718,51 @@ impl GuildChannel {
         self.kind == ChannelType::Text && (self.nsfw || serenity_utils::is_nsfw(&self.name))
     }
 
+    /// Retrieves the [`Member`]s of the channel from the [`Cache`].
+    ///
+    /// For voice channels, these are the members currently connected to the
+    /// channel. For other channels, these are the members that have the
+    /// [Read Messages] permission in the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::GuildNotFound`] if the channel's guild could
+    /// not be found in the [`Cache`].
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`Member`]: struct.Member.html
+    /// [`ModelError::GuildNotFound`]: enum.ModelError.html#variant.GuildNotFound
+    /// [Read Messages]: permissions/constant.READ_MESSAGES.html
+    #[cfg(feature = "cache")]
+    pub fn members(&self) -> Result<Vec<Member>> {
+        let guild = match self.guild() {
+            Some(guild) => guild,
+            None => return Err(Error::Model(ModelError::GuildNotFound)),
+        };
+        let guild = guild.read().unwrap();
+
+        let members = match self.kind {
+            ChannelType::Voice => guild
+                .voice_states
+                .values()
+                .filter(|state| state.channel_id == Some(self.id))
+                .filter_map(|state| guild.members.get(&state.user_id).cloned())
+                .collect(),
+            _ => guild
+                .members
+                .iter()
+                .filter(|&(user_id, _)| {
+                    guild
+                        .permissions_for(self.id, *user_id)
+                        .contains(Permissions::READ_MESSAGES)
+                })
+                .map(|(_, member)| member.clone())
+                .collect(),
+        };
+
+        Ok(members)
+    }
+
     /// Gets a message from the channel.
     ///
     /// Requires the [Read Message History] permission.
@@ -410,13 +783,60 @@ impl GuildChannel {
     /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
     #[inline]
     pub fn messages<F>(&self, f: F) -> Result<Vec<Message>>
-        where F: FnOnce(&GetMessages) -> &GetMessages {
+        where F: FnOnce(GetMessages) -> GetMessages {
         self.id.messages(f)
     }
 
+    /// Creates an iterator over the channel's messages, from newest to
+    /// oldest.
+    ///
+    /// Refer to [`ChannelId::messages_iter`] for more information.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// [`ChannelId::messages_iter`]: struct.ChannelId.html#method.messages_iter
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    #[inline]
+    pub fn messages_iter(&self) -> MessagesIter { self.id.messages_iter() }
+
+    /// Returns the subset of the given permissions that the current user
+    /// does not have in the channel.
+    ///
+    /// Refer to [`bot_permissions`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`bot_permissions`].
+    ///
+    /// [`bot_permissions`]: #method.bot_permissions
+    #[cfg(feature = "cache")]
+    pub fn missing_permissions(&self, required: Permissions) -> Result<Permissions> {
+        Ok(required - self.bot_permissions()?)
+    }
+
     /// Returns the name of the guild channel.
     pub fn name(&self) -> &str { &self.name }
 
+    /// Retrieves the [`PermissionOverwrite`] of the channel for the given
+    /// member or role, if one exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::{PermissionOverwriteType, RoleId};
+    ///
+    /// if let Some(overwrite) = channel.overwrite_for(PermissionOverwriteType::Role(RoleId(7))) {
+    ///     println!("Allowed: {:?}, denied: {:?}", overwrite.allow, overwrite.deny);
+    /// }
+    /// ```
+    ///
+    /// [`PermissionOverwrite`]: struct.PermissionOverwrite.html
+    pub fn overwrite_for(&self, target: PermissionOverwriteType) -> Option<&PermissionOverwrite> {
+        self.permission_overwrites
+            .iter()
+            .find(|overwrite| overwrite.kind == target)
+    }
+
     /// Calculates the permissions of a member.
     ///
     /// The Id of the argument must be a [`Member`] of the [`Guild`] that the
@@ -516,13 +936,135 @@ impl GuildChannel {
     }
 
     /// Pins a [`Message`] to the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has the
+    /// maximum number of pinned messages, without attempting to pin the
+    /// message.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`ModelError::TooManyPins`]: enum.ModelError.html#variant.TooManyPins
+    pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        self.id.pin_checked(message_id)
+    }
+
+    /// Pins a [`Message`] to the channel like [`pin`], and then retrieves the
+    /// channel's pinned messages, including the newly pinned one.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`pin`].
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`pin`]: #method.pin
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn pin_and_list<M: Into<MessageId>>(&self, message_id: M) -> Result<Vec<Message>> {
+        self.pin(message_id)?;
+
+        self.id.pins()
+    }
+
+    /// Retrieves the number of [`Message`]s which are pinned to the channel.
+    ///
+    /// [`Message`]: struct.Message.html
     #[inline]
-    pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> { self.id.pin(message_id) }
+    pub fn pin_count(&self) -> Result<usize> { self.id.pin_count() }
 
     /// Gets all channel's pins.
     #[inline]
     pub fn pins(&self) -> Result<Vec<Message>> { self.id.pins() }
 
+    /// Computes the position updates needed to move the channel to a new
+    /// position among its siblings in the given channels, as used by
+    /// [`edit_position`].
+    ///
+    /// The siblings - the channels of the same kind and in the same category
+    /// - are ordered by their current position, the channel is placed at the
+    /// new position, and every channel is then given a contiguous position
+    /// starting from `0`. Positions past the end place the channel last.
+    ///
+    /// Only the channels whose positions change are returned, with their new
+    /// positions.
+    ///
+    /// [`edit_position`]: #method.edit_position
+    pub fn position_updates(&self,
+                            channels: &[GuildChannel],
+                            new_position: u64)
+                            -> Vec<(ChannelId, i64)> {
+        let mut siblings = channels
+            .iter()
+            .filter(|channel| {
+                channel.id != self.id && channel.kind == self.kind
+                    && channel.category_id == self.category_id
+            })
+            .map(|channel| (channel.id, channel.position))
+            .collect::<Vec<_>>();
+        siblings.sort_by_key(|&(id, position)| (position, id));
+
+        let index = cmp::min(new_position as usize, siblings.len());
+        siblings.insert(index, (self.id, self.position));
+
+        siblings
+            .into_iter()
+            .enumerate()
+            .filter(|&(index, (_, position))| index as i64 != position)
+            .map(|(index, (id, _))| (id, index as i64))
+            .collect()
+    }
+
+    /// Deletes the messages among the channel's `limit` most recent messages
+    /// that match the given filter, returning the number of messages deleted.
+    ///
+    /// Unlike [`ChannelId::purge`], messages older than two weeks are skipped
+    /// rather than deleted one at a time, as Discord does not allow them to be
+    /// bulk deleted. The remaining messages are bulk deleted in groups of up
+    /// to 100.
+    ///
+    /// Requires the [Read Message History] and [Manage Messages] permissions.
+    ///
+    /// # Examples
+    ///
+    /// Delete the messages sent by bots among the last 200 messages:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::GuildChannel;
+    /// #
+    /// # fn prune(channel: GuildChannel) {
+    /// let _ = channel.prune_messages(200, |m| m.author.bot);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ChannelId::purge`]: struct.ChannelId.html#method.purge
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn prune_messages<F>(&self, limit: u64, filter: F) -> Result<usize>
+        where F: Fn(&Message) -> bool {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_MESSAGES;
+
+            if !utils::user_has_perms(self.id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        let cutoff = Utc::now().naive_utc() - Duration::weeks(2);
+
+        self.id
+            .purge_with(limit as usize, false, |m| m.id.created_at() > cutoff && filter(m))
+            .map(|report| report.deleted)
+    }
+
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
     /// certain [`Emoji`].
     ///
@@ -546,18 +1088,83 @@ impl GuildChannel {
             .reaction_users(message_id, reaction_type, limit, after)
     }
 
+    /// Retrieves up to `n` of the channel's most recent messages from the
+    /// [`Cache`], ordered from oldest to newest, without making any request.
+    ///
+    /// The cache only holds messages received while it was running, and at
+    /// most [`MESSAGE_CACHE_LIMIT`] messages per channel, so fewer than `n`
+    /// messages may be returned even if the channel has more. Use
+    /// [`recent_messages_http`] when the messages must be complete and
+    /// up-to-date.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`MESSAGE_CACHE_LIMIT`]: ../constants/constant.MESSAGE_CACHE_LIMIT.html
+    /// [`recent_messages_http`]: #method.recent_messages_http
+    #[cfg(feature = "cache")]
+    pub fn recent_messages(&self, n: usize) -> Vec<Message> {
+        CACHE
+            .read()
+            .unwrap()
+            .messages
+            .get(&self.id)
+            .map_or_else(Vec::new, |messages| {
+                let skip = messages.len().saturating_sub(n);
+
+                messages.iter().skip(skip).cloned().collect()
+            })
+    }
+
+    /// Retrieves up to `n` of the channel's most recent messages over the REST
+    /// API, ordered from oldest to newest like [`recent_messages`].
+    ///
+    /// At most 100 messages can be retrieved, and larger amounts are reduced.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [`recent_messages`]: #method.recent_messages
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn recent_messages_http(&self, n: usize) -> Result<Vec<Message>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut messages = self.id.messages(|g| g.limit(n as u64))?;
+        // Messages are received from newest to oldest.
+        messages.reverse();
+
+        Ok(messages)
+    }
+
+    /// Returns the channel's slowmode in seconds, if it has one set.
+    ///
+    /// Refer to [`rate_limit_per_user`] for more information.
+    ///
+    /// [`rate_limit_per_user`]: #structfield.rate_limit_per_user
+    pub fn slowmode(&self) -> Option<u64> {
+        self.rate_limit_per_user.and_then(|seconds| if seconds == 0 { None } else { Some(seconds) })
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
     /// # Errors
     ///
     /// Returns a [`ModelError::MessageTooLong`] if the content of the message
     /// is over the above limit, containing the number of unicode code points
     /// over the limit.
     ///
-    /// [`ChannelId`]: struct.ChannelId.html
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions, without making a request.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
     /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
     #[inline]
-    pub fn say(&self, content: &str) -> Result<Message> { self.id.say(content) }
+    pub fn say(&self, content: &str) -> Result<Message> {
+        self.send_message(|m| m.content(content))
+    }
 
     /// Sends (a) file(s) along with optional message contents.
     ///
@@ -595,26 +1202,286 @@ impl GuildChannel {
     /// is over the above limit, containing the number of unicode code points
     /// over the limit.
     ///
-    /// Returns a [`ModelError::InvalidPermissions`] if the current user does
-    /// not have the required permissions.
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] containing the missing permissions
+    /// if the current user does not have the required permissions, without
+    /// making a request. Use [`send_message_unchecked`] to skip this check.
     ///
     /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
     /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
     /// [`Message`]: struct.Message.html
+    /// [`send_message_unchecked`]: #method.send_message_unchecked
     /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
     pub fn send_message<F: FnOnce(CreateMessage) -> CreateMessage>(&self, f: F) -> Result<Message> {
         #[cfg(feature = "cache")]
         {
             let req = Permissions::SEND_MESSAGES;
+            let current_user_id = CACHE.read().unwrap().user.id;
+            let missing = req - self.permissions_for(current_user_id)?;
+
+            if !missing.is_empty() {
+                return Err(Error::Model(ModelError::InvalidPermissions(missing)));
+            }
+        }
+
+        self.id.send_message(f)
+    }
+
+    /// Sends a message to the channel, like [`send_message`], but without
+    /// checking the permissions of the current user in the [`Cache`] first.
+    ///
+    /// This avoids a cache lookup, at the cost of a missing permission being
+    /// reported by Discord instead.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the above limit, containing the number of unicode code points
+    /// over the limit.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
+    /// [`send_message`]: #method.send_message
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[inline]
+    pub fn send_message_unchecked<F>(&self, f: F) -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage {
+        self.id.send_message(f)
+    }
+
+    /// Sets the bitrate of the voice channel in bits per second, without
+    /// editing any of its other settings.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Examples
+    ///
+    /// Lower the bitrate of a voice channel to save bandwidth:
+    ///
+    /// ```rust,ignore
+    /// channel.set_bitrate(32000)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if the channel is not a
+    /// voice channel, or a [`ModelError::BitrateAmount`] if the bitrate is
+    /// not between 8000 and 96000.
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::BitrateAmount`]: enum.ModelError.html#variant.BitrateAmount
+    /// [`ModelError::InvalidChannelType`]: enum.ModelError.html#variant.InvalidChannelType
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn set_bitrate(&mut self, bitrate: u64) -> Result<()> {
+        self.check_voice()?;
+
+        let min = u64::from(constants::VOICE_BITRATE_MIN);
+        let max = u64::from(constants::VOICE_BITRATE_MAX);
+
+        if bitrate < min || bitrate > max {
+            return Err(Error::Model(ModelError::BitrateAmount(bitrate)));
+        }
+
+        let edit = EditChannel::default().bitrate(bitrate);
+        self.bitrate = self.edit_fields(&edit)?.bitrate;
+
+        Ok(())
+    }
+
+    /// Moves the channel into the given category, or out of its category if
+    /// `None` is given, without editing any of its other settings.
+    ///
+    /// The channel's [`category_id`] and [`position`] are updated from the
+    /// edited channel.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`category_id`]: #structfield.category_id
+    /// [`position`]: #structfield.position
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn set_category<C: Into<Option<ChannelId>>>(&mut self, category_id: C) -> Result<()> {
+        let edit = EditChannel::default().category(category_id);
+        let edited = self.edit_fields(&edit)?;
+
+        self.category_id = edited.category_id;
+        self.position = edited.position;
+
+        Ok(())
+    }
+
+    /// Sets whether the channel is age-restricted, without editing any of its
+    /// other settings.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn set_nsfw(&mut self, nsfw: bool) -> Result<()> {
+        let edit = EditChannel::default().nsfw(nsfw);
+        self.nsfw = self.edit_fields(&edit)?.nsfw;
+
+        Ok(())
+    }
+
+    /// Sets the topic of the channel, without editing any of its other
+    /// settings.
+    ///
+    /// An empty topic clears the channel's topic.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TopicTooLong`] if the topic is over 1024
+    /// unicode code points, containing the number of code points over the
+    /// limit.
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`ModelError::TopicTooLong`]: enum.ModelError.html#variant.TopicTooLong
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn set_topic<S: AsRef<str>>(&mut self, topic: S) -> Result<()> {
+        let topic = topic.as_ref();
+        let length = topic.chars().count() as u64;
+        let limit = u64::from(constants::CHANNEL_TOPIC_LENGTH);
+
+        if length > limit {
+            return Err(Error::Model(ModelError::TopicTooLong(length - limit)));
+        }
+
+        let edit = EditChannel::default().topic(topic);
+        self.topic = self.edit_fields(&edit)?.topic;
+
+        Ok(())
+    }
+
+    /// Sets the maximum number of users that may be connected to the voice
+    /// channel, without editing any of its other settings.
+    ///
+    /// A limit of `0` removes the limit.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if the channel is not a
+    /// voice channel, or a [`ModelError::UserLimitAmount`] if the limit is
+    /// over 99.
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidChannelType`]: enum.ModelError.html#variant.InvalidChannelType
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`ModelError::UserLimitAmount`]: enum.ModelError.html#variant.UserLimitAmount
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn set_user_limit(&mut self, limit: u64) -> Result<()> {
+        self.check_voice()?;
+
+        if limit > u64::from(constants::VOICE_USER_LIMIT_MAX) {
+            return Err(Error::Model(ModelError::UserLimitAmount(limit)));
+        }
+
+        let edit = EditChannel::default().user_limit(limit);
+        self.user_limit = self.edit_fields(&edit)?.user_limit;
+
+        Ok(())
+    }
+
+    /// Replaces the channel's [`PermissionOverwrite`]s with those of its
+    /// category, like the "Sync Now" option of the Discord client.
+    ///
+    /// The category is taken from the [`Cache`] if possible, and otherwise
+    /// requested over the REST API. All overwrites are replaced in a single
+    /// edit.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::NoCategory`] if the channel is not in a
+    /// category.
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`ModelError::NoCategory`]: enum.ModelError.html#variant.NoCategory
+    /// [`PermissionOverwrite`]: struct.PermissionOverwrite.html
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    pub fn sync_permissions(&mut self) -> Result<()> {
+        if self.category_id.is_none() {
+            return Err(Error::Model(ModelError::NoCategory));
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_CHANNELS;
 
             if !utils::user_has_perms(self.id, req)? {
                 return Err(Error::Model(ModelError::InvalidPermissions(req)));
             }
         }
 
-        self.id.send_message(f)
+        let cached = feature_cache! {{
+            self.category()
+                .map(|category| category.read().unwrap().permission_overwrites.clone())
+        } else {
+            None
+        }};
+
+        let overwrites = GuildChannel::category_overwrites(cached, || self.category_http())?;
+        let map = GuildChannel::sync_permissions_map(overwrites);
+        self.permission_overwrites = http::edit_channel(self.id.0, &map)?.permission_overwrites;
+
+        Ok(())
     }
 
+    /// Returns the topic of the channel, if it has a non-empty one.
+    pub fn topic(&self) -> Option<&str> {
+        match self.topic {
+            Some(ref topic) if !topic.is_empty() => Some(topic.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Broadcasts that the current user is typing in the channel until the
+    /// returned guard is dropped.
+    ///
+    /// Refer to [`Typing`] for more information.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// [`Typing`]: struct.Typing.html
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[inline]
+    pub fn typing(&self) -> Result<Typing> { self.id.typing() }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
@@ -626,6 +1493,18 @@ impl GuildChannel {
         self.id.unpin(message_id)
     }
 
+    /// Unpins a [`Message`] in the channel given by its Id, and then
+    /// retrieves the channel's remaining pinned messages.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[inline]
+    pub fn unpin_and_list<M: Into<MessageId>>(&self, message_id: M) -> Result<Vec<Message>> {
+        self.id.unpin_and_list(message_id)
+    }
+
     /// Retrieves the channel's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -633,6 +1512,71 @@ impl GuildChannel {
     /// [Manage Webhooks]: permissions/constant.MANAGE_WEBHOOKS.html
     #[inline]
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { self.id.webhooks() }
+
+    /// Broadcasts that the current user is typing in the channel while the
+    /// given closure runs, returning the closure's result.
+    ///
+    /// Refer to [`ChannelId::with_typing`] for more information.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// [`ChannelId::with_typing`]: struct.ChannelId.html#method.with_typing
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[inline]
+    pub fn with_typing<T, F: FnOnce() -> T>(&self, f: F) -> Result<T> { self.id.with_typing(f) }
+
+    // Uses the overwrites of the cached category if there are any, and
+    // otherwise requests the category through `fetch`.
+    fn category_overwrites<F>(cached: Option<Vec<PermissionOverwrite>>, fetch: F)
+                              -> Result<Vec<PermissionOverwrite>>
+        where F: FnOnce() -> Result<Option<ChannelCategory>> {
+        match cached {
+            Some(overwrites) => Ok(overwrites),
+            None => match fetch()? {
+                Some(category) => Ok(category.permission_overwrites),
+                None => Err(Error::Model(ModelError::NoCategory)),
+            },
+        }
+    }
+
+    fn check_voice(&self) -> Result<()> {
+        if self.kind == ChannelType::Voice {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::InvalidChannelType(self.kind)))
+        }
+    }
+
+    // Sends only the fields set on the builder, so that the focused setters do
+    // not overwrite any other settings.
+    fn edit_fields(&self, edit: &EditChannel) -> Result<GuildChannel> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_CHANNELS;
+
+            if !utils::user_has_perms(self.id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        http::edit_channel(self.id.0, &edit.0)
+    }
+
+    // An empty overwrite is used if the target does not have one yet.
+    fn overwrite_or_empty(&self, target: PermissionOverwriteType) -> PermissionOverwrite {
+        self.overwrite_for(target)
+            .cloned()
+            .unwrap_or_else(|| PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::empty(),
+                kind: target,
+            })
+    }
+
+    // All overwrites are sent, replacing the channel's existing ones.
+    fn sync_permissions_map(overwrites: Vec<PermissionOverwrite>) -> JsonMap {
+        EditChannel::default().permissions(overwrites).0
+    }
 }
 
 #[cfg(feature = "model")]
@@ -640,3 +1584,54 @@ impl Display for GuildChannel {
     /// Formats the channel, creating a mention of it.
     fn fmt(&self, f: &mut Formatter) -> FmtResult { Display::fmt(&self.id.mention(), f) }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::*;
+
+    fn category() -> ChannelCategory {
+        ChannelCategory {
+            id: ChannelId(1),
+            category_id: None,
+            guild_id: Some(GuildId(2)),
+            position: 0,
+            kind: ChannelType::Category,
+            name: "general".to_string(),
+            nsfw: false,
+            permission_overwrites: vec![
+                PermissionOverwrite::allow_only(
+                    PermissionOverwriteType::Role(RoleId(3)),
+                    Permissions::SEND_MESSAGES,
+                ),
+                PermissionOverwrite::deny_only(
+                    PermissionOverwriteType::Member(UserId(4)),
+                    Permissions::ATTACH_FILES,
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn sync_permissions_uses_category_overwrites() {
+        let category = category();
+
+        let fetched = GuildChannel::category_overwrites(None, || Ok(Some(category.clone())));
+        assert_eq!(fetched.unwrap(), category.permission_overwrites);
+
+        let cached = Some(category.permission_overwrites.clone());
+        let cached = GuildChannel::category_overwrites(cached, || panic!("Requested the category"));
+        assert_eq!(cached.unwrap(), category.permission_overwrites);
+
+        match GuildChannel::category_overwrites(None, || Ok(None)) {
+            Err(Error::Model(ModelError::NoCategory)) => {},
+            other => panic!("Expected a missing category: {:?}", other),
+        }
+
+        let map = GuildChannel::sync_permissions_map(category.permission_overwrites.clone());
+        assert_eq!(map.len(), 1);
+        assert_eq!(
+            map["permission_overwrites"],
+            ::serde_json::to_value(&category.permission_overwrites).unwrap()
+        );
+    }
+}
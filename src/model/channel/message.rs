@@ -1,13 +1,23 @@
 use chrono::{DateTime, FixedOffset};
+#[cfg(feature = "model")]
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use model::*;
+use std::result::Result as StdResult;
 
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use std::fmt::Write;
 #[cfg(feature = "model")]
+use std::collections::HashMap;
+#[cfg(feature = "model")]
 use std::mem;
 #[cfg(feature = "model")]
-use builder::{CreateEmbed, CreateMessage};
+use std::thread;
+#[cfg(feature = "model")]
+use std::time::Duration;
+#[cfg(feature = "model")]
+use builder::{CreateMessage, Timestamp};
 #[cfg(feature = "model")]
 use constants;
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -30,12 +40,31 @@ pub struct Message {
     ///
     /// [`Channel`]: enum.Channel.html
     pub channel_id: ChannelId,
+    /// The rows of interactive components, such as buttons, attached to the
+    /// message.
+    #[serde(default)]
+    pub components: Vec<ActionRow>,
     /// The content of the message.
     pub content: String,
     /// The timestamp of the last time the message was updated, if it was.
     pub edited_timestamp: Option<DateTime<FixedOffset>>,
     /// Array of embeds sent with the message.
     pub embeds: Vec<Embed>,
+    /// The Id of the [`Guild`] that the message was sent in, if Discord
+    /// included it in the payload.
+    ///
+    /// Prefer [`guild_id`], which falls back to the cache when this is
+    /// missing.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    /// [`guild_id`]: #method.guild_id
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// The set of [`MessageFlags`] attached to the message, if any were sent.
+    ///
+    /// [`MessageFlags`]: struct.MessageFlags.html
+    #[serde(default)]
+    pub flags: Option<MessageFlags>,
     /// Indicator of the type of message this is, i.e. whether it is a regular
     /// message or a system message.
     #[serde(rename = "type")]
@@ -48,6 +77,14 @@ pub struct Message {
     pub mention_roles: Vec<RoleId>,
     /// Array of users mentioned in the message.
     pub mentions: Vec<User>,
+    /// The message that this message replies to or was crossposted from, if
+    /// any.
+    ///
+    /// Refer to [`referenced_message`] to retrieve the referenced message.
+    ///
+    /// [`referenced_message`]: #method.referenced_message
+    #[serde(default)]
+    pub message_reference: Option<MessageReference>,
     /// Non-repeating number used for ensuring message order.
     #[serde(default)]
     pub nonce: Value,
@@ -69,6 +106,148 @@ pub struct Message {
 
 #[cfg(feature = "model")]
 impl Message {
+    /// Applies a reaction being added to the message, as received through a
+    /// [`ReactionAddEvent`], to the message's [`reactions`].
+    ///
+    /// This increments the count of the reaction type, adding it if it was
+    /// not yet present. `me` should be whether the reaction was added by the
+    /// current user.
+    ///
+    /// [`ReactionAddEvent`]: event/struct.ReactionAddEvent.html
+    /// [`reactions`]: #structfield.reactions
+    pub fn apply_reaction_add<R: Into<ReactionType>>(&mut self, reaction_type: R, me: bool) {
+        let reaction_type = reaction_type.into();
+
+        match self.reaction_index(&reaction_type) {
+            Some(index) => {
+                let reaction = &mut self.reactions[index];
+                reaction.count += 1;
+                reaction.me |= me;
+            },
+            None => self.reactions.push(MessageReaction {
+                count: 1,
+                me: me,
+                reaction_type: reaction_type,
+            }),
+        }
+    }
+
+    /// Applies a reaction being removed from the message, as received through
+    /// a [`ReactionRemoveEvent`], to the message's [`reactions`].
+    ///
+    /// This decrements the count of the reaction type, removing it once no
+    /// reactions of the type remain. `me` should be whether the reaction was
+    /// removed by the current user.
+    ///
+    /// [`ReactionRemoveEvent`]: event/struct.ReactionRemoveEvent.html
+    /// [`reactions`]: #structfield.reactions
+    pub fn apply_reaction_remove<R: Into<ReactionType>>(&mut self, reaction_type: R, me: bool) {
+        let index = match self.reaction_index(&reaction_type.into()) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let count = {
+            let reaction = &mut self.reactions[index];
+            reaction.count = reaction.count.saturating_sub(1);
+
+            if me {
+                reaction.me = false;
+            }
+
+            reaction.count
+        };
+
+        if count == 0 {
+            self.reactions.remove(index);
+        }
+    }
+
+    /// Applies the embeds of the message being suppressed or unsuppressed to
+    /// the message's [`flags`] and [`embeds`].
+    ///
+    /// Suppressing sets the [`MessageFlags::SUPPRESS_EMBEDS`] flag and clears
+    /// the embeds. Unsuppressing only clears the flag, as the embeds are not
+    /// known until the message is retrieved again.
+    ///
+    /// This is done by [`suppress_embeds`] once the message was edited.
+    ///
+    /// [`MessageFlags::SUPPRESS_EMBEDS`]: struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    /// [`embeds`]: #structfield.embeds
+    /// [`flags`]: #structfield.flags
+    /// [`suppress_embeds`]: #method.suppress_embeds
+    pub fn apply_suppress_embeds(&mut self, suppress: bool) {
+        let mut flags = self.flags.unwrap_or_else(MessageFlags::empty);
+        flags.set(MessageFlags::SUPPRESS_EMBEDS, suppress);
+        self.flags = Some(flags);
+
+        if suppress {
+            self.embeds.clear();
+        }
+    }
+
+    /// Retrieves the message's [`attachments`] whose filename ends with the
+    /// given extension, such as `"csv"` or `".tar.gz"`.
+    ///
+    /// The extension is matched case-insensitively, and may be given with or
+    /// without its leading period.
+    ///
+    /// [`attachments`]: #structfield.attachments
+    pub fn attachments_with_extension(&self, extension: &str) -> Vec<&Attachment> {
+        let extension = if extension.starts_with('.') {
+            &extension[1..]
+        } else {
+            extension
+        };
+        let suffix = format!(".{}", extension.to_lowercase());
+
+        self.attachments
+            .iter()
+            .filter(|attachment| attachment.filename.to_lowercase().ends_with(&suffix))
+            .collect()
+    }
+
+    /// Retrieves the nickname of the message's author in the guild the
+    /// message was sent in, from the cache.
+    ///
+    /// Returns `None` if the message was not sent in a guild, if the author's
+    /// [`Member`] is not cached, or if the author has no nickname, so that
+    /// callers can fall back to the author's username.
+    ///
+    /// Requires the `cache` feature be enabled. Refer to [`author_nick_http`]
+    /// for retrieving the nickname over the REST API.
+    ///
+    /// [`Member`]: struct.Member.html
+    /// [`author_nick_http`]: #method.author_nick_http
+    #[cfg(feature = "cache")]
+    pub fn author_nick(&self) -> Option<String> {
+        self.guild_id().and_then(|guild_id| {
+            CACHE
+                .read()
+                .unwrap()
+                .member(guild_id, self.author.id)
+                .and_then(|member| member.nick)
+        })
+    }
+
+    /// Retrieves the nickname of the message's author in the guild the
+    /// message was sent in, by requesting the author's [`Member`].
+    ///
+    /// The guild is resolved through [`guild_id_http`], so this also works
+    /// without the cache. Returns `None` if the message was not sent in a
+    /// guild or if the author has no nickname. Refer to [`author_nick`] for
+    /// retrieving the nickname from the cache.
+    ///
+    /// [`Member`]: struct.Member.html
+    /// [`author_nick`]: #method.author_nick
+    /// [`guild_id_http`]: #method.guild_id_http
+    pub fn author_nick_http(&self) -> Result<Option<String>> {
+        match self.guild_id_http()? {
+            Some(guild_id) => Ok(guild_id.member(self.author.id)?.nick),
+            None => Ok(None),
+        }
+    }
+
     /// Retrieves the related channel located in the cache.
     ///
     /// Returns `None` if the channel is not in the cache.
@@ -115,10 +294,60 @@ impl Message {
     #[inline]
     pub fn channel(&self) -> Option<Channel> { CACHE.read().unwrap().channel(self.channel_id) }
 
-    /// A util function for determining whether this message was sent by someone else, or the
-    /// bot.
-    #[cfg(all(feature = "cache", feature = "utils"))]
-    pub fn is_own(&self) -> bool { self.author.id == CACHE.read().unwrap().user.id }
+    /// Removes all reactions of the given type from the message's
+    /// [`reactions`], as when all of the reactions of an emoji are removed.
+    ///
+    /// [`reactions`]: #structfield.reactions
+    pub fn clear_reaction_emoji<R: Into<ReactionType>>(&mut self, reaction_type: R) {
+        if let Some(index) = self.reaction_index(&reaction_type.into()) {
+            self.reactions.remove(index);
+        }
+    }
+
+    /// Removes all of the message's [`reactions`], as received through a
+    /// [`ReactionRemoveAllEvent`].
+    ///
+    /// [`ReactionRemoveAllEvent`]: event/struct.ReactionRemoveAllEvent.html
+    /// [`reactions`]: #structfield.reactions
+    #[inline]
+    pub fn clear_reactions(&mut self) { self.reactions.clear(); }
+
+    /// Crossposts the message to the guilds following its news channel,
+    /// returning the updated message with the [`MessageFlags::CROSSPOSTED`]
+    /// flag set.
+    ///
+    /// **Note**: Requires the [Send Messages] permission if the current user
+    /// is the author of the message, and the [Manage Messages] permission
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidChannelType`]
+    /// if the message is not in a news channel.
+    ///
+    /// [`MessageFlags::CROSSPOSTED`]: struct.MessageFlags.html#associatedconstant.CROSSPOSTED
+    /// [`ModelError::InvalidChannelType`]: enum.ModelError.html#variant.InvalidChannelType
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn crosspost(&self) -> Result<Message> {
+        #[cfg(feature = "cache")]
+        {
+            // Channels that are not in the cache are left to Discord to check.
+            let kind = CACHE
+                .read()
+                .unwrap()
+                .channel(self.channel_id)
+                .map(|channel| channel.kind());
+
+            if let Some(kind) = kind {
+                if kind != ChannelType::News {
+                    return Err(Error::Model(ModelError::InvalidChannelType(kind)));
+                }
+            }
+        }
+
+        http::crosspost_message(self.channel_id.0, self.id.0)
+    }
 
     /// Deletes the message.
     ///
@@ -149,8 +378,101 @@ impl Message {
         self.channel_id.delete_message(self.id)
     }
 
+    /// Deletes the message once the given duration has passed.
+    ///
+    /// This does not block: the message is deleted from a background thread,
+    /// and this returns immediately. As the deletion happens later, an error
+    /// deleting the message - such as if it was already deleted - is logged
+    /// instead of returned.
+    ///
+    /// Refer to [`delete`] for the permissions required.
+    ///
+    /// # Examples
+    ///
+    /// Reply to a message, deleting the reply after 10 seconds:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::Message;
+    /// #
+    /// use std::time::Duration;
+    ///
+    /// fn on_message(message: &Message) {
+    ///     if let Ok(reply) = message.reply("This will self-destruct.") {
+    ///         let _ = reply.delete_after(Duration::from_secs(10));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if the background thread could not be
+    /// spawned.
+    ///
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    /// [`delete`]: #method.delete
+    pub fn delete_after(&self, duration: Duration) -> Result<()> {
+        let message = self.clone();
+
+        thread::Builder::new()
+            .name(format!("serenity delete message {}", self.id))
+            .spawn(move || {
+                thread::sleep(duration);
+
+                if let Err(why) = message.delete() {
+                    warn!("Err deleting message {}: {:?}", message.id, why);
+                }
+            })?;
+
+        Ok(())
+    }
+
+    /// Deletes the current user's own reaction of the given emoji from the
+    /// message, such as when a reaction menu times out.
+    ///
+    /// Unlike deleting another user's reaction, this does not require the
+    /// [Manage Messages] permission.
+    ///
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[inline]
+    pub fn delete_own_reaction<R: Into<ReactionType>>(&self, reaction_type: R) -> Result<()> {
+        self.channel_id.delete_reaction(self.id, None, reaction_type)
+    }
+
+    /// Deletes all of the [`Reaction`]s of the given emoji on the message,
+    /// leaving other reactions untouched.
+    ///
+    /// Refer to [`delete_reactions`] to delete every reaction.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` feature is enabled, then returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`Reaction`]: struct.Reaction.html
+    /// [`delete_reactions`]: #method.delete_reactions
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn delete_reaction_emoji<R: Into<ReactionType>>(&self, reaction_type: R) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_MESSAGES;
+
+            if !utils::user_has_perms(self.channel_id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        self.channel_id.delete_reaction_emoji(self.id, reaction_type)
+    }
+
     /// Deletes all of the [`Reaction`]s associated with the message.
     ///
+    /// Refer to [`delete_reaction_emoji`] to only delete the reactions of one
+    /// emoji.
+    ///
     /// **Note**: Requires the [Manage Messages] permission.
     ///
     /// # Errors
@@ -161,6 +483,7 @@ impl Message {
     ///
     /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
     /// [`Reaction`]: struct.Reaction.html
+    /// [`delete_reaction_emoji`]: #method.delete_reaction_emoji
     /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
     pub fn delete_reactions(&self) -> Result<()> {
         #[cfg(feature = "cache")]
@@ -177,7 +500,9 @@ impl Message {
 
     /// Edits this message, replacing the original content with new content.
     ///
-    /// Message editing preserves all unchanged message data.
+    /// Message editing preserves all unchanged message data. Setting an
+    /// embed through [`CreateMessage::embed`] replaces the message's embeds,
+    /// while passing no embeds to [`CreateMessage::embeds`] removes them.
     ///
     /// Refer to the documentation for [`CreateMessage`] for more information
     /// regarding message restrictions and requirements.
@@ -206,6 +531,8 @@ impl Message {
     /// [`ModelError::InvalidUser`]: enum.ModelError.html#variant.InvalidUser
     /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
     /// [`CreateMessage`]: ../builder/struct.CreateMessage.html
+    /// [`CreateMessage::embed`]: ../builder/struct.CreateMessage.html#method.embed
+    /// [`CreateMessage::embeds`]: ../builder/struct.CreateMessage.html#method.embeds
     /// [`the limit`]: ../builder/struct.CreateMessage.html#method.content
     pub fn edit<F>(&mut self, f: F) -> Result<()>
         where F: FnOnce(CreateMessage) -> CreateMessage {
@@ -222,16 +549,14 @@ impl Message {
             builder = builder.content(&self.content);
         }
 
-        if let Some(embed) = self.embeds.get(0) {
-            builder = builder.embed(|_| CreateEmbed::from(embed.clone()));
-        }
-
         if self.tts {
             builder = builder.tts(true);
         }
 
         let map = f(builder).0;
 
+        Message::check_content_length(&map)?;
+
         match http::edit_message(self.channel_id.0, self.id.0, &Value::Object(map)) {
             Ok(edited) => {
                 mem::replace(self, edited);
@@ -242,6 +567,14 @@ impl Message {
         }
     }
 
+    /// Retrieves when the message was last edited, in UTC.
+    ///
+    /// Returns `None` if the message has not been edited.
+    #[inline]
+    pub fn edited_at(&self) -> Option<NaiveDateTime> {
+        self.edited_timestamp.map(|timestamp| timestamp.naive_utc())
+    }
+
     pub(crate) fn transform_content(&mut self) {
         match self.kind {
             MessageType::PinsAdd => {
@@ -264,20 +597,27 @@ impl Message {
         }
     }
 
-    /// Returns message content, but with user and role mentions replaced with
-    /// names and everyone/here mentions cancelled.
-    #[cfg(feature = "cache")]
+    /// Returns message content, but with user, role, and channel mentions
+    /// replaced with names and everyone/here mentions cancelled.
+    ///
+    /// Role and channel names are resolved through the [`Cache`]. Mentions
+    /// that can not be resolved are replaced with their Id.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    #[cfg(all(feature = "cache", feature = "utils"))]
     pub fn content_safe(&self) -> String {
         let mut result = self.content.clone();
 
-        // First replace all user mentions.
+        // First replace all user mentions, including nickname mentions.
         for u in &self.mentions {
             let mut at_distinct = String::with_capacity(38);
             at_distinct.push('@');
             at_distinct.push_str(&u.name);
             at_distinct.push('#');
             let _ = write!(at_distinct, "{}", u.discriminator);
-            result = result.replace(&u.mention(), &at_distinct);
+            result = result
+                .replace(&u.mention(), &at_distinct)
+                .replace(&format!("<@!{}>", u.id.0), &at_distinct);
         }
 
         // Then replace all role mentions.
@@ -287,10 +627,42 @@ impl Message {
             if let Some(role) = id.find() {
                 result = result.replace(&mention, &format!("@{}", role.name));
             } else {
-                result = result.replace(&mention, "@deleted-role");
+                result = result.replace(&mention, &format!("@{}", id.0));
+            }
+        }
+
+        // Then replace all channel mentions, which the message doesn't list.
+        let mut channel_ids = vec![];
+
+        {
+            let mut rest = &result[..];
+
+            while let Some(start) = rest.find("<#") {
+                rest = &rest[start + 2..];
+
+                if let Some(end) = rest.find('>') {
+                    if let Ok(id) = rest[..end].parse::<u64>() {
+                        channel_ids.push(ChannelId(id));
+                    }
+                }
             }
         }
 
+        for id in channel_ids {
+            let name = CACHE
+                .read()
+                .unwrap()
+                .guild_channel(id)
+                .map(|channel| channel.read().unwrap().name.clone());
+
+            let replacement = match name {
+                Some(name) => format!("#{}", name),
+                None => format!("#{}", id.0),
+            };
+
+            result = result.replace(&id.mention(), &replacement);
+        }
+
         // And finally replace everyone and here mentions.
         result
             .replace("@everyone", "@\u{200B}everyone")
@@ -341,104 +713,517 @@ impl Message {
     /// Retrieves the Id of the guild that the message was sent in, if sent in
     /// one.
     ///
-    /// Returns `None` if the channel data or guild data does not exist in the
-    /// cache.
-    #[cfg(feature = "cache")]
+    /// If the payload did not include the [`guild_id`][`Message::guild_id`]
+    /// field and the `cache` feature is enabled, the Id is resolved from the
+    /// cached channel.
+    ///
+    /// Returns `None` if the message was sent in a private channel or group,
+    /// or if the field is missing and the channel is not in the cache.
+    ///
+    /// [`Message::guild_id`]: #structfield.guild_id
     pub fn guild_id(&self) -> Option<GuildId> {
-        match CACHE.read().unwrap().channel(self.channel_id) {
-            Some(Channel::Guild(ch)) => Some(ch.read().unwrap().guild_id),
-            _ => None,
+        if self.guild_id.is_some() {
+            return self.guild_id;
         }
-    }
 
-    /// True if message was sent using direct messages.
-    #[cfg(feature = "cache")]
-    pub fn is_private(&self) -> bool {
-        match CACHE.read().unwrap().channel(self.channel_id) {
-            Some(Channel::Group(_)) | Some(Channel::Private(_)) => true,
-            _ => false,
-        }
+        feature_cache! {{
+            match CACHE.read().unwrap().channel(self.channel_id) {
+                Some(Channel::Guild(ch)) => Some(ch.read().unwrap().guild_id),
+                _ => None,
+            }
+        } else {
+            None
+        }}
     }
 
-    /// Checks the length of a string to ensure that it is within Discord's
-    /// maximum message length limit.
+    /// Retrieves the Id of the guild that the message was sent in, if sent in
+    /// one, requesting the message's channel if the Id can not be resolved
+    /// through [`guild_id`].
     ///
-    /// Returns `None` if the message is within the limit, otherwise returns
-    /// `Some` with an inner value of how many unicode code points the message
-    /// is over.
-    pub fn overflow_length(content: &str) -> Option<u64> {
-        // Check if the content is over the maximum number of unicode code
-        // points.
-        let count = content.chars().count() as i64;
-        let diff = count - i64::from(constants::MESSAGE_CODE_LIMIT);
-
-        if diff > 0 {
-            Some(diff as u64)
-        } else {
-            None
+    /// [`guild_id`]: #method.guild_id
+    pub fn guild_id_http(&self) -> Result<Option<GuildId>> {
+        match self.guild_id() {
+            Some(guild_id) => Ok(Some(guild_id)),
+            None => Ok(self.channel_id.get()?.guild_id()),
         }
     }
 
-    /// Pins this message to its channel.
+    /// Retrieves the message's [`attachments`] that are images.
     ///
-    /// **Note**: Requires the [Manage Messages] permission.
+    /// Refer to [`Attachment::is_image`] for how images are detected.
     ///
-    /// # Errors
+    /// [`Attachment::is_image`]: struct.Attachment.html#method.is_image
+    /// [`attachments`]: #structfield.attachments
+    pub fn image_attachments(&self) -> Vec<&Attachment> {
+        self.attachments
+            .iter()
+            .filter(|attachment| attachment.is_image())
+            .collect()
+    }
+
+    /// Whether the message was flagged as [ephemeral], meaning that it is
+    /// only visible to the user it was sent to.
     ///
-    /// If the `cache` is enabled, returns a
-    /// [`ModelError::InvalidPermissions`] if the current user does not have
-    /// the required permissions.
+    /// Ephemeral messages can not be reacted to, edited, or deleted like
+    /// regular messages, so this can be used to avoid such requests failing.
     ///
-    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
-    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
-    pub fn pin(&self) -> Result<()> {
-        #[cfg(feature = "cache")]
-        {
-            let req = Permissions::MANAGE_MESSAGES;
-
-            if !utils::user_has_perms(self.channel_id, req)? {
-                return Err(Error::Model(ModelError::InvalidPermissions(req)));
-            }
-        }
-
-        self.channel_id.pin(self.id.0)
+    /// [ephemeral]: struct.MessageFlags.html#associatedconstant.EPHEMERAL
+    pub fn is_ephemeral(&self) -> bool {
+        self.flags
+            .map_or(false, |flags| flags.contains(MessageFlags::EPHEMERAL))
     }
 
-    /// React to the message with a custom [`Emoji`] or unicode character.
+    /// Whether the message was sent by the current user, such as the bot
+    /// itself.
     ///
-    /// **Note**: Requires the [Add Reactions] permission.
+    /// This compares the author against the current user in the [`Cache`],
+    /// and so makes no REST requests.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// If the `cache` is enabled, returns a
-    /// [`ModelError::InvalidPermissions`] if the current user does not have the
-    /// required [permissions].
+    /// Ignore messages sent by the bot itself:
     ///
-    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
-    /// [`Emoji`]: struct.Emoji.html
-    /// [Add Reactions]: permissions/constant.ADD_REACTIONS.html
-    /// [permissions]: permissions
-    pub fn react<R: Into<ReactionType>>(&self, reaction_type: R) -> Result<()> {
-        #[cfg(feature = "cache")]
-        {
-            let req = Permissions::ADD_REACTIONS;
+    /// ```rust,no_run
+    /// # use serenity::model::Message;
+    /// #
+    /// fn on_message(message: &Message) {
+    ///     if message.is_own() {
+    ///         return;
+    ///     }
+    ///
+    ///     // Handle the message.
+    /// }
+    /// ```
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    #[cfg(feature = "cache")]
+    pub fn is_own(&self) -> bool { self.author.id == CACHE.read().unwrap().user.id }
 
-            if !utils::user_has_perms(self.channel_id, req)? {
-                return Err(Error::Model(ModelError::InvalidPermissions(req)));
-            }
+    /// Whether the message is pinned to its channel.
+    ///
+    /// As this reads the [`pinned`] field, it is not updated by [`pin`] and
+    /// [`unpin`], which do not take a mutable reference.
+    ///
+    /// [`pin`]: #method.pin
+    /// [`pinned`]: #structfield.pinned
+    /// [`unpin`]: #method.unpin
+    #[inline]
+    pub fn is_pinned(&self) -> bool { self.pinned }
+
+    /// Whether the message was sent in a [`PrivateChannel`] or a [`Group`].
+    ///
+    /// Messages that carry a guild Id are never private. Otherwise the
+    /// channel is looked up in the [`Cache`], and so no REST requests are
+    /// made. Messages in channels that are not in the cache are not
+    /// considered private.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`Group`]: struct.Group.html
+    /// [`PrivateChannel`]: struct.PrivateChannel.html
+    #[cfg(feature = "cache")]
+    pub fn is_private(&self) -> bool {
+        if self.guild_id.is_some() {
+            return false;
         }
 
-        http::create_reaction(self.channel_id.0, self.id.0, &reaction_type.into())
+        match CACHE.read().unwrap().channel(self.channel_id) {
+            Some(Channel::Group(_)) | Some(Channel::Private(_)) => true,
+            _ => false,
+        }
     }
 
-    /// Replies to the user, mentioning them prior to the content in the form
-    /// of: `@<USER_ID>: YOUR_CONTENT`.
-    ///
-    /// User mentions are generally around 20 or 21 characters long.
+    /// Whether the message is a system message, such as a pin notification
+    /// or a member join, rather than a [`MessageType::Regular`] message.
     ///
-    /// **Note**: Requires the [Send Messages] permission.
+    /// [`MessageType::Regular`]: enum.MessageType.html#variant.Regular
+    #[inline]
+    pub fn is_system(&self) -> bool { self.kind != MessageType::Regular }
+
+    /// Returns the type of the message.
     ///
-    /// **Note**: Message contents must be under 2000 unicode code points.
+    /// This is the same as reading the [`kind`] field.
+    ///
+    /// [`kind`]: #structfield.kind
+    #[inline]
+    pub fn kind(&self) -> MessageType { self.kind }
+
+    /// Creates a URL that jumps to the message when clicked in the client.
+    ///
+    /// The URL is in the form of
+    /// `https://discord.com/channels/{guild_id}/{channel_id}/{message_id}`,
+    /// where the guild is resolved through [`guild_id`]. If the guild can
+    /// not be resolved - as is the case for messages sent in private channels
+    /// and groups - `@me` is used in its place.
+    ///
+    /// Use [`link_ensured`] if the guild of a guild message may not be known.
+    ///
+    /// [`guild_id`]: #method.guild_id
+    /// [`link_ensured`]: #method.link_ensured
+    pub fn link(&self) -> String { Message::link_for(self.guild_id(), self.channel_id, self.id) }
+
+    /// Creates a URL that jumps to the message when clicked in the client,
+    /// like [`link`].
+    ///
+    /// If the guild of the message can not be resolved through [`guild_id`],
+    /// the channel is retrieved to determine whether the message was sent in
+    /// a guild.
+    ///
+    /// [`guild_id`]: #method.guild_id
+    /// [`link`]: #method.link
+    pub fn link_ensured(&self) -> Result<String> {
+        let guild_id = match self.guild_id() {
+            Some(guild_id) => Some(guild_id),
+            None => match self.channel_id.get()? {
+                Channel::Guild(channel) => Some(channel.read().unwrap().guild_id),
+                _ => None,
+            },
+        };
+
+        Ok(Message::link_for(guild_id, self.channel_id, self.id))
+    }
+
+    /// Parses the Ids of all channels mentioned in the form of `<#id>` out of
+    /// the message's [`content`].
+    ///
+    /// Ids are returned in the order they first appear, without duplicates.
+    ///
+    /// [`content`]: #structfield.content
+    pub fn mentioned_channel_ids(&self) -> Vec<ChannelId> {
+        self.mention_ids(&["#"]).into_iter().map(ChannelId).collect()
+    }
+
+    /// Parses the Ids of all roles mentioned in the form of `<@&id>` out of
+    /// the message's [`content`].
+    ///
+    /// Unlike [`mention_roles`], this includes mentions that Discord did not
+    /// resolve. Ids are returned in the order they first appear, without
+    /// duplicates.
+    ///
+    /// [`content`]: #structfield.content
+    /// [`mention_roles`]: #structfield.mention_roles
+    pub fn mentioned_role_ids(&self) -> Vec<RoleId> {
+        self.mention_ids(&["@&"]).into_iter().map(RoleId).collect()
+    }
+
+    /// Parses the Ids of all users mentioned in the form of `<@id>` or
+    /// `<@!id>` out of the message's [`content`].
+    ///
+    /// Unlike [`mentions`], this includes mentions that Discord did not
+    /// resolve, such as of users that are not members of the guild. Ids are
+    /// returned in the order they first appear, without duplicates.
+    ///
+    /// # Examples
+    ///
+    /// Parse the users mentioned as arguments of a command:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::Message;
+    /// #
+    /// fn on_message(message: &Message) {
+    ///     if message.content.starts_with("~ban ") {
+    ///         for user_id in message.mentioned_user_ids() {
+    ///             println!("Banning {}", user_id);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`content`]: #structfield.content
+    /// [`mentions`]: #structfield.mentions
+    pub fn mentioned_user_ids(&self) -> Vec<UserId> {
+        self.mention_ids(&["@!", "@"]).into_iter().map(UserId).collect()
+    }
+
+    /// Whether the message mentions the current user.
+    ///
+    /// This is the case if the current user is mentioned directly, if a role
+    /// of the current user in the guild is mentioned, or if `@everyone` or
+    /// `@here` is mentioned with [`mention_everyone`] set. Roles are resolved
+    /// through the [`Cache`], and so no REST requests are made.
+    ///
+    /// # Examples
+    ///
+    /// Only respond to messages that mention the bot:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::Message;
+    /// #
+    /// fn on_message(message: &Message) {
+    ///     if !message.mentions_me().unwrap_or(false) {
+    ///         return;
+    ///     }
+    ///
+    ///     let _ = message.reply("You rang?");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the current user is not yet
+    /// known to the cache.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`ModelError::ItemMissing`]: enum.ModelError.html#variant.ItemMissing
+    /// [`mention_everyone`]: #structfield.mention_everyone
+    #[cfg(feature = "cache")]
+    pub fn mentions_me(&self) -> Result<bool> {
+        let user_id = CACHE.read().unwrap().user.id;
+
+        // The current user is only known once the `Ready` event is received.
+        if user_id.0 == 0 {
+            return Err(Error::Model(ModelError::ItemMissing));
+        }
+
+        if self.mention_everyone || self.mentions_user(user_id) {
+            return Ok(true);
+        }
+
+        if self.mention_roles.is_empty() {
+            return Ok(false);
+        }
+
+        let guild = match self.guild() {
+            Some(guild) => guild,
+            None => return Ok(false),
+        };
+        let guild = guild.read().unwrap();
+
+        let mentioned = guild.members.get(&user_id).map_or(false, |member| {
+            member
+                .roles
+                .iter()
+                .any(|role_id| self.mention_roles.contains(role_id))
+        });
+
+        Ok(mentioned)
+    }
+
+    /// Whether the given user is directly mentioned in the message.
+    ///
+    /// Refer to [`mentions_me`] to check whether the current user is
+    /// mentioned, including through roles and `@everyone`.
+    ///
+    /// [`mentions_me`]: #method.mentions_me
+    pub fn mentions_user<U: Into<UserId>>(&self, user_id: U) -> bool {
+        let user_id = user_id.into();
+
+        self.mentions.iter().any(|user| user.id == user_id)
+    }
+
+    /// Checks the length of a string to ensure that it is within Discord's
+    /// maximum message length limit.
+    ///
+    /// Returns `None` if the message is within the limit, otherwise returns
+    /// `Some` with an inner value of how many unicode code points the message
+    /// is over.
+    pub fn overflow_length(content: &str) -> Option<u64> {
+        // Check if the content is over the maximum number of unicode code
+        // points.
+        let count = content.chars().count() as i64;
+        let diff = count - i64::from(constants::MESSAGE_CODE_LIMIT);
+
+        if diff > 0 {
+            Some(diff as u64)
+        } else {
+            None
+        }
+    }
+
+    /// Pins this message to its channel.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has the
+    /// maximum number of pinned messages, without attempting to pin the
+    /// message.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`ModelError::TooManyPins`]: enum.ModelError.html#variant.TooManyPins
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn pin(&self) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_MESSAGES;
+
+            if !utils::user_has_perms(self.channel_id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        self.channel_id.pin_checked(self.id)
+    }
+
+    /// React to the message with a custom [`Emoji`] or unicode character.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have the
+    /// required [permissions].
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`Emoji`]: struct.Emoji.html
+    /// [Add Reactions]: permissions/constant.ADD_REACTIONS.html
+    /// [permissions]: permissions
+    pub fn react<R: Into<ReactionType>>(&self, reaction_type: R) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::ADD_REACTIONS;
+
+            if !utils::user_has_perms(self.channel_id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        http::create_reaction(self.channel_id.0, self.id.0, &reaction_type.into())
+    }
+
+    /// React to the message, like [`react`], returning the created
+    /// [`Reaction`].
+    ///
+    /// Discord does not respond with the reaction, so it is built locally from
+    /// the message's Ids, the emoji, and the current user's Id. If the `cache`
+    /// is enabled, the current user's Id is taken from it; otherwise, it is
+    /// retrieved over REST.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required [permissions].
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`Reaction`]: struct.Reaction.html
+    /// [`react`]: #method.react
+    /// [Add Reactions]: permissions/constant.ADD_REACTIONS.html
+    /// [permissions]: permissions
+    pub fn react_get<R: Into<ReactionType>>(&self, reaction_type: R) -> Result<Reaction> {
+        let reaction_type = reaction_type.into();
+
+        self.react(reaction_type.clone())?;
+
+        let user_id = feature_cache! {{
+            CACHE.read().unwrap().user.id
+        } else {
+            http::get_current_user()?.id
+        }};
+
+        Ok(Reaction {
+            channel_id: self.channel_id,
+            emoji: reaction_type,
+            message_id: self.id,
+            user_id: user_id,
+        })
+    }
+
+    /// Whether the message has any reactions of the given type.
+    ///
+    /// Refer to [`reaction_count`] for how reaction types are compared.
+    ///
+    /// [`reaction_count`]: #method.reaction_count
+    #[inline]
+    pub fn reacted_with<R: Into<ReactionType>>(&self, reaction_type: R) -> bool {
+        self.reaction_count(reaction_type).is_some()
+    }
+
+    /// Retrieves the number of reactions of the given type on the message,
+    /// returning `None` if there are none.
+    ///
+    /// Custom emojis are compared by their Id alone, and unicode emojis by
+    /// their literal unicode.
+    ///
+    /// # Examples
+    ///
+    /// Tally the votes on a poll:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::Message;
+    /// #
+    /// # fn tally(message: &Message) {
+    /// let yes = message.reaction_count('✅').unwrap_or(0);
+    /// let no = message.reaction_count('❎').unwrap_or(0);
+    /// # }
+    /// ```
+    pub fn reaction_count<R: Into<ReactionType>>(&self, reaction_type: R) -> Option<u64> {
+        self.reaction_index(&reaction_type.into())
+            .map(|index| self.reactions[index].count)
+    }
+
+    /// Builds a map of each type of reaction on the message to its count.
+    ///
+    /// Reaction types are compared as in [`reaction_count`], so a custom emoji
+    /// is found in the map regardless of the name it is looked up with.
+    ///
+    /// # Examples
+    ///
+    /// Tally the votes on a poll in one go:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::{Message, ReactionType};
+    /// #
+    /// # fn tally(message: &Message) {
+    /// let tally = message.reaction_map();
+    ///
+    /// let yes = tally.get(&ReactionType::from('✅')).cloned().unwrap_or(0);
+    /// # }
+    /// ```
+    ///
+    /// [`reaction_count`]: #method.reaction_count
+    pub fn reaction_map(&self) -> HashMap<ReactionType, u64> {
+        let mut map = HashMap::with_capacity(self.reactions.len());
+
+        for reaction in &self.reactions {
+            *map.entry(reaction.reaction_type.clone()).or_insert(0) += reaction.count;
+        }
+
+        map
+    }
+
+    /// Retrieves the message that this message replies to, if it is an inline
+    /// reply.
+    ///
+    /// The message is requested over the REST API, as messages are not kept
+    /// in the [`Cache`]. Returns `Ok(None)` if the message does not reference
+    /// another message.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    pub fn referenced_message(&self) -> Result<Option<Message>> {
+        let (channel_id, message_id) = match self.message_reference {
+            Some(MessageReference {
+                channel_id,
+                message_id: Some(message_id),
+                ..
+            }) => (channel_id.unwrap_or(self.channel_id), message_id),
+            _ => return Ok(None),
+        };
+
+        channel_id.message(message_id).map(Some)
+    }
+
+    /// Replies to the user, mentioning them prior to the content in the form
+    /// of: `@<USER_ID>: YOUR_CONTENT`.
+    ///
+    /// User mentions are generally around 20 or 21 characters long.
+    ///
+    /// This does not create an inline reply to the message. Refer to
+    /// [`reply_ping`] and [`reply_ref`] for inline replies.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
     ///
     /// # Errors
     ///
@@ -452,6 +1237,8 @@ impl Message {
     ///
     /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
     /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
+    /// [`reply_ping`]: #method.reply_ping
+    /// [`reply_ref`]: #method.reply_ref
     /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
     pub fn reply(&self, content: &str) -> Result<Message> {
         if let Some(length_over) = Message::overflow_length(content) {
@@ -479,6 +1266,175 @@ impl Message {
         http::send_message(self.channel_id.0, &map)
     }
 
+    /// Replies to the message inline, referencing it and mentioning its
+    /// author.
+    ///
+    /// Unlike [`reply`], the mention is not prefixed to the content. Refer to
+    /// [`reply_ref`] to reply without mentioning the author.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the above limit, containing the number of unicode code points
+    /// over the limit.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
+    /// [`reply`]: #method.reply
+    /// [`reply_ref`]: #method.reply_ref
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn reply_ping(&self, content: &str) -> Result<Message> {
+        self.reply_inline(content, true)
+    }
+
+    /// Replies to the message inline, referencing it without mentioning its
+    /// author.
+    ///
+    /// Refer to [`reply_ping`] to also mention the author.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the above limit, containing the number of unicode code points
+    /// over the limit.
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`ModelError::MessageTooLong`]: enum.ModelError.html#variant.MessageTooLong
+    /// [`reply_ping`]: #method.reply_ping
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    pub fn reply_ref(&self, content: &str) -> Result<Message> { self.reply_inline(content, false) }
+
+    /// Resolves the names of the users, roles, and channels mentioned in the
+    /// message's [`content`], in the order they first appear.
+    ///
+    /// Users are named from the message's [`mentions`] or otherwise the
+    /// [`Cache`], roles from the message's guild in the cache, and channels
+    /// from the cache. Each Id that can not be resolved is named by the Id
+    /// itself, so this never fails when the cache is incomplete.
+    ///
+    /// Refer to [`mentioned_user_ids`], [`mentioned_role_ids`], and
+    /// [`mentioned_channel_ids`] for how mentions are found.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`content`]: #structfield.content
+    /// [`mentioned_channel_ids`]: #method.mentioned_channel_ids
+    /// [`mentioned_role_ids`]: #method.mentioned_role_ids
+    /// [`mentioned_user_ids`]: #method.mentioned_user_ids
+    /// [`mentions`]: #structfield.mentions
+    #[cfg(feature = "cache")]
+    pub fn resolved_mentions(&self) -> ResolvedMentions {
+        // This is retrieved before the cache is locked, as it may lock it too.
+        let guild_id = self.guild_id();
+        let cache = CACHE.read().unwrap();
+
+        let users = self.mentioned_user_ids()
+            .into_iter()
+            .map(|id| {
+                let name = self.mentions
+                    .iter()
+                    .find(|user| user.id == id)
+                    .map(|user| user.name.clone())
+                    .or_else(|| cache.users.get(&id).map(|user| user.read().unwrap().name.clone()))
+                    .unwrap_or_else(|| id.0.to_string());
+
+                (id, name)
+            })
+            .collect();
+
+        let guild = guild_id.and_then(|guild_id| cache.guild(guild_id));
+        let roles = self.mentioned_role_ids()
+            .into_iter()
+            .map(|id| {
+                let name = guild
+                    .as_ref()
+                    .and_then(|guild| guild.read().unwrap().roles.get(&id).map(|r| r.name.clone()))
+                    .unwrap_or_else(|| id.0.to_string());
+
+                (id, name)
+            })
+            .collect();
+
+        let channels = self.mentioned_channel_ids()
+            .into_iter()
+            .map(|id| {
+                let name = cache
+                    .guild_channel(id)
+                    .map(|channel| channel.read().unwrap().name.clone())
+                    .unwrap_or_else(|| id.0.to_string());
+
+                (id, name)
+            })
+            .collect();
+
+        ResolvedMentions {
+            channels: channels,
+            roles: roles,
+            users: users,
+        }
+    }
+
+    /// Suppresses or unsuppresses the embeds of the message, by updating its
+    /// [`MessageFlags::SUPPRESS_EMBEDS`] flag.
+    ///
+    /// Other flags of the message are left untouched. Once the message was
+    /// edited, its [`flags`] and [`embeds`] are updated as described in
+    /// [`apply_suppress_embeds`].
+    ///
+    /// **Note**: The logged in user must either be the author of the message or
+    /// have the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` feature is enabled, then returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`MessageFlags::SUPPRESS_EMBEDS`]: struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`apply_suppress_embeds`]: #method.apply_suppress_embeds
+    /// [`embeds`]: #structfield.embeds
+    /// [`flags`]: #structfield.flags
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    pub fn suppress_embeds(&mut self, suppress: bool) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_MESSAGES;
+            let is_author = self.author.id == CACHE.read().unwrap().user.id;
+
+            if !is_author && !utils::user_has_perms(self.channel_id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        let mut flags = self.flags.unwrap_or_else(MessageFlags::empty);
+        flags.set(MessageFlags::SUPPRESS_EMBEDS, suppress);
+
+        let map = json!({
+            "flags": flags.bits(),
+        });
+
+        http::edit_message(self.channel_id.0, self.id.0, &map)?;
+        self.apply_suppress_embeds(suppress);
+
+        Ok(())
+    }
+
     /// Unpins the message from its channel.
     ///
     /// **Note**: Requires the [Manage Messages] permission.
@@ -504,6 +1460,95 @@ impl Message {
         http::unpin_message(self.channel_id.0, self.id.0)
     }
 
+    /// Whether the message has been edited since it was sent.
+    #[inline]
+    pub fn was_edited(&self) -> bool { self.edited_timestamp.is_some() }
+
+    fn link_for(guild_id: Option<GuildId>, channel_id: ChannelId, message_id: MessageId) -> String {
+        match guild_id {
+            Some(guild_id) => format!(
+                "https://discord.com/channels/{}/{}/{}",
+                guild_id,
+                channel_id,
+                message_id
+            ),
+            None => format!("https://discord.com/channels/@me/{}/{}", channel_id, message_id),
+        }
+    }
+
+    // Each prefix is tried in order against the text following a `<`, so
+    // longer prefixes sharing a start with shorter ones must come first.
+    fn mention_ids(&self, prefixes: &[&str]) -> Vec<u64> {
+        let mut ids = vec![];
+
+        for part in self.content.split('<').skip(1) {
+            let inner = match part.find('>') {
+                Some(end) => &part[..end],
+                None => continue,
+            };
+
+            let id = prefixes
+                .iter()
+                .filter(|prefix| inner.starts_with(*prefix))
+                .map(|prefix| &inner[prefix.len()..])
+                .find(|id| !id.is_empty() && id.chars().all(|c| c.is_digit(10)))
+                .and_then(|id| id.parse::<u64>().ok());
+
+            if let Some(id) = id {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+
+        ids
+    }
+
+    fn reaction_index(&self, reaction_type: &ReactionType) -> Option<usize> {
+        self.reactions
+            .iter()
+            .position(|reaction| reaction.reaction_type == *reaction_type)
+    }
+
+    fn reply_inline(&self, content: &str, mention: bool) -> Result<Message> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::SEND_MESSAGES;
+
+            if !utils::user_has_perms(self.channel_id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        self.channel_id.send_message(|m| {
+            m.content(content)
+                .reference_message(self.id)
+                .mention_reply(mention)
+        })
+    }
+
+    pub(crate) fn check_allowed_mentions(map: &JsonMap) -> Result<()> {
+        let allowed_mentions = match map.get("allowed_mentions") {
+            Some(&Value::Object(ref allowed_mentions)) => allowed_mentions,
+            _ => return Ok(()),
+        };
+
+        let parse = match allowed_mentions.get("parse") {
+            Some(&Value::Array(ref parse)) => parse,
+            _ => return Ok(()),
+        };
+
+        for &kind in &["roles", "users"] {
+            let parsed = parse.iter().any(|value| value.as_str() == Some(kind));
+
+            if parsed && allowed_mentions.contains_key(kind) {
+                return Err(Error::Model(ModelError::AllowedMentionsConflict(kind)));
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn check_content_length(map: &JsonMap) -> Result<()> {
         if let Some(content) = map.get("content") {
             if let Value::String(ref content) = *content {
@@ -516,55 +1561,142 @@ impl Message {
         Ok(())
     }
 
+    pub(crate) fn check_embed_timestamp(map: &JsonMap) -> Result<()> {
+        for embed in Message::embed_maps(map) {
+            if let Some(&Value::String(ref timestamp)) = embed.get("timestamp") {
+                if !Timestamp::from(timestamp.as_str()).is_valid() {
+                    return Err(Error::Model(ModelError::EmbedTimestampInvalid(timestamp.clone())));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_embed_urls(map: &JsonMap) -> Result<()> {
+        const SCHEMES: &'static [&'static str] = &["http://", "https://", "attachment://"];
+
+        for embed in Message::embed_maps(map) {
+            for part in &["author", "footer"] {
+                let url = match embed.get(*part).and_then(|part| part.get("icon_url")) {
+                    Some(&Value::String(ref url)) => url,
+                    _ => continue,
+                };
+                let lowercase = url.to_lowercase();
+
+                let valid = SCHEMES.iter().any(|scheme| {
+                    lowercase.starts_with(scheme) && lowercase.len() > scheme.len()
+                });
+
+                if !valid {
+                    return Err(Error::Model(ModelError::InvalidUrl(url.clone())));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // The limit applies to the total length of all of the message's embeds.
     pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
-        let embed = match map.get("embed") {
-            Some(&Value::Object(ref value)) => value,
-            _ => return Ok(()),
-        };
+        let total = Message::embed_maps(map)
+            .into_iter()
+            .flat_map(Message::embed_parts)
+            .map(|(_, _, text)| text.chars().count())
+            .sum::<usize>();
+
+        if total <= constants::EMBED_MAX_LENGTH as usize {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::EmbedTooLarge {
+                field: "total",
+                limit: u64::from(constants::EMBED_MAX_LENGTH),
+                actual: total as u64,
+            }))
+        }
+    }
+
+    pub(crate) fn check_embed_parts(map: &JsonMap) -> Result<()> {
+        for embed in Message::embed_maps(map) {
+            for (part, limit, text) in Message::embed_parts(embed) {
+                let length = text.chars().count() as u64;
+
+                if length > u64::from(limit) {
+                    return Err(Error::Model(ModelError::EmbedTooLarge {
+                        field: part,
+                        limit: u64::from(limit),
+                        actual: length,
+                    }));
+                }
+            }
+        }
+
+        Ok(())
+    }
 
-        let mut total: usize = 0;
+    pub(crate) fn check_file_count(count: usize) -> Result<()> {
+        let limit = constants::MESSAGE_FILE_LIMIT as usize;
+
+        if count <= limit {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::TooManyFiles((count - limit) as u64)))
+        }
+    }
+
+    // The name, length limit, and text of each textual part of an embed.
+    fn embed_parts(embed: &JsonMap) -> Vec<(&'static str, u16, &str)> {
+        let mut parts = vec![];
 
         if let Some(&Value::Object(ref author)) = embed.get("author") {
-            if let Some(&Value::Object(ref name)) = author.get("name") {
-                total += name.len();
+            if let Some(&Value::String(ref name)) = author.get("name") {
+                parts.push(("author name", constants::EMBED_AUTHOR_NAME_LENGTH, &name[..]));
             }
         }
 
         if let Some(&Value::String(ref description)) = embed.get("description") {
-            total += description.len();
+            parts.push(("description", constants::EMBED_DESCRIPTION_LENGTH, &description[..]));
         }
 
         if let Some(&Value::Array(ref fields)) = embed.get("fields") {
-            for field_as_value in fields {
-                if let Value::Object(ref field) = *field_as_value {
-                    if let Some(&Value::String(ref field_name)) = field.get("name") {
-                        total += field_name.len();
-                    }
+            for field in fields.iter().filter_map(|field| field.as_object()) {
+                if let Some(&Value::String(ref name)) = field.get("name") {
+                    parts.push(("field name", constants::EMBED_FIELD_NAME_LENGTH, &name[..]));
+                }
 
-                    if let Some(&Value::String(ref field_value)) = field.get("value") {
-                        total += field_value.len();
-                    }
+                if let Some(&Value::String(ref value)) = field.get("value") {
+                    parts.push(("field value", constants::EMBED_FIELD_VALUE_LENGTH, &value[..]));
                 }
             }
         }
 
         if let Some(&Value::Object(ref footer)) = embed.get("footer") {
             if let Some(&Value::String(ref text)) = footer.get("text") {
-                total += text.len();
+                parts.push(("footer text", constants::EMBED_FOOTER_TEXT_LENGTH, &text[..]));
             }
         }
 
         if let Some(&Value::String(ref title)) = embed.get("title") {
-            total += title.len();
+            parts.push(("title", constants::EMBED_TITLE_LENGTH, &title[..]));
         }
 
-        if total <= constants::EMBED_MAX_LENGTH as usize {
-            Ok(())
-        } else {
-            let overflow = total as u64 - u64::from(constants::EMBED_MAX_LENGTH);
+        parts
+    }
 
-            Err(Error::Model(ModelError::EmbedTooLarge(overflow)))
+    // Embeds are set through `CreateMessage` under `"embeds"`, but a single
+    // `"embed"` may still have been inserted into the map directly.
+    fn embed_maps(map: &JsonMap) -> Vec<&JsonMap> {
+        let mut embeds = vec![];
+
+        if let Some(&Value::Object(ref embed)) = map.get("embed") {
+            embeds.push(embed);
         }
+
+        if let Some(&Value::Array(ref values)) = map.get("embeds") {
+            embeds.extend(values.iter().filter_map(|value| value.as_object()));
+        }
+
+        embeds
     }
 }
 
@@ -578,6 +1710,46 @@ impl<'a> From<&'a Message> for MessageId {
     fn from(message: &Message) -> MessageId { message.id }
 }
 
+bitflags! {
+    /// A set of flags that Discord attaches to a [`Message`], describing
+    /// special behaviour of the message.
+    ///
+    /// Bits that do not correspond to a known flag are kept as-is when
+    /// deserializing and serializing.
+    ///
+    /// [`Message`]: struct.Message.html
+    pub struct MessageFlags: u64 {
+        /// The message has been published to the guilds following its
+        /// channel.
+        const CROSSPOSTED = 1;
+        /// The message originated from a message in a followed channel.
+        const IS_CROSSPOST = 1 << 1;
+        /// The embeds of the message are not shown.
+        const SUPPRESS_EMBEDS = 1 << 2;
+        /// The message this crosspost originated from has been deleted.
+        const SOURCE_MESSAGE_DELETED = 1 << 3;
+        /// The message came from the urgent message system.
+        const URGENT = 1 << 4;
+        /// The message is only visible to the user who invoked the
+        /// interaction it was sent in response to.
+        const EPHEMERAL = 1 << 6;
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(MessageFlags {
+            bits: u64::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for MessageFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
 /// A representation of a reaction to a message.
 ///
 /// Multiple of the same [reaction type] are sent into one `MessageReaction`,
@@ -597,6 +1769,53 @@ pub struct MessageReaction {
     pub reaction_type: ReactionType,
 }
 
+#[cfg(feature = "model")]
+impl MessageReaction {
+    /// Whether the reaction is of the given emoji.
+    ///
+    /// Refer to [`Reaction::is_emoji`] for how emojis are compared.
+    ///
+    /// [`Reaction::is_emoji`]: struct.Reaction.html#method.is_emoji
+    pub fn is_emoji<R: Into<ReactionType>>(&self, reaction_type: R) -> bool {
+        self.reaction_type == reaction_type.into()
+    }
+}
+
+/// A reference from a [`Message`] to another message, such as the message
+/// that it replies to.
+///
+/// [`Message`]: struct.Message.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageReference {
+    /// The Id of the channel that the referenced message is in.
+    #[serde(default)]
+    pub channel_id: Option<ChannelId>,
+    /// The Id of the guild that the referenced message is in, if any.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// The Id of the referenced message.
+    #[serde(default)]
+    pub message_id: Option<MessageId>,
+}
+
+/// The names of the users, roles, and channels mentioned in a [`Message`], as
+/// returned by [`Message::resolved_mentions`].
+///
+/// Each mention is paired with its name, or with its Id as a string if the
+/// name could not be resolved.
+///
+/// [`Message`]: struct.Message.html
+/// [`Message::resolved_mentions`]: struct.Message.html#method.resolved_mentions
+#[derive(Clone, Debug, Default)]
+pub struct ResolvedMentions {
+    /// The mentioned channels and their names.
+    pub channels: Vec<(ChannelId, String)>,
+    /// The mentioned roles and their names.
+    pub roles: Vec<(RoleId, String)>,
+    /// The mentioned users and their usernames.
+    pub users: Vec<(UserId, String)>,
+}
+
 enum_number!(
     /// Differentiates between regular and different types of system messages.
     MessageType {
@@ -616,5 +1835,40 @@ enum_number!(
         PinsAdd = 6,
         /// An indicator that a member joined the guild.
         MemberJoin = 7,
+        /// An indicator that a member boosted the guild.
+        NitroBoost = 8,
+        /// An indicator that a boost by a member brought the guild to tier 1.
+        NitroTier1 = 9,
+        /// An indicator that a boost by a member brought the guild to tier 2.
+        NitroTier2 = 10,
+        /// An indicator that a boost by a member brought the guild to tier 3.
+        NitroTier3 = 11,
+        /// An indicator that a channel was followed into this channel.
+        ChannelFollowAdd = 12,
     }
 );
+
+impl MessageType {
+    /// Whether messages of this type can be deleted.
+    ///
+    /// Regular messages, pin notifications, member joins, boost
+    /// notifications, and channel follow notifications can be deleted, while
+    /// the remaining group-related system messages can not.
+    pub fn is_deletable(&self) -> bool {
+        match *self {
+            MessageType::Regular
+            | MessageType::PinsAdd
+            | MessageType::MemberJoin
+            | MessageType::NitroBoost
+            | MessageType::NitroTier1
+            | MessageType::NitroTier2
+            | MessageType::NitroTier3
+            | MessageType::ChannelFollowAdd => true,
+            MessageType::GroupRecipientAddition
+            | MessageType::GroupRecipientRemoval
+            | MessageType::GroupCallCreation
+            | MessageType::GroupNameUpdate
+            | MessageType::GroupIconUpdate => false,
+        }
+    }
+}
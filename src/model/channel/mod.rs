@@ -1,5 +1,6 @@
 mod attachment;
 mod channel_id;
+mod component;
 mod embed;
 mod group;
 mod guild_channel;
@@ -7,9 +8,12 @@ mod message;
 mod private_channel;
 mod reaction;
 mod channel_category;
+#[cfg(feature = "model")]
+mod typing;
 
 pub use self::attachment::*;
 pub use self::channel_id::*;
+pub use self::component::*;
 pub use self::embed::*;
 pub use self::group::*;
 pub use self::guild_channel::*;
@@ -17,19 +21,25 @@ pub use self::message::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
 pub use self::channel_category::*;
+#[cfg(feature = "model")]
+pub use self::typing::*;
 
 use internal::RwLockExt;
 use serde::de::Error as DeError;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
 use serde_json;
 use super::utils::deserialize_u64;
 use model::*;
+use std::str::FromStr;
 
 #[cfg(feature = "model")]
 use std::fmt::{Display, Formatter, Result as FmtResult};
 #[cfg(feature = "model")]
-use builder::{CreateMessage, GetMessages};
+use builder::{CreateMessage, EditChannel, GetMessages};
 #[cfg(feature = "model")]
-use http::AttachmentType;
+use http::{AttachmentType, HttpError, StatusCode};
+#[cfg(all(feature = "cache", feature = "model"))]
+use CACHE;
 
 /// A container for any channel.
 #[derive(Clone, Debug)]
@@ -54,6 +64,85 @@ pub enum Channel {
 }
 
 impl Channel {
+    /// Checks through the [`Cache`] whether the current user can [`delete`]
+    /// the channel, without deleting it.
+    ///
+    /// Guild channels and categories require the [Manage Channels]
+    /// permission, and groups require the current user to be their owner.
+    /// Private channels can always be closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the channel, or the guild of a
+    /// guild channel or category, is not in the cache.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`ModelError::ItemMissing`]: enum.ModelError.html#variant.ItemMissing
+    /// [`delete`]: #method.delete
+    /// [Manage Channels]: permissions/constant.MANAGE_CHANNELS.html
+    #[cfg(all(feature = "cache", feature = "model"))]
+    pub fn can_delete(&self) -> Result<bool> {
+        let req = Permissions::MANAGE_CHANNELS;
+
+        match *self {
+            Channel::Group(ref group) => {
+                let owner_id = group.with(|g| g.owner_id);
+
+                Ok(owner_id == CACHE.read().unwrap().user.id)
+            },
+            Channel::Guild(ref channel) => utils::user_has_perms(channel.with(|c| c.id), req),
+            Channel::Private(_) => Ok(true),
+            Channel::Category(ref category) => {
+                let (id, guild_id) = category.with(|c| (c.id, c.guild_id));
+                let cache = CACHE.read().unwrap();
+
+                // Categories are not checked by `utils::user_has_perms`, so
+                // their guild's permissions are used directly.
+                match guild_id.and_then(|guild_id| cache.guild(guild_id)) {
+                    Some(guild) => {
+                        let perms = guild.with(|g| g.permissions_for(id, cache.user.id));
+
+                        Ok(perms.contains(req))
+                    },
+                    None => Err(Error::Model(ModelError::ItemMissing)),
+                }
+            },
+        }
+    }
+
+    /// Retrieves the [`ChannelCategory`] that a guild channel is in from the
+    /// [`Cache`].
+    ///
+    /// Returns `None` for channels that are not in a category, and for
+    /// channels other than [`Channel::Guild`]s.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`Channel::Guild`]: #variant.Guild
+    /// [`ChannelCategory`]: struct.ChannelCategory.html
+    #[cfg(all(feature = "cache", feature = "model"))]
+    pub fn category(&self) -> Option<Arc<RwLock<ChannelCategory>>> {
+        match *self {
+            Channel::Guild(ref channel) => channel.read().unwrap().category(),
+            _ => None,
+        }
+    }
+
+    /// Counts how many of the channel's most recent messages match the given
+    /// filter, looking at no more than `scan_limit` messages.
+    ///
+    /// Refer to [`ChannelId::count_messages`] for more information.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// [`ChannelId::count_messages`]: struct.ChannelId.html#method.count_messages
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn count_messages<F>(&self, scan_limit: usize, filter: F) -> Result<usize>
+        where F: Fn(&Message) -> bool {
+        self.id().count_messages(scan_limit, filter)
+    }
+
     /// React to a [`Message`] with a custom [`Emoji`] or unicode character.
     ///
     /// [`Message::react`] may be a more suited method of reacting in most
@@ -73,6 +162,23 @@ impl Channel {
         self.id().create_reaction(message_id, reaction_type)
     }
 
+    /// React to a [`Message`] with multiple reactions, applying them one after
+    /// the other in the given order.
+    ///
+    /// Refer to [`ChannelId::create_reactions`] for more information.
+    ///
+    /// [`ChannelId::create_reactions`]: struct.ChannelId.html#method.create_reactions
+    /// [`Message`]: struct.Message.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn create_reactions<M, R, It>(&self,
+                                      message_id: M,
+                                      reactions: It)
+                                      -> StdResult<(), (usize, Error)>
+        where M: Into<MessageId>, R: Into<ReactionType>, It: IntoIterator<Item=R> {
+        self.id().create_reactions(message_id, reactions)
+    }
+
     /// Deletes the inner channel.
     ///
     /// **Note**: There is no real function as _deleting_ a [`Group`]. The
@@ -99,6 +205,28 @@ impl Channel {
         Ok(())
     }
 
+    /// Deletes the inner channel, like [`delete`], but treats the channel
+    /// already having been deleted as a success.
+    ///
+    /// Returns `Ok(true)` if the channel was deleted, and `Ok(false)` if it
+    /// did not exist. This is useful for cleanup that may delete the same
+    /// channel more than once.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`delete`] other than that of the channel not
+    /// being found.
+    ///
+    /// [`delete`]: #method.delete
+    #[cfg(feature = "model")]
+    pub fn delete_if_exists(&self) -> Result<bool> {
+        match self.delete() {
+            Ok(()) => Ok(true),
+            Err(Error::Http(HttpError::InvalidRequest(StatusCode::NotFound))) => Ok(false),
+            Err(why) => Err(why),
+        }
+    }
+
     /// Deletes a [`Message`] given its Id.
     ///
     /// Refer to [`Message::delete`] for more information.
@@ -115,6 +243,34 @@ impl Channel {
         self.id().delete_message(message_id)
     }
 
+    /// Deletes all messages by Ids from the given iterator in the channel.
+    ///
+    /// Any iterator of values that can be converted into a [`MessageId`] is
+    /// accepted, including iterators over `MessageId` references.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// **Note**: This uses bulk delete endpoint which is not available
+    /// for user accounts.
+    ///
+    /// **Note**: Messages that are older than 2 weeks can't be deleted using
+    /// this method.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::BulkDeleteAmount`] if fewer than 2 or more than
+    /// 100 Ids are given.
+    ///
+    /// [`MessageId`]: struct.MessageId.html
+    /// [`ModelError::BulkDeleteAmount`]: enum.ModelError.html#variant.BulkDeleteAmount
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn delete_messages<T, It>(&self, message_ids: It) -> Result<()>
+        where T: Into<MessageId>, It: IntoIterator<Item=T> {
+        self.id().delete_messages(message_ids)
+    }
+
     /// Deletes the given [`Reaction`] from the channel.
     ///
     /// **Note**: Requires the [Manage Messages] permission, _if_ the current
@@ -134,6 +290,59 @@ impl Channel {
             .delete_reaction(message_id, user_id, reaction_type)
     }
 
+    /// Deletes the given [`Reaction`] of the targeted user from the channel.
+    ///
+    /// Refer to [`ChannelId::delete_reaction_target`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission, _if_ the target
+    /// is not the current user.
+    ///
+    /// [`ChannelId::delete_reaction_target`]: struct.ChannelId.html#method.delete_reaction_target
+    /// [`Reaction`]: struct.Reaction.html
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn delete_reaction_target<M, R>(&self,
+                                        message_id: M,
+                                        target: ReactionTarget,
+                                        reaction_type: R)
+                                        -> Result<()>
+        where M: Into<MessageId>, R: Into<ReactionType> {
+        self.id()
+            .delete_reaction_target(message_id, target, reaction_type)
+    }
+
+    /// Edits the inner channel, updating it in-place on success.
+    ///
+    /// Only the [`Guild`] and [`Category`] variants can be edited, through
+    /// [`GuildChannel::edit`] and [`ChannelCategory::edit`] respectively.
+    ///
+    /// **Note**: Requires the [Manage Channel] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] for the [`Group`] and
+    /// [`Private`] variants.
+    ///
+    /// [`Category`]: #variant.Category
+    /// [`ChannelCategory::edit`]: struct.ChannelCategory.html#method.edit
+    /// [`Group`]: #variant.Group
+    /// [`Guild`]: #variant.Guild
+    /// [`GuildChannel::edit`]: struct.GuildChannel.html#method.edit
+    /// [`ModelError::InvalidChannelType`]: enum.ModelError.html#variant.InvalidChannelType
+    /// [`Private`]: #variant.Private
+    /// [Manage Channel]: permissions/constant.MANAGE_CHANNELS.html
+    #[cfg(feature = "model")]
+    pub fn edit<F: FnOnce(EditChannel) -> EditChannel>(&self, f: F) -> Result<()> {
+        match *self {
+            Channel::Guild(ref channel) => channel.write().unwrap().edit(f),
+            Channel::Category(ref category) => category.write().unwrap().edit(f),
+            Channel::Group(_) | Channel::Private(_) => {
+                Err(Error::Model(ModelError::InvalidChannelType(self.kind())))
+            },
+        }
+    }
+
     /// Edits a [`Message`] in the channel given its Id.
     ///
     /// Message editing preserves all unchanged message data.
@@ -160,6 +369,53 @@ impl Channel {
         self.id().edit_message(message_id, f)
     }
 
+    /// Retrieves the inner [`Group`] if the channel is a [`Channel::Group`].
+    ///
+    /// [`Channel::Group`]: #variant.Group
+    /// [`Group`]: struct.Group.html
+    pub fn group(&self) -> Option<Arc<RwLock<Group>>> {
+        match *self {
+            Channel::Group(ref group) => Some(Arc::clone(group)),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the inner [`GuildChannel`] if the channel is a
+    /// [`Channel::Guild`].
+    ///
+    /// # Examples
+    ///
+    /// Set the topic of a channel that is known to be a guild channel:
+    ///
+    /// ```rust,ignore
+    /// if let Some(channel) = channel.guild() {
+    ///     channel.write().unwrap().set_topic("A topic")?;
+    /// }
+    /// ```
+    ///
+    /// [`Channel::Guild`]: #variant.Guild
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    pub fn guild(&self) -> Option<Arc<RwLock<GuildChannel>>> {
+        match *self {
+            Channel::Guild(ref channel) => Some(Arc::clone(channel)),
+            _ => None,
+        }
+    }
+
+    /// Whether the channel is a [`ChannelCategory`].
+    ///
+    /// [`ChannelCategory`]: struct.ChannelCategory.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn is_category(&self) -> bool { self.kind() == ChannelType::Category }
+
+    /// Whether the channel is a [`Group`].
+    ///
+    /// [`Group`]: struct.Group.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn is_group(&self) -> bool { self.kind() == ChannelType::Group }
+
     /// Determines if the channel is NSFW.
     ///
     /// Refer to [`utils::is_nsfw`] for more details.
@@ -175,6 +431,70 @@ impl Channel {
         }
     }
 
+    /// Whether the channel is a [`PrivateChannel`].
+    ///
+    /// [`PrivateChannel`]: struct.PrivateChannel.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn is_private(&self) -> bool { self.kind() == ChannelType::Private }
+
+    /// Whether the channel is a text [`GuildChannel`], including news
+    /// channels.
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    #[cfg(feature = "model")]
+    pub fn is_text(&self) -> bool {
+        match self.kind() {
+            ChannelType::News | ChannelType::Text => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the channel is a voice [`GuildChannel`].
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn is_voice(&self) -> bool { self.kind() == ChannelType::Voice }
+
+    /// Retrieves the [`ChannelType`] of the inner channel.
+    ///
+    /// For the [`Guild`] variant this reads the stored [`GuildChannel::kind`],
+    /// so a voice channel is reported as [`ChannelType::Voice`].
+    ///
+    /// [`ChannelType`]: enum.ChannelType.html
+    /// [`ChannelType::Voice`]: enum.ChannelType.html#variant.Voice
+    /// [`Guild`]: #variant.Guild
+    /// [`GuildChannel::kind`]: struct.GuildChannel.html#structfield.kind
+    pub fn kind(&self) -> ChannelType {
+        match *self {
+            Channel::Group(_) => ChannelType::Group,
+            Channel::Guild(ref ch) => ch.with(|c| c.kind),
+            Channel::Private(_) => ChannelType::Private,
+            Channel::Category(_) => ChannelType::Category,
+        }
+    }
+
+    /// Retrieves the name of the inner channel.
+    ///
+    /// For [`Group`]s without a name and [`Private`] channels, the name is
+    /// generated from the recipients. Refer to [`Group::name`] and
+    /// [`PrivateChannel::name`].
+    ///
+    /// [`Group`]: #variant.Group
+    /// [`Group::name`]: struct.Group.html#method.name
+    /// [`Private`]: #variant.Private
+    /// [`PrivateChannel::name`]: struct.PrivateChannel.html#method.name
+    #[cfg(feature = "model")]
+    pub fn name(&self) -> String {
+        match *self {
+            Channel::Group(ref group) => group.with(|g| g.name().into_owned()),
+            Channel::Guild(ref channel) => channel.with(|c| c.name.clone()),
+            Channel::Private(ref channel) => channel.with(|c| c.name()),
+            Channel::Category(ref category) => category.with(|c| c.name.clone()),
+        }
+    }
+
     /// Gets a message from the channel.
     ///
     /// Requires the [Read Message History] permission.
@@ -205,10 +525,152 @@ impl Channel {
     #[cfg(feature = "model")]
     #[inline]
     pub fn messages<F>(&self, f: F) -> Result<Vec<Message>>
-        where F: FnOnce(&GetMessages) -> &GetMessages {
+        where F: FnOnce(GetMessages) -> GetMessages {
         self.id().messages(f)
     }
 
+    /// Creates an iterator over the channel's messages, from newest to
+    /// oldest.
+    ///
+    /// Refer to [`ChannelId::messages_iter`] for more information.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// [`ChannelId::messages_iter`]: struct.ChannelId.html#method.messages_iter
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn messages_iter(&self) -> MessagesIter { self.id().messages_iter() }
+
+    /// Calculates the permissions of a [`User`] in the channel.
+    ///
+    /// How the permissions are calculated depends on the variant:
+    ///
+    /// - for [`Channel::Guild`], the member's roles are resolved from the
+    /// [`Cache`] and the channel's [`PermissionOverwrite`]s are applied, with
+    /// member overwrites taking precedence over role overwrites. Refer to
+    /// [`GuildChannel::permissions_for`];
+    /// - for [`Channel::Private`] and [`Channel::Group`], a fixed set of
+    /// messaging permissions is returned, as all users of such a channel have
+    /// the same permissions;
+    /// - for [`Channel::Category`], the permissions are calculated the same way
+    /// as for a guild channel, using the category's own overwrites.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::GuildNotFound`] if the guild of a guild
+    /// channel or category could not be found in the [`Cache`].
+    ///
+    /// Returns a [`ModelError::ItemMissing`] for guild channels and
+    /// categories if the `cache` feature is disabled, as the member's roles
+    /// can not be resolved.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`Channel::Category`]: #variant.Category
+    /// [`Channel::Group`]: #variant.Group
+    /// [`Channel::Guild`]: #variant.Guild
+    /// [`Channel::Private`]: #variant.Private
+    /// [`GuildChannel::permissions_for`]: struct.GuildChannel.html#method.permissions_for
+    /// [`ModelError::GuildNotFound`]: enum.ModelError.html#variant.GuildNotFound
+    /// [`ModelError::ItemMissing`]: enum.ModelError.html#variant.ItemMissing
+    /// [`PermissionOverwrite`]: struct.PermissionOverwrite.html
+    /// [`User`]: struct.User.html
+    #[cfg(feature = "model")]
+    pub fn permissions_for<U: Into<UserId>>(&self, user_id: U) -> Result<Permissions> {
+        match *self {
+            Channel::Guild(ref channel) => {
+                feature_cache! {{
+                    channel.read().unwrap().permissions_for(user_id)
+                } else {
+                    let _ = (channel, user_id);
+
+                    Err(Error::Model(ModelError::ItemMissing))
+                }}
+            },
+            Channel::Private(_) | Channel::Group(_) => Ok(
+                Permissions::ADD_REACTIONS
+                    | Permissions::READ_MESSAGES
+                    | Permissions::SEND_MESSAGES
+                    | Permissions::SEND_TTS_MESSAGES
+                    | Permissions::EMBED_LINKS
+                    | Permissions::ATTACH_FILES
+                    | Permissions::READ_MESSAGE_HISTORY
+                    | Permissions::MENTION_EVERYONE
+                    | Permissions::USE_EXTERNAL_EMOJIS,
+            ),
+            Channel::Category(ref category) => {
+                feature_cache! {{
+                    let category = category.read().unwrap();
+
+                    let guild = category
+                        .find_guild_id()
+                        .and_then(|guild_id| CACHE.read().unwrap().guild(guild_id));
+
+                    guild
+                        .ok_or_else(|| Error::Model(ModelError::GuildNotFound))
+                        .map(|g| g.read().unwrap().permissions_for(category.id, user_id))
+                } else {
+                    let _ = (category, user_id);
+
+                    Err(Error::Model(ModelError::ItemMissing))
+                }}
+            },
+        }
+    }
+
+    /// Pins a [`Message`] to the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has the
+    /// maximum number of pinned messages, without attempting to pin the
+    /// message.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`ModelError::TooManyPins`]: enum.ModelError.html#variant.TooManyPins
+    #[cfg(feature = "model")]
+    pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        self.id().pin_checked(message_id)
+    }
+
+    /// Pins a [`Message`] to the channel like [`pin`], and then retrieves the
+    /// channel's pinned messages, including the newly pinned one.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`pin`].
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`pin`]: #method.pin
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[cfg(feature = "model")]
+    pub fn pin_and_list<M: Into<MessageId>>(&self, message_id: M) -> Result<Vec<Message>> {
+        self.pin(message_id)?;
+
+        self.id().pins()
+    }
+
+    /// Retrieves the number of [`Message`]s which are pinned to the channel.
+    ///
+    /// [`Message`]: struct.Message.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn pin_count(&self) -> Result<usize> { self.id().pin_count() }
+
+    /// Retrieves the inner [`PrivateChannel`] if the channel is a
+    /// [`Channel::Private`].
+    ///
+    /// [`Channel::Private`]: #variant.Private
+    /// [`PrivateChannel`]: struct.PrivateChannel.html
+    pub fn private(&self) -> Option<Arc<RwLock<PrivateChannel>>> {
+        match *self {
+            Channel::Private(ref channel) => Some(Arc::clone(channel)),
+            _ => None,
+        }
+    }
+
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
     /// certain [`Emoji`].
     ///
@@ -238,6 +700,22 @@ impl Channel {
             .reaction_users(message_id, reaction_type, limit, after)
     }
 
+    /// Retrieves the Id of the guild that the channel belongs to.
+    ///
+    /// Returns `None` for [`Group`]s and [`PrivateChannel`]s, and for
+    /// [`ChannelCategory`]s that were received without their guild's Id.
+    ///
+    /// [`ChannelCategory`]: struct.ChannelCategory.html
+    /// [`Group`]: struct.Group.html
+    /// [`PrivateChannel`]: struct.PrivateChannel.html
+    pub fn guild_id(&self) -> Option<GuildId> {
+        match *self {
+            Channel::Guild(ref ch) => Some(ch.with(|c| c.guild_id)),
+            Channel::Category(ref category) => category.with(|c| c.guild_id),
+            Channel::Group(_) | Channel::Private(_) => None,
+        }
+    }
+
     /// Retrieves the Id of the inner [`Group`], [`GuildChannel`], or
     /// [`PrivateChannel`].
     ///
@@ -318,6 +796,52 @@ impl Channel {
         self.id().send_message(f)
     }
 
+    /// Sends a message to the channel from an already constructed
+    /// [`CreateMessage`] builder.
+    ///
+    /// Refer to [`ChannelId::send_built`] for more information.
+    ///
+    /// [`ChannelId::send_built`]: struct.ChannelId.html#method.send_built
+    /// [`CreateMessage`]: ../builder/struct.CreateMessage.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn send_built(&self, message: CreateMessage) -> Result<Message> {
+        self.id().send_built(message)
+    }
+
+    /// Sets whether the channel is age-restricted.
+    ///
+    /// Refer to [`GuildChannel::set_nsfw`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if the channel is not a
+    /// [`GuildChannel`].
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    /// [`GuildChannel::set_nsfw`]: struct.GuildChannel.html#method.set_nsfw
+    /// [`ModelError::InvalidChannelType`]: enum.ModelError.html#variant.InvalidChannelType
+    #[cfg(feature = "model")]
+    pub fn set_nsfw(&self, nsfw: bool) -> Result<()> {
+        match *self {
+            Channel::Guild(ref channel) => channel.write().unwrap().set_nsfw(nsfw),
+            _ => Err(Error::Model(ModelError::InvalidChannelType(self.kind()))),
+        }
+    }
+
+    /// Broadcasts that the current user is typing in the channel until the
+    /// returned guard is dropped.
+    ///
+    /// Refer to [`Typing`] for more information.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// [`Typing`]: struct.Typing.html
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn typing(&self) -> Result<Typing> { self.id().typing() }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
@@ -329,8 +853,54 @@ impl Channel {
     pub fn unpin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
         self.id().unpin(message_id)
     }
+
+    /// Unpins a [`Message`] in the channel given by its Id, and then
+    /// retrieves the channel's remaining pinned messages.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn unpin_and_list<M: Into<MessageId>>(&self, message_id: M) -> Result<Vec<Message>> {
+        self.id().unpin_and_list(message_id)
+    }
+
+    /// Broadcasts that the current user is typing in the channel while the
+    /// given closure runs, returning the closure's result.
+    ///
+    /// Refer to [`ChannelId::with_typing`] for more information.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// [`ChannelId::with_typing`]: struct.ChannelId.html#method.with_typing
+    /// [Send Messages]: permissions/constant.SEND_MESSAGES.html
+    #[cfg(feature = "model")]
+    #[inline]
+    pub fn with_typing<T, F: FnOnce() -> T>(&self, f: F) -> Result<T> { self.id().with_typing(f) }
+}
+
+impl PartialEq for Channel {
+    /// Compares two channels by their variant and [`id`].
+    ///
+    /// Channels of different variants are never considered equal, even if
+    /// their Ids match.
+    ///
+    /// [`id`]: #method.id
+    fn eq(&self, other: &Channel) -> bool {
+        match (self, other) {
+            (&Channel::Group(_), &Channel::Group(_)) |
+            (&Channel::Guild(_), &Channel::Guild(_)) |
+            (&Channel::Private(_), &Channel::Private(_)) |
+            (&Channel::Category(_), &Channel::Category(_)) => self.id() == other.id(),
+            _ => false,
+        }
+    }
 }
 
+impl Eq for Channel {}
+
 impl<'de> Deserialize<'de> for Channel {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
         let v = JsonMap::deserialize(deserializer)?;
@@ -341,7 +911,7 @@ impl<'de> Deserialize<'de> for Channel {
         };
 
         match kind {
-            0 | 2 => serde_json::from_value::<GuildChannel>(Value::Object(v))
+            0 | 2 | 5 => serde_json::from_value::<GuildChannel>(Value::Object(v))
                 .map(|x| Channel::Guild(Arc::new(RwLock::new(x))))
                 .map_err(DeError::custom),
             1 => serde_json::from_value::<PrivateChannel>(Value::Object(v))
@@ -411,10 +981,64 @@ enum_number!(
 
 [`ChannelCategory`]: struct.ChannelCategory.html"]
         Category = 4,
+        #[doc="An indicator that the channel is a news [`GuildChannel`], whose
+messages can be crossposted to the guilds following it.
+
+[`GuildChannel`]: struct.GuildChannel.html"]
+        News = 5,
     }
 );
 
 impl ChannelType {
+    /// Parses a channel type from the name produced by [`name`].
+    ///
+    /// Returns `None` if the name is not one of the exact lowercase names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::ChannelType;
+    ///
+    /// assert_eq!(ChannelType::from_name("voice"), Some(ChannelType::Voice));
+    /// assert_eq!(ChannelType::from_name("Voice"), None);
+    /// ```
+    ///
+    /// [`name`]: #method.name
+    pub fn from_name(name: &str) -> Option<ChannelType> {
+        Some(match name {
+            "group" => ChannelType::Group,
+            "private" => ChannelType::Private,
+            "text" => ChannelType::Text,
+            "voice" => ChannelType::Voice,
+            "category" => ChannelType::Category,
+            "news" => ChannelType::News,
+            _ => return None,
+        })
+    }
+
+    /// Whether the channel type is of a channel outside of guilds: a
+    /// [`Group`] or a [`PrivateChannel`].
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`PrivateChannel`]: struct.PrivateChannel.html
+    pub fn is_dm(&self) -> bool {
+        match *self {
+            ChannelType::Group | ChannelType::Private => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the channel type is of a channel in a guild: a
+    /// [`GuildChannel`] or a [`ChannelCategory`].
+    ///
+    /// This is the opposite of [`is_dm`].
+    ///
+    /// [`ChannelCategory`]: struct.ChannelCategory.html
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    /// [`is_dm`]: #method.is_dm
+    #[inline]
+    pub fn is_guild(&self) -> bool { !self.is_dm() }
+
     pub fn name(&self) -> &str {
         match *self {
             ChannelType::Group => "group",
@@ -422,10 +1046,29 @@ impl ChannelType {
             ChannelType::Text => "text",
             ChannelType::Voice => "voice",
             ChannelType::Category => "category",
+            ChannelType::News => "news",
+        }
+    }
+
+    /// Whether messages can be sent in channels of the type, which is the
+    /// case for every type other than voice channels and categories.
+    pub fn supports_messages(&self) -> bool {
+        match *self {
+            ChannelType::Category | ChannelType::Voice => false,
+            _ => true,
         }
     }
 }
 
+impl FromStr for ChannelType {
+    type Err = ();
+
+    fn from_str(s: &str) -> StdResult<Self, ()> { ChannelType::from_name(s).ok_or(()) }
+}
+
+const OVERWRITE_MEMBER: &'static str = "member";
+const OVERWRITE_ROLE: &'static str = "role";
+
 #[derive(Deserialize)]
 struct PermissionOverwriteData {
     allow: Permissions,
@@ -435,21 +1078,133 @@ struct PermissionOverwriteData {
 }
 
 /// A channel-specific permission overwrite for a member or role.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PermissionOverwrite {
     pub allow: Permissions,
     pub deny: Permissions,
     pub kind: PermissionOverwriteType,
 }
 
+impl PermissionOverwrite {
+    /// Creates an overwrite for the given member or role which only allows the
+    /// given permissions, denying none.
+    pub fn allow_only(kind: PermissionOverwriteType, permissions: Permissions) -> Self {
+        PermissionOverwrite::builder(kind).allow(permissions).build()
+    }
+
+    /// Creates a builder for an overwrite for the given member or role, with
+    /// no permissions allowed or denied.
+    ///
+    /// # Examples
+    ///
+    /// Create an overwrite which lets a role send messages, but not attach
+    /// files:
+    ///
+    /// ```rust
+    /// use serenity::model::{PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
+    ///
+    /// let overwrite = PermissionOverwrite::builder(PermissionOverwriteType::Role(RoleId(7)))
+    ///     .allow(Permissions::SEND_MESSAGES)
+    ///     .deny(Permissions::ATTACH_FILES)
+    ///     .build();
+    ///
+    /// assert_eq!(overwrite.allow, Permissions::SEND_MESSAGES);
+    /// assert_eq!(overwrite.deny, Permissions::ATTACH_FILES);
+    /// ```
+    pub fn builder(kind: PermissionOverwriteType) -> PermissionOverwriteBuilder {
+        PermissionOverwriteBuilder(PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::empty(),
+            kind: kind,
+        })
+    }
+
+    /// Creates an overwrite for the given member or role which only denies the
+    /// given permissions, allowing none.
+    pub fn deny_only(kind: PermissionOverwriteType, permissions: Permissions) -> Self {
+        PermissionOverwrite::builder(kind).deny(permissions).build()
+    }
+
+    /// Denies the given permissions, removing them from the allowed
+    /// permissions if present.
+    ///
+    /// Other permissions of the overwrite are left untouched.
+    pub fn forbid(&mut self, permissions: Permissions) {
+        self.allow.remove(permissions);
+        self.deny.insert(permissions);
+    }
+
+    /// Allows the given permissions, removing them from the denied
+    /// permissions if present.
+    ///
+    /// Other permissions of the overwrite are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::{PermissionOverwrite, PermissionOverwriteType, Permissions, UserId};
+    ///
+    /// let mut overwrite = PermissionOverwrite {
+    ///     allow: Permissions::empty(),
+    ///     deny: Permissions::ADD_REACTIONS | Permissions::SEND_MESSAGES,
+    ///     kind: PermissionOverwriteType::Member(UserId(7)),
+    /// };
+    /// overwrite.grant(Permissions::SEND_MESSAGES);
+    ///
+    /// assert_eq!(overwrite.allow, Permissions::SEND_MESSAGES);
+    /// assert_eq!(overwrite.deny, Permissions::ADD_REACTIONS);
+    /// ```
+    pub fn grant(&mut self, permissions: Permissions) {
+        self.deny.remove(permissions);
+        self.allow.insert(permissions);
+    }
+}
+
+/// A builder for a [`PermissionOverwrite`], created by
+/// [`PermissionOverwrite::builder`].
+///
+/// [`PermissionOverwrite`]: struct.PermissionOverwrite.html
+/// [`PermissionOverwrite::builder`]: struct.PermissionOverwrite.html#method.builder
+#[derive(Clone, Debug)]
+pub struct PermissionOverwriteBuilder(PermissionOverwrite);
+
+impl PermissionOverwriteBuilder {
+    /// Allows the given permissions, removing them from the denied
+    /// permissions if present.
+    ///
+    /// Refer to [`PermissionOverwrite::grant`] for more information.
+    ///
+    /// [`PermissionOverwrite::grant`]: struct.PermissionOverwrite.html#method.grant
+    pub fn allow(mut self, permissions: Permissions) -> Self {
+        self.0.grant(permissions);
+
+        self
+    }
+
+    /// Builds the overwrite.
+    pub fn build(self) -> PermissionOverwrite { self.0 }
+
+    /// Denies the given permissions, removing them from the allowed
+    /// permissions if present.
+    ///
+    /// Refer to [`PermissionOverwrite::forbid`] for more information.
+    ///
+    /// [`PermissionOverwrite::forbid`]: struct.PermissionOverwrite.html#method.forbid
+    pub fn deny(mut self, permissions: Permissions) -> Self {
+        self.0.forbid(permissions);
+
+        self
+    }
+}
+
 impl<'de> Deserialize<'de> for PermissionOverwrite {
     fn deserialize<D: Deserializer<'de>>(deserializer: D)
                                          -> StdResult<PermissionOverwrite, D::Error> {
         let data = PermissionOverwriteData::deserialize(deserializer)?;
 
         let kind = match &data.kind[..] {
-            "member" => PermissionOverwriteType::Member(UserId(data.id)),
-            "role" => PermissionOverwriteType::Role(RoleId(data.id)),
+            OVERWRITE_MEMBER => PermissionOverwriteType::Member(UserId(data.id)),
+            OVERWRITE_ROLE => PermissionOverwriteType::Role(RoleId(data.id)),
             _ => return Err(DeError::custom("Unknown PermissionOverwriteType")),
         };
 
@@ -461,6 +1216,18 @@ impl<'de> Deserialize<'de> for PermissionOverwrite {
     }
 }
 
+impl Serialize for PermissionOverwrite {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("PermissionOverwrite", 4)?;
+        state.serialize_field("allow", &self.allow.bits())?;
+        state.serialize_field("deny", &self.deny.bits())?;
+        state.serialize_field("id", &self.kind.id().to_string())?;
+        state.serialize_field("type", self.kind.kind_str())?;
+
+        state.end()
+    }
+}
+
 /// The type of edit being made to a Channel's permissions.
 ///
 /// This is for use with methods such as `GuildChannel::create_permission`.
@@ -473,3 +1240,22 @@ pub enum PermissionOverwriteType {
     /// A role which is having its permission overwrites edited.
     Role(RoleId),
 }
+
+impl PermissionOverwriteType {
+    /// Retrieves the Id of the member or role.
+    pub fn id(&self) -> u64 {
+        match *self {
+            PermissionOverwriteType::Member(id) => id.0,
+            PermissionOverwriteType::Role(id) => id.0,
+        }
+    }
+
+    /// Retrieves the name of the type as used by Discord, which is either
+    /// `"member"` or `"role"`.
+    pub fn kind_str(&self) -> &'static str {
+        match *self {
+            PermissionOverwriteType::Member(_) => OVERWRITE_MEMBER,
+            PermissionOverwriteType::Role(_) => OVERWRITE_ROLE,
+        }
+    }
+}
@@ -40,6 +40,19 @@ impl PrivateChannel {
     /// Broadcasts that the current user is typing to the recipient.
     pub fn broadcast_typing(&self) -> Result<()> { self.id.broadcast_typing() }
 
+    /// Closes the channel, like closing a private channel in the client.
+    ///
+    /// This does not delete the contents of the channel: the channel is
+    /// re-opened, with its history, the next time a message is sent to or
+    /// received from the recipient.
+    ///
+    /// If the `cache` is enabled, the channel is also removed from the
+    /// [`Cache`], so that it is added back once it is re-opened.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    #[inline]
+    pub fn close(&self) -> Result<()> { self.id.delete().map(|_| ()) }
+
     /// React to a [`Message`] with a custom [`Emoji`] or unicode character.
     ///
     /// [`Message::react`] may be a more suited method of reacting in most
@@ -57,9 +70,30 @@ impl PrivateChannel {
         self.id.create_reaction(message_id, reaction_type)
     }
 
+    /// React to a [`Message`] with multiple reactions, applying them one after
+    /// the other in the given order.
+    ///
+    /// Refer to [`ChannelId::create_reactions`] for more information.
+    ///
+    /// [`ChannelId::create_reactions`]: struct.ChannelId.html#method.create_reactions
+    /// [`Message`]: struct.Message.html
+    #[inline]
+    pub fn create_reactions<M, R, It>(&self,
+                                      message_id: M,
+                                      reactions: It)
+                                      -> StdResult<(), (usize, Error)>
+        where M: Into<MessageId>, R: Into<ReactionType>, It: IntoIterator<Item=R> {
+        self.id.create_reactions(message_id, reactions)
+    }
+
     /// Deletes the channel. This does not delete the contents of the channel,
     /// and is equivalent to closing a private channel on the client, which can
     /// be re-opened.
+    ///
+    /// Prefer [`close`], which is named after what this does. This is kept
+    /// for backwards compatibility, and returns the closed channel.
+    ///
+    /// [`close`]: #method.close
     #[inline]
     pub fn delete(&self) -> Result<Channel> { self.id.delete() }
 
@@ -78,7 +112,8 @@ impl PrivateChannel {
     /// [`Channel::delete_messages`]: enum.Channel.html#method.delete_messages
     /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
     #[inline]
-    pub fn delete_messages<'a, It: IntoIterator<Item=&'a MessageId>>(&self, message_ids: It) -> Result<()> {
+    pub fn delete_messages<T, It>(&self, message_ids: It) -> Result<()>
+        where T: Into<MessageId>, It: IntoIterator<Item=T> {
         self.id.delete_messages(message_ids)
     }
 
@@ -146,6 +181,12 @@ impl PrivateChannel {
     #[inline]
     pub fn is_nsfw(&self) -> bool { false }
 
+    /// Determines whether the given user is the recipient of the channel.
+    #[inline]
+    pub fn is_recipient<U: Into<UserId>>(&self, user_id: U) -> bool {
+        self.recipient_id() == user_id.into()
+    }
+
     /// Gets a message from the channel.
     ///
     /// Requires the [Read Message History] permission.
@@ -166,12 +207,30 @@ impl PrivateChannel {
     /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
     #[inline]
     pub fn messages<F>(&self, f: F) -> Result<Vec<Message>>
-        where F: FnOnce(&GetMessages) -> &GetMessages {
+        where F: FnOnce(GetMessages) -> GetMessages {
         self.id.messages(f)
     }
 
+    /// Creates an iterator over the channel's messages, from newest to
+    /// oldest.
+    ///
+    /// Refer to [`ChannelId::messages_iter`] for more information.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// [`ChannelId::messages_iter`]: struct.ChannelId.html#method.messages_iter
+    /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
+    #[inline]
+    pub fn messages_iter(&self) -> MessagesIter { self.id.messages_iter() }
+
     /// Returns "DM with $username#discriminator".
-    pub fn name(&self) -> String { format!("DM with {}", self.recipient.with(|r| r.tag())) }
+    ///
+    /// The recipient's lock is acquired internally. To retrieve only the
+    /// recipient's username - as the [`Display`] implementation formats the
+    /// channel - use `to_string` instead.
+    ///
+    /// [`Display`]: #impl-Display
+    pub fn name(&self) -> String { format!("DM with {}", self.recipient_tag()) }
 
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
     /// certain [`Emoji`].
@@ -208,6 +267,37 @@ impl PrivateChannel {
     #[inline]
     pub fn pins(&self) -> Result<Vec<Message>> { self.id.pins() }
 
+    /// Returns the Id of the recipient.
+    ///
+    /// This only briefly locks the recipient, and is preferable to reading
+    /// the [`User`] when only the Id is needed.
+    ///
+    /// [`User`]: struct.User.html
+    #[inline]
+    pub fn recipient_id(&self) -> UserId { self.recipient.with(|r| r.id) }
+
+    /// Returns the recipient's name and discriminator in the form of
+    /// `username#discriminator`.
+    pub fn recipient_tag(&self) -> String { self.recipient.with(|r| r.tag()) }
+
+    /// Re-fetches the recipient over the REST API, replacing the data of the
+    /// inner [`User`].
+    ///
+    /// As the recipient is shared with the [`Cache`], this refreshes the
+    /// cached user as well.
+    ///
+    /// **Note**: The current user must be a bot user.
+    ///
+    /// [`Cache`]: ../cache/struct.Cache.html
+    /// [`User`]: struct.User.html
+    pub fn refresh_recipient(&self) -> Result<()> {
+        let user = self.recipient.with(|r| r.id).get()?;
+
+        *self.recipient.write().unwrap() = user;
+
+        Ok(())
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// # Errors
@@ -264,6 +354,15 @@ impl PrivateChannel {
         self.id.send_message(f)
     }
 
+    /// Broadcasts that the current user is typing in the channel until the
+    /// returned guard is dropped.
+    ///
+    /// Refer to [`Typing`] for more information.
+    ///
+    /// [`Typing`]: struct.Typing.html
+    #[inline]
+    pub fn typing(&self) -> Result<Typing> { self.id.typing() }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
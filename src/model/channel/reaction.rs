@@ -1,11 +1,19 @@
 use serde::de::{Deserialize, Error as DeError, MapAccess, Visitor};
+#[cfg(any(feature = "model", feature = "http"))]
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 use internal::prelude::*;
 use model::*;
 
 #[cfg(all(feature = "cache", feature = "model"))]
 use CACHE;
 #[cfg(feature = "model")]
+use constants;
+#[cfg(feature = "model")]
+use std::cmp;
+#[cfg(feature = "model")]
 use http;
 
 /// An emoji reaction to a message.
@@ -33,6 +41,9 @@ impl Reaction {
     /// Deletes the reaction, but only if the current user is the user who made
     /// the reaction or has permission to.
     ///
+    /// Refer to [`delete_all`] to delete the reactions of all users with this
+    /// emoji.
+    ///
     /// **Note**: Requires the [Manage Messages] permission, _if_ the current
     /// user did not perform the reaction.
     ///
@@ -43,6 +54,7 @@ impl Reaction {
     /// the required [permissions].
     ///
     /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [`delete_all`]: #method.delete_all
     /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
     /// [permissions]: permissions
     pub fn delete(&self) -> Result<()> {
@@ -77,8 +89,63 @@ impl Reaction {
         http::delete_reaction(self.channel_id.0, self.message_id.0, user_id, &self.emoji)
     }
 
-    /// Retrieves the list of [`User`]s who have reacted to a [`Message`] with a
-    /// certain [`Emoji`].
+    /// Deletes the reactions of all users with the reaction's emoji from the
+    /// message.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, then returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required [permissions].
+    ///
+    /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
+    /// [Manage Messages]: permissions/constant.MANAGE_MESSAGES.html
+    /// [permissions]: permissions
+    pub fn delete_all(&self) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_MESSAGES;
+
+            if !utils::user_has_perms(self.channel_id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        self.channel_id
+            .delete_reaction_emoji(self.message_id, self.emoji.clone())
+    }
+
+    /// Whether the reaction was made with the given emoji.
+    ///
+    /// Custom emojis are compared by Id alone, as the name of a custom emoji
+    /// in a reaction may be outdated or missing, while unicode emojis are
+    /// compared by their unicode, ignoring variation selectors (`U+FE0E` and
+    /// `U+FE0F`) so that, for example, `"❤"` and `"❤\u{FE0F}"` match. Skin
+    /// tone modifiers are significant.
+    ///
+    /// # Examples
+    ///
+    /// Only handle reactions with a thumbs up:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::Reaction;
+    /// #
+    /// fn on_reaction_add(reaction: &Reaction) {
+    ///     if !reaction.is_emoji("👍") {
+    ///         return;
+    ///     }
+    ///
+    ///     // Handle the vote.
+    /// }
+    /// ```
+    pub fn is_emoji<R: Into<ReactionType>>(&self, reaction_type: R) -> bool {
+        self.emoji == reaction_type.into()
+    }
+
+    /// Retrieves the list of [`User`]s who have reacted to the [`Message`]
+    /// with the reaction's emoji.
     ///
     /// The default `limit` is `50` - specify otherwise to receive a different
     /// maximum number of users. The maximum that may be retrieve at a time is
@@ -95,31 +162,91 @@ impl Reaction {
     /// not have the required [permissions].
     ///
     /// [`ModelError::InvalidPermissions`]: enum.ModelError.html#variant.InvalidPermissions
-    /// [`Emoji`]: struct.Emoji.html
     /// [`Message`]: struct.Message.html
     /// [`User`]: struct.User.html
     /// [Read Message History]: permissions/constant.READ_MESSAGE_HISTORY.html
     /// [permissions]: permissions
-    pub fn users<R, U>(&self,
-                       reaction_type: R,
-                       limit: Option<u8>,
-                       after: Option<U>)
-                       -> Result<Vec<User>>
-        where R: Into<ReactionType>, U: Into<UserId> {
-        http::get_reaction_users(
-            self.channel_id.0,
-            self.message_id.0,
-            &reaction_type.into(),
-            limit.unwrap_or(50),
-            after.map(|u| u.into().0),
-        )
+    pub fn users<U: Into<UserId>>(&self, limit: Option<u8>, after: Option<U>) -> Result<Vec<User>> {
+        self.channel_id
+            .reaction_users(self.message_id, self.emoji.clone(), limit, after)
+    }
+
+    /// Resolves the number of users to retrieve per request for a reaction,
+    /// as done by [`users`] and the other `reaction_users` methods.
+    ///
+    /// `None` resolves to [`REACTION_USERS_DEFAULT_LIMIT`], and limits over
+    /// [`REACTION_USERS_MAX_LIMIT`] are reduced to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::Reaction;
+    ///
+    /// assert_eq!(Reaction::users_limit(None), 50);
+    /// assert_eq!(Reaction::users_limit(Some(200)), 100);
+    /// ```
+    ///
+    /// [`REACTION_USERS_DEFAULT_LIMIT`]: ../constants/constant.REACTION_USERS_DEFAULT_LIMIT.html
+    /// [`REACTION_USERS_MAX_LIMIT`]: ../constants/constant.REACTION_USERS_MAX_LIMIT.html
+    /// [`users`]: #method.users
+    pub fn users_limit(limit: Option<u8>) -> u8 {
+        limit.map_or(constants::REACTION_USERS_DEFAULT_LIMIT, |limit| {
+            cmp::min(limit, constants::REACTION_USERS_MAX_LIMIT)
+        })
+    }
+}
+
+/// The user whose [`Reaction`] is targeted, for use with methods such as
+/// [`ChannelId::delete_reaction_target`].
+///
+/// [`ChannelId::delete_reaction_target`]: struct.ChannelId.html#method.delete_reaction_target
+/// [`Reaction`]: struct.Reaction.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ReactionTarget {
+    /// The current user.
+    Me,
+    /// Another user, given by their Id.
+    User(UserId),
+}
+
+impl ReactionTarget {
+    /// Retrieves the Id of the targeted user, or `None` if the current user
+    /// is targeted.
+    pub fn user_id(&self) -> Option<UserId> {
+        match *self {
+            ReactionTarget::Me => None,
+            ReactionTarget::User(user_id) => Some(user_id),
+        }
     }
 }
 
+impl From<UserId> for ReactionTarget {
+    fn from(user_id: UserId) -> ReactionTarget { ReactionTarget::User(user_id) }
+}
+
 /// The type of a [`Reaction`] sent.
 ///
+/// A reaction type has two string forms, which are not interchangeable:
+///
+/// - the data form, `name:id` for custom emojis, which is what the reaction
+/// endpoints expect. This is produced by [`as_data`] and the `String`
+/// conversion;
+/// - the display form, `<:name:id>` for custom emojis, which is what clients
+/// render in message content. This is produced by the [`Display`]
+/// implementation, e.g. via `to_string()`.
+///
+/// Unicode emojis are the same in both forms.
+///
+/// Reaction types are compared and hashed by the emoji they represent: custom
+/// emojis by their Id alone, and unicode emojis by their unicode without any
+/// variation selectors. This makes them usable as map keys, such as in
+/// [`Message::reaction_map`].
+///
+/// [`Display`]: #impl-Display
+/// [`Message::reaction_map`]: struct.Message.html#method.reaction_map
 /// [`Reaction`]: struct.Reaction.html
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// [`as_data`]: #method.as_data
+#[derive(Clone, Debug)]
 pub enum ReactionType {
     /// A reaction with a [`Guild`]s custom [`Emoji`], which is unique to the
     /// guild.
@@ -127,6 +254,8 @@ pub enum ReactionType {
     /// [`Emoji`]: struct.Emoji.html
     /// [`Guild`]: struct.Guild.html
     Custom {
+        /// Whether the emoji is animated.
+        animated: bool,
         /// The Id of the custom [`Emoji`].
         ///
         /// [`Emoji`]: struct.Emoji.html
@@ -144,6 +273,7 @@ impl<'de> Deserialize<'de> for ReactionType {
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "snake_case")]
         enum Field {
+            Animated,
             Id,
             Name,
         }
@@ -158,11 +288,19 @@ impl<'de> Deserialize<'de> for ReactionType {
             }
 
             fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> StdResult<Self::Value, V::Error> {
+                let mut animated = None;
                 let mut id = None;
                 let mut name = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
+                        Field::Animated => {
+                            if animated.is_some() {
+                                return Err(DeError::duplicate_field("animated"));
+                            }
+
+                            animated = Some(map.next_value()?);
+                        },
                         Field::Id => {
                             if id.is_some() {
                                 return Err(DeError::duplicate_field("id"));
@@ -182,10 +320,12 @@ impl<'de> Deserialize<'de> for ReactionType {
                     }
                 }
 
+                let animated = animated.unwrap_or(false);
                 let name = name.ok_or_else(|| DeError::missing_field("name"))?;
 
                 Ok(if let Some(id) = id {
                     ReactionType::Custom {
+                        animated: animated,
                         id: id,
                         name: name,
                     }
@@ -199,21 +339,205 @@ impl<'de> Deserialize<'de> for ReactionType {
     }
 }
 
+impl ReactionType {
+    /// Creates a reaction type for a [custom][`ReactionType::Custom`] emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::{EmojiId, ReactionType};
+    ///
+    /// let name = Some("ferris".to_string());
+    /// let ferris = ReactionType::custom(false, EmojiId(302233550662713344), name);
+    /// ```
+    ///
+    /// [`ReactionType::Custom`]: enum.ReactionType.html#variant.Custom
+    pub fn custom(animated: bool, id: EmojiId, name: Option<String>) -> ReactionType {
+        ReactionType::Custom {
+            animated: animated,
+            id: id,
+            name: name,
+        }
+    }
+
+    /// Creates a reaction type for a [unicode][`ReactionType::Unicode`] emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::ReactionType;
+    ///
+    /// let thumbs_up = ReactionType::unicode("👍");
+    /// ```
+    ///
+    /// [`ReactionType::Unicode`]: enum.ReactionType.html#variant.Unicode
+    pub fn unicode<S: Into<String>>(unicode: S) -> ReactionType {
+        ReactionType::Unicode(unicode.into())
+    }
+}
+
 #[cfg(any(feature = "model", feature = "http"))]
 impl ReactionType {
-    /// Creates a data-esque display of the type. This is not very useful for
-    /// displaying, as the primary client can not render it, but can be useful
-    /// for debugging.
+    /// Creates the raw, unencoded form of the type that Discord expects when
+    /// referring to a reaction: `name:id` for a [custom][`ReactionType::Custom`]
+    /// emoji - regardless of whether it is animated - and the literal
+    /// unicode for a [unicode][`ReactionType::Unicode`] emoji.
+    ///
+    /// This is not very useful for displaying, as the primary client can not
+    /// render it. Use the [`Display`] implementation for that instead.
+    ///
+    /// Unicode emojis are normalized to the sequence that Discord expects,
+    /// so that the same emoji works with or without a variation selector:
+    ///
+    /// - a lone symbol that is displayed as text by default, such as `"❤"`,
+    /// is sent with the emoji variation selector `U+FE0F` - replacing a text
+    /// variation selector `U+FE0E` if given;
+    /// - a variation selector directly before a skin tone modifier is
+    /// dropped, so `"✌\u{FE0F}\u{1F3FD}"` is sent as `"✌\u{1F3FD}"`;
+    /// - anything else, such as emojis displayed as emojis by default or
+    /// joined sequences, is sent as given.
+    ///
+    /// Symbols outside of the Basic Multilingual Plane are assumed to display
+    /// as emojis by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::{EmojiId, ReactionType};
+    ///
+    /// let custom = ReactionType::Custom {
+    ///     animated: false,
+    ///     id: EmojiId(302233550662713344),
+    ///     name: Some("ferris".to_string()),
+    /// };
     ///
-    /// **Note**: This is mainly for use internally. There is otherwise most
-    /// likely little use for it.
+    /// assert_eq!(custom.as_data(), "ferris:302233550662713344");
+    /// assert_eq!(ReactionType::from("🍎").as_data(), "🍎");
+    /// assert_eq!(ReactionType::from("\u{2764}").as_data(), "\u{2764}\u{FE0F}");
+    /// ```
+    ///
+    /// [`Display`]: #impl-Display
+    /// [`ReactionType::Custom`]: enum.ReactionType.html#variant.Custom
+    /// [`ReactionType::Unicode`]: enum.ReactionType.html#variant.Unicode
     pub fn as_data(&self) -> String {
         match *self {
             ReactionType::Custom {
                 id,
                 ref name,
+                ..
             } => format!("{}:{}", name.as_ref().map_or("", |s| s.as_str()), id),
-            ReactionType::Unicode(ref unicode) => unicode.clone(),
+            ReactionType::Unicode(ref unicode) => normalize_unicode(unicode).into_owned(),
+        }
+    }
+
+    /// Creates the percent-encoded form of [`as_data`], suitable for use as
+    /// a segment of a REST path.
+    ///
+    /// The `:` separating the name and Id of custom emojis is kept as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::ReactionType;
+    ///
+    /// assert_eq!(ReactionType::from("🍎").as_url_encoded(), "%F0%9F%8D%8E");
+    /// ```
+    ///
+    /// Refer to [`write_url_encoded`] for writing the encoded form into an
+    /// existing buffer instead.
+    ///
+    /// [`as_data`]: #method.as_data
+    /// [`write_url_encoded`]: #method.write_url_encoded
+    pub fn as_url_encoded(&self) -> String {
+        let mut encoded = String::new();
+        // Writing to a `String` does not fail.
+        let _ = self.write_url_encoded(&mut encoded);
+
+        encoded
+    }
+
+    /// Writes the percent-encoded form of [`as_data`] into the given writer,
+    /// such as a `String` that is reused between requests.
+    ///
+    /// This writes the same output as [`as_url_encoded`], without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::ReactionType;
+    ///
+    /// let mut path = String::from("reactions/");
+    /// ReactionType::from("🍎").write_url_encoded(&mut path).unwrap();
+    ///
+    /// assert_eq!(path, "reactions/%F0%9F%8D%8E");
+    /// ```
+    ///
+    /// [`as_data`]: #method.as_data
+    /// [`as_url_encoded`]: #method.as_url_encoded
+    pub fn write_url_encoded<W: FmtWrite>(&self, w: &mut W) -> FmtResult {
+        fn encode<W: FmtWrite>(w: &mut W, data: &str) -> FmtResult {
+            for byte in data.bytes() {
+                match byte {
+                    b'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'-' | b'.' | b'_' | b'~' | b':' => {
+                        w.write_char(byte as char)?
+                    },
+                    _ => write!(w, "%{:02X}", byte)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        match *self {
+            ReactionType::Custom {
+                id,
+                ref name,
+                ..
+            } => {
+                if let Some(ref name) = *name {
+                    encode(w, name)?;
+                }
+
+                write!(w, ":{}", id)
+            },
+            ReactionType::Unicode(ref unicode) => encode(w, &normalize_unicode(unicode)),
+        }
+    }
+}
+
+// Custom emojis are matched by Id alone, as their names may differ between
+// events. Unicode emojis are matched without their variation selectors, which
+// clients and users include inconsistently.
+impl PartialEq for ReactionType {
+    fn eq(&self, other: &ReactionType) -> bool {
+        match (self, other) {
+            (&ReactionType::Custom { id, .. }, &ReactionType::Custom { id: other, .. }) => {
+                id == other
+            },
+            (&ReactionType::Unicode(ref name), &ReactionType::Unicode(ref other)) => {
+                name.chars().filter(not_selector).eq(other.chars().filter(not_selector))
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ReactionType {}
+
+impl Hash for ReactionType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match *self {
+            ReactionType::Custom { id, .. } => {
+                0u8.hash(state);
+                id.hash(state);
+            },
+            ReactionType::Unicode(ref name) => {
+                1u8.hash(state);
+
+                for c in name.chars().filter(not_selector) {
+                    c.hash(state);
+                }
+            },
         }
     }
 }
@@ -247,6 +571,7 @@ impl From<char> for ReactionType {
 impl From<Emoji> for ReactionType {
     fn from(emoji: Emoji) -> ReactionType {
         ReactionType::Custom {
+            animated: false,
             id: emoji.id,
             name: Some(emoji.name),
         }
@@ -277,25 +602,45 @@ impl<'a> From<&'a str> for ReactionType {
     fn from(unicode: &str) -> ReactionType { ReactionType::Unicode(unicode.to_string()) }
 }
 
+#[cfg(any(feature = "model", feature = "http"))]
+impl From<ReactionType> for String {
+    /// Converts the reaction type into its data form, as produced by
+    /// [`ReactionType::as_data`].
+    ///
+    /// [`ReactionType::as_data`]: enum.ReactionType.html#method.as_data
+    fn from(reaction_type: ReactionType) -> String { reaction_type.as_data() }
+}
+
 impl Display for ReactionType {
     /// Formats the reaction type, displaying the associated emoji in a
     /// way that clients can understand.
     ///
     /// If the type is a [custom][`ReactionType::Custom`] emoji, then refer to
     /// the documentation for [emoji's formatter][`Emoji::fmt`] on how this is
-    /// displayed. Otherwise, if the type is a
-    /// [unicode][`ReactionType::Unicode`], then the inner unicode is displayed.
+    /// displayed, with animated emojis being displayed as `<a:name:id>`.
+    /// Otherwise, if the type is a [unicode][`ReactionType::Unicode`], then
+    /// the inner unicode is displayed.
+    ///
+    /// **Note**: This is the display form of the type. Use
+    /// [`ReactionType::as_data`] for the form that reaction endpoints expect.
     ///
     /// [`Emoji::fmt`]: struct.Emoji.html#method.fmt
     /// [`ReactionType::Custom`]: enum.ReactionType.html#variant.Custom
     /// [`ReactionType::Unicode`]: enum.ReactionType.html#variant.Unicode
+    /// [`ReactionType::as_data`]: enum.ReactionType.html#method.as_data
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match *self {
             ReactionType::Custom {
+                animated,
                 id,
                 ref name,
             } => {
                 f.write_char('<')?;
+
+                if animated {
+                    f.write_char('a')?;
+                }
+
                 f.write_char(':')?;
                 f.write_str(name.as_ref().map_or("", |s| s.as_str()))?;
                 f.write_char(':')?;
@@ -306,3 +651,127 @@ impl Display for ReactionType {
         }
     }
 }
+
+impl FromStr for ReactionType {
+    type Err = ();
+
+    /// Parses a reaction type from its display form, as written in message
+    /// content.
+    ///
+    /// Custom emojis are parsed from `<:name:id>`, or `<a:name:id>` if
+    /// animated, while anything else is parsed as a unicode emoji. Strings
+    /// that are empty, contain whitespace, or consist only of ASCII
+    /// characters - such as a malformed custom emoji - are rejected.
+    ///
+    /// # Examples
+    ///
+    /// React with the emoji given as an argument:
+    ///
+    /// ```rust
+    /// use serenity::model::{EmojiId, ReactionType};
+    ///
+    /// let ferris = "<a:ferris:302233550662713344>".parse::<ReactionType>();
+    ///
+    /// assert_eq!(ferris, Ok(ReactionType::Custom {
+    ///     animated: true,
+    ///     id: EmojiId(302233550662713344),
+    ///     name: Some("ferris".to_string()),
+    /// }));
+    /// assert_eq!("👍".parse(), Ok(ReactionType::Unicode("👍".to_string())));
+    /// assert!("<:ferris>".parse::<ReactionType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> StdResult<Self, ()> {
+        if s.is_empty() || s.chars().any(char::is_whitespace) {
+            return Err(());
+        }
+
+        if !s.starts_with('<') {
+            return if s.bytes().all(|b| b < 0x80) {
+                Err(())
+            } else {
+                Ok(ReactionType::Unicode(s.to_string()))
+            };
+        }
+
+        if !s.ends_with('>') {
+            return Err(());
+        }
+
+        let inner = &s[1..s.len() - 1];
+        let (animated, inner) = if inner.starts_with("a:") {
+            (true, &inner[2..])
+        } else if inner.starts_with(':') {
+            (false, &inner[1..])
+        } else {
+            return Err(());
+        };
+
+        let split = inner.rfind(':').ok_or(())?;
+        let (name, id) = (&inner[..split], &inner[split + 1..]);
+
+        if name.is_empty() || id.is_empty() || !id.chars().all(|c| c.is_digit(10)) {
+            return Err(());
+        }
+
+        Ok(ReactionType::Custom {
+            animated: animated,
+            id: EmojiId(id.parse::<u64>().map_err(|_| ())?),
+            name: Some(name.to_string()),
+        })
+    }
+}
+
+#[cfg(any(feature = "model", feature = "http"))]
+fn is_skin_tone(c: char) -> bool { c >= '\u{1F3FB}' && c <= '\u{1F3FF}' }
+
+// Whether a symbol is displayed as text unless followed by `U+FE0F`. Only the
+// Basic Multilingual Plane is checked, where the few symbols displayed as
+// emojis by default are listed.
+#[cfg(any(feature = "model", feature = "http"))]
+fn is_text_default(c: char) -> bool {
+    match c as u32 {
+        0xA9 | 0xAE => true,
+        0x231A...0x231B | 0x23E9...0x23EC | 0x23F0 | 0x23F3 | 0x25FD...0x25FE |
+        0x2614...0x2615 | 0x2648...0x2653 | 0x267F | 0x2693 | 0x26A1 | 0x26AA...0x26AB |
+        0x26BD...0x26BE | 0x26C4...0x26C5 | 0x26CE | 0x26D4 | 0x26EA | 0x26F2...0x26F3 |
+        0x26F5 | 0x26FA | 0x26FD | 0x2705 | 0x270A...0x270B | 0x2728 | 0x274C | 0x274E |
+        0x2753...0x2755 | 0x2757 | 0x2795...0x2797 | 0x27B0 | 0x27BF | 0x2B1B...0x2B1C |
+        0x2B50 | 0x2B55 => false,
+        0x2000...0x3299 => true,
+        _ => false,
+    }
+}
+
+// Normalizes a unicode emoji into the sequence that Discord expects, as
+// documented on `ReactionType::as_data`.
+#[cfg(any(feature = "model", feature = "http"))]
+fn normalize_unicode(unicode: &str) -> Cow<str> {
+    let mut symbols = unicode.chars().filter(not_selector);
+
+    if let (Some(symbol), None) = (symbols.next(), symbols.next()) {
+        if is_text_default(symbol) {
+            return Cow::Owned(format!("{}\u{FE0F}", symbol));
+        }
+    }
+
+    let mut chars = unicode.chars().peekable();
+    let mut normalized = String::with_capacity(unicode.len());
+
+    while let Some(c) = chars.next() {
+        let before_skin_tone = chars.peek().map_or(false, |next| is_skin_tone(*next));
+
+        if !not_selector(&c) && before_skin_tone {
+            continue;
+        }
+
+        normalized.push(c);
+    }
+
+    if normalized.len() == unicode.len() {
+        Cow::Borrowed(unicode)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+fn not_selector(c: &char) -> bool { *c != '\u{FE0E}' && *c != '\u{FE0F}' }
@@ -0,0 +1,88 @@
+use http::{HttpError, StatusCode};
+use model::*;
+
+use std::sync::mpsc::{self, RecvTimeoutError, Sender as MpscSender};
+use std::thread;
+use std::time::Duration;
+
+/// A guard that shows the current user as typing in a channel for as long as
+/// it is alive.
+///
+/// Typing is broadcast when the guard is created, and then again every 8
+/// seconds from a background thread, as Discord stops showing the typing
+/// indicator after roughly 10 seconds. The background thread is stopped when
+/// the guard is dropped or [`stop`] is called, or when the channel can no
+/// longer be found.
+///
+/// This is created by methods such as [`ChannelId::start_typing`].
+///
+/// # Examples
+///
+/// Show the bot as typing while a long-running command is processed:
+///
+/// ```rust,no_run
+/// use serenity::model::ChannelId;
+///
+/// let typing = ChannelId(7).start_typing();
+///
+/// // Do some long-running work.
+///
+/// drop(typing);
+///
+/// let _ = ChannelId(7).say("Done!");
+/// ```
+///
+/// [`ChannelId::start_typing`]: struct.ChannelId.html#method.start_typing
+/// [`stop`]: #method.stop
+#[derive(Debug)]
+pub struct Typing(MpscSender<()>);
+
+impl Typing {
+    /// Broadcasts typing in the given channel, and starts a background thread
+    /// that keeps broadcasting typing until the guard is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the initial broadcast fails, or an [`Error::Io`] if
+    /// the background thread could not be spawned.
+    ///
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    pub fn start(channel_id: ChannelId) -> Result<Typing> {
+        channel_id.broadcast_typing()?;
+
+        let (tx, rx) = mpsc::channel();
+
+        thread::Builder::new()
+            .name(format!("serenity typing {}", channel_id))
+            .spawn(move || loop {
+                // The sender is dropped alongside the guard, disconnecting
+                // the channel and ending the loop.
+                match rx.recv_timeout(Duration::from_secs(8)) {
+                    Err(RecvTimeoutError::Timeout) => match channel_id.broadcast_typing() {
+                        Ok(()) => {},
+                        // The channel was deleted while typing.
+                        Err(Error::Http(HttpError::InvalidRequest(StatusCode::NotFound))) => {
+                            break;
+                        },
+                        Err(why) => {
+                            warn!("Err broadcasting typing in {}: {:?}", channel_id, why);
+                        },
+                    },
+                    _ => break,
+                }
+            })?;
+
+        Ok(Typing(tx))
+    }
+
+    /// Stops broadcasting typing.
+    ///
+    /// This is the same as dropping the guard, and never fails, even if the
+    /// channel has been deleted in the meantime or the background thread has
+    /// already stopped.
+    pub fn stop(self) {}
+}
+
+impl Drop for Typing {
+    fn drop(&mut self) { let _ = self.0.send(()); }
+}
@@ -1,6 +1,6 @@
 use std::error::Error as StdError;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use super::Permissions;
+use super::{ChannelType, Permissions};
 
 /// An error returned from the [`model`] module.
 ///
@@ -66,15 +66,45 @@ use super::Permissions;
 /// [`model`]: ./index.html
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Error {
+    /// Indicates that a message's allowed mentions both parse a type of
+    /// mention and explicitly list Ids of that type, which Discord rejects.
+    ///
+    /// The conflicting type - either `"roles"` or `"users"` - is provided.
+    AllowedMentionsConflict(&'static str),
+    /// Indicates that a voice channel's bitrate is outside of the allowed
+    /// range of 8000 to 96000 bits per second.
+    ///
+    /// The provided value is the given bitrate.
+    BitrateAmount(u64),
     /// When attempting to delete below or above the minimum and maximum allowed
     /// number of messages.
     BulkDeleteAmount,
     /// When attempting to delete a number of days' worth of messages that is
     /// not allowed.
     DeleteMessageDaysAmount(u8),
-    /// Indicates that the textual content of an embed exceeds the maximum
-    /// length.
-    EmbedTooLarge(u64),
+    /// Indicates that the textual content of an embed exceeds Discord's
+    /// maximum length, either for a part of it - such as a title over 256
+    /// unicode code points - or for all of a message's embeds in total.
+    ///
+    /// The name of the part - one of `"author name"`, `"description"`,
+    /// `"field name"`, `"field value"`, `"footer text"`, `"title"`, or
+    /// `"total"` for the combined length - is provided, along with its limit
+    /// and its actual length in unicode code points.
+    EmbedTooLarge {
+        field: &'static str,
+        limit: u64,
+        actual: u64,
+    },
+    /// Indicates that the timestamp of an embed is not a valid ISO-8601
+    /// timestamp, and would be rejected by Discord.
+    ///
+    /// The invalid timestamp is provided.
+    EmbedTimestampInvalid(String),
+    /// Indicates that a recipient can not be added to a [`Group`], as it
+    /// already has the maximum of 10 recipients.
+    ///
+    /// [`Group`]: ../model/struct.Group.html
+    GroupFull,
     /// An indication that a [guild][`Guild`] could not be found by
     /// [Id][`GuildId`] in the [`Cache`].
     ///
@@ -82,6 +112,13 @@ pub enum Error {
     /// [`GuildId`]: ../model/struct.GuildId.html
     /// [`Cache`]: ../cache/struct.Cache.html
     GuildNotFound,
+    /// Indicates that an action can not be performed in a channel of its
+    /// [`ChannelType`], such as crossposting outside of a news channel.
+    ///
+    /// The provided [`ChannelType`] is the type of the channel.
+    ///
+    /// [`ChannelType`]: ../model/enum.ChannelType.html
+    InvalidChannelType(ChannelType),
     /// Indicates that you do not have the required permissions to perform an
     /// operation.
     ///
@@ -90,6 +127,13 @@ pub enum Error {
     ///
     /// [`Permission`]: ../model/permissions/struct.Permissions.html
     InvalidPermissions(Permissions),
+    /// Indicates that a URL - such as the icon of an embed's author or footer
+    /// - does not use a scheme supported by Discord, which would cause it to
+    /// be silently dropped.
+    ///
+    /// The supported schemes are `http://`, `https://`, and `attachment://`.
+    /// The invalid URL is provided.
+    InvalidUrl(String),
     /// An indicator that the [current user] can not perform an action.
     ///
     /// [current user]: ../model/struct.CurrentUser.html
@@ -106,9 +150,38 @@ pub enum Error {
     ///
     /// [`Message`]: ../model/struct.Message.html
     MessageTooLong(u64),
+    /// Indicates that the number of messages to retrieve is either `0` or
+    /// over Discord's limit of 100.
+    ///
+    /// The provided value is the given number of messages.
+    MessageLimitAmount(u64),
     /// Indicates that the current user is attempting to Direct Message another
     /// bot user, which is disallowed by the API.
     MessagingBot,
+    /// Indicates that a channel is not in a category, so there is no category
+    /// to act upon.
+    NoCategory,
+    /// Indicates that a message was sent, but Discord did not echo back the
+    /// nonce it was sent with, so it can not be correlated with the message
+    /// received over the gateway.
+    NonceMismatch,
+    /// Indicates that more files were given to send along with a message than
+    /// Discord's limit of 10.
+    ///
+    /// The number of files over the limit is provided.
+    TooManyFiles(u64),
+    /// Indicates that a message can not be pinned, as the channel already
+    /// has the maximum number of pinned messages.
+    TooManyPins,
+    /// Indicates that a channel's topic is over Discord's limit of 1024
+    /// unicode code points.
+    ///
+    /// The number of unicode code points over the limit is provided.
+    TopicTooLong(u64),
+    /// Indicates that a voice channel's user limit is over the maximum of 99.
+    ///
+    /// The provided value is the given user limit.
+    UserLimitAmount(u64),
 }
 
 impl Display for Error {
@@ -118,15 +191,28 @@ impl Display for Error {
 impl StdError for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::AllowedMentionsConflict(_) => "Conflicting allowed mentions",
+            Error::BitrateAmount(_) => "Invalid voice channel bitrate",
             Error::BulkDeleteAmount => "Too few/many messages to bulk delete",
             Error::DeleteMessageDaysAmount(_) => "Invalid delete message days",
-            Error::EmbedTooLarge(_) => "Embed too large",
+            Error::EmbedTooLarge { .. } => "Embed too large",
+            Error::EmbedTimestampInvalid(_) => "Invalid embed timestamp",
+            Error::GroupFull => "The group has too many recipients",
             Error::GuildNotFound => "Guild not found in the cache",
+            Error::InvalidChannelType(_) => "Invalid channel type",
             Error::InvalidPermissions(_) => "Invalid permissions",
+            Error::InvalidUrl(_) => "Invalid URL scheme",
             Error::InvalidUser => "The current user can not perform the action",
             Error::ItemMissing => "The required item is missing from the cache",
             Error::MessageTooLong(_) => "Message too large",
+            Error::MessageLimitAmount(_) => "Invalid number of messages to retrieve",
             Error::MessagingBot => "Attempted to message another bot user",
+            Error::NoCategory => "The channel is not in a category",
+            Error::NonceMismatch => "The nonce of the sent message was not echoed",
+            Error::TooManyFiles(_) => "Too many files to send with a message",
+            Error::TooManyPins => "The channel has too many pinned messages",
+            Error::TopicTooLong(_) => "Channel topic too long",
+            Error::UserLimitAmount(_) => "Invalid voice channel user limit",
         }
     }
 }
@@ -13,6 +13,8 @@ use internal::prelude::*;
 #[cfg(feature = "cache")]
 use cache::{Cache, CacheUpdate};
 #[cfg(feature = "cache")]
+use constants::MESSAGE_CACHE_LIMIT;
+#[cfg(feature = "cache")]
 use internal::RwLockExt;
 #[cfg(feature = "cache")]
 use std::mem;
@@ -127,21 +129,8 @@ impl CacheUpdate for ChannelDeleteEvent {
 
     fn update(&mut self, cache: &mut Cache) -> Option<()> {
         match self.channel {
-            Channel::Guild(ref channel) => {
-                let (guild_id, channel_id) = channel.with(|channel| (channel.guild_id, channel.id));
-
-                cache.channels.remove(&channel_id);
-
-                cache
-                    .guilds
-                    .get_mut(&guild_id)
-                    .and_then(|guild| guild.with_mut(|g| g.channels.remove(&channel_id)));
-            },
-            Channel::Category(ref category) => {
-                let channel_id = category.with(|cat| cat.id);
-
-                cache.categories.remove(&channel_id);
-            },
+            Channel::Guild(ref channel) => cache.remove_channel(channel.with(|c| c.id)),
+            Channel::Category(ref category) => cache.remove_channel(category.with(|c| c.id)),
             // We ignore these two due to the fact that the delete event for dms/groups
             // will _not_ fire anymore.
             Channel::Private(_) | Channel::Group(_) => unreachable!(),
@@ -373,10 +362,12 @@ impl CacheUpdate for GuildDeleteEvent {
     type Output = Arc<RwLock<Guild>>;
 
     fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
-        // Remove channel entries for the guild if the guild is found.
+        // Remove channel and message entries for the guild if the guild is
+        // found.
         cache.guilds.remove(&self.guild.id).map(|guild| {
             for channel_id in guild.write().unwrap().channels.keys() {
                 cache.channels.remove(channel_id);
+                cache.messages.remove(channel_id);
             }
 
             guild
@@ -712,6 +703,26 @@ pub struct MessageCreateEvent {
     pub message: Message,
 }
 
+#[cfg(feature = "cache")]
+impl CacheUpdate for MessageCreateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &mut Cache) -> Option<()> {
+        let messages = cache
+            .messages
+            .entry(self.message.channel_id)
+            .or_insert_with(Default::default);
+
+        messages.push_back(self.message.clone());
+
+        while messages.len() > MESSAGE_CACHE_LIMIT as usize {
+            messages.pop_front();
+        }
+
+        None
+    }
+}
+
 impl<'de> Deserialize<'de> for MessageCreateEvent {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
         Ok(Self {
@@ -726,12 +737,42 @@ pub struct MessageDeleteBulkEvent {
     pub ids: Vec<MessageId>,
 }
 
+#[cfg(feature = "cache")]
+impl CacheUpdate for MessageDeleteBulkEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &mut Cache) -> Option<()> {
+        if let Some(messages) = cache.messages.get_mut(&self.channel_id) {
+            let ids = &self.ids;
+
+            messages.retain(|message| !ids.contains(&message.id));
+        }
+
+        None
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize)]
 pub struct MessageDeleteEvent {
     pub channel_id: ChannelId,
     #[serde(rename = "id")] pub message_id: MessageId,
 }
 
+#[cfg(feature = "cache")]
+impl CacheUpdate for MessageDeleteEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &mut Cache) -> Option<()> {
+        let message_id = self.message_id;
+
+        if let Some(messages) = cache.messages.get_mut(&self.channel_id) {
+            messages.retain(|message| message.id != message_id);
+        }
+
+        None
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageUpdateEvent {
     pub id: MessageId,
@@ -751,6 +792,58 @@ pub struct MessageUpdateEvent {
     pub embeds: Option<Vec<Value>>,
 }
 
+#[cfg(feature = "cache")]
+impl CacheUpdate for MessageUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &mut Cache) -> Option<()> {
+        let message = cache
+            .messages
+            .get_mut(&self.channel_id)
+            .and_then(|messages| messages.iter_mut().find(|message| message.id == self.id));
+
+        // Only the fields present in the event were changed.
+        if let Some(message) = message {
+            if let Some(ref content) = self.content {
+                message.content.clone_from(content);
+            }
+
+            if let Some(pinned) = self.pinned {
+                message.pinned = pinned;
+            }
+
+            if let Some(edited_timestamp) = self.edited_timestamp {
+                message.edited_timestamp = Some(edited_timestamp);
+            }
+
+            if let Some(mention_everyone) = self.mention_everyone {
+                message.mention_everyone = mention_everyone;
+            }
+
+            if let Some(ref mentions) = self.mentions {
+                message.mentions.clone_from(mentions);
+            }
+
+            if let Some(ref mention_roles) = self.mention_roles {
+                message.mention_roles.clone_from(mention_roles);
+            }
+
+            if let Some(ref attachments) = self.attachments {
+                message.attachments.clone_from(attachments);
+            }
+
+            if let Some(ref embeds) = self.embeds {
+                message.embeds = embeds
+                    .iter()
+                    .filter_map(|embed| serde_json::from_value(embed.clone()).ok())
+                    .collect();
+            }
+        }
+
+        None
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct PresenceUpdateEvent {
     pub guild_id: Option<GuildId>,
@@ -1028,6 +1121,15 @@ impl CacheUpdate for VoiceStateUpdateEvent {
             return None;
         }
 
+        // Voice states outside of guilds are of calls, such as in groups.
+        if self.voice_state.channel_id.is_some() {
+            cache
+                .voice_states
+                .insert(self.voice_state.user_id, self.voice_state.clone());
+        } else {
+            cache.voice_states.remove(&self.voice_state.user_id);
+        }
+
         None
     }
 }
@@ -38,6 +38,7 @@ use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::sync::{Arc, RwLock};
 use internal::prelude::*;
+use constants;
 
 #[cfg(feature = "utils")]
 use utils::Colour;
@@ -53,11 +54,20 @@ macro_rules! id_u64 {
             pub struct $name(pub u64);
 
             impl $name {
-                /// Retrieves the time that the Id was created at.
+                /// Retrieves the time that the Id was created at, in UTC, to
+                /// millisecond precision.
+                ///
+                /// The timestamp is stored within the Id itself, as the
+                /// number of milliseconds since the [Discord epoch].
+                ///
+                /// [Discord epoch]: ../constants/constant.DISCORD_EPOCH.html
                 pub fn created_at(&self) -> NaiveDateTime {
-                    let offset = (self.0 >> 22) / 1000;
+                    let millis = (self.0 >> 22) + constants::DISCORD_EPOCH;
 
-                    NaiveDateTime::from_timestamp(1_420_070_400 + offset as i64, 0)
+                    NaiveDateTime::from_timestamp(
+                        (millis / 1000) as i64,
+                        (millis % 1000) as u32 * 1_000_000,
+                    )
                 }
             }
 
@@ -74,6 +84,12 @@ macro_rules! id_u64 {
                 }
             }
 
+            impl<'a> From<&'a $name> for $name {
+                fn from(id: &'a $name) -> $name {
+                    *id
+                }
+            }
+
             impl PartialEq for $name {
                 fn eq(&self, other: &Self) -> bool {
                     self.0 == other.0
@@ -1,13 +1,21 @@
 // Disable this lint to avoid it wanting to change `0xABCDEF` to `0xAB_CDEF`.
 #![allow(unreadable_literal)]
 
+use std::error::Error as StdError;
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
 macro_rules! colour {
-    ($(#[$attr:meta] $name:ident, $val:expr;)*) => {
+    ($(#[$attr:meta] $name:ident, $constant:ident, $val:expr;)*) => {
         impl Colour {
             $(
+                #[$attr]
+                pub const $constant: Colour = Colour($val);
+
                 #[$attr]
                 pub fn $name() -> Colour {
-                    Colour::new($val)
+                    Colour::$constant
                 }
             )*
         }
@@ -18,9 +26,10 @@ macro_rules! colour {
 /// colour. This is particularly useful when working with a [`Role`]'s colour,
 /// as the API works with an integer value instead of an RGB value.
 ///
-/// Instances can be created by using the struct's associated functions. These
-/// produce presets equivalent to those found in the official client's colour
-/// picker.
+/// Instances can be created by using the struct's associated functions, or
+/// from an RGB tuple or an integer value. Presets equivalent to those found in
+/// the official client's colour picker are available as associated constants,
+/// such as [`Colour::BLURPLE`], and as associated functions.
 ///
 /// # Examples
 ///
@@ -72,6 +81,7 @@ macro_rules! colour {
 /// assert!(blitz_blue > fooyoo);
 /// ```
 ///
+/// [`Colour::BLURPLE`]: #associatedconstant.BLURPLE
 /// [`Role`]: ../model/struct.Role.html
 /// [`dark_teal`]: #method.dark_teal
 /// [`g`]: #method.g
@@ -132,6 +142,50 @@ impl Colour {
         Colour(uint)
     }
 
+    /// Generates a new Colour from a hex string in the form of `#RRGGBB`,
+    /// `RRGGBB`, or `0xRRGGBB`, such as one read from a configuration file.
+    ///
+    /// This is also available through `str::parse`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_hex_str("#7289DA").unwrap(), Colour::blurple());
+    /// assert_eq!("0x7289da".parse::<Colour>().unwrap(), Colour::blurple());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseColourError::InvalidLength`] if there are not exactly
+    /// 6 hex digits, or a [`ParseColourError::InvalidCharacter`] if any of
+    /// them is not a hex digit.
+    ///
+    /// [`ParseColourError::InvalidCharacter`]: enum.ParseColourError.html#variant.InvalidCharacter
+    /// [`ParseColourError::InvalidLength`]: enum.ParseColourError.html#variant.InvalidLength
+    pub fn from_hex_str(s: &str) -> StdResult<Colour, ParseColourError> {
+        let digits = if s.starts_with('#') {
+            &s[1..]
+        } else if s.starts_with("0x") || s.starts_with("0X") {
+            &s[2..]
+        } else {
+            s
+        };
+
+        if !digits.chars().all(|c| c.is_digit(16)) {
+            return Err(ParseColourError::InvalidCharacter);
+        }
+
+        if digits.len() != 6 {
+            return Err(ParseColourError::InvalidLength);
+        }
+
+        u32::from_str_radix(digits, 16)
+            .map(Colour)
+            .map_err(|_| ParseColourError::InvalidCharacter)
+    }
+
     /// Returns the red RGB component of this Colour.
     ///
     /// # Examples
@@ -235,66 +289,103 @@ impl From<(u8, u8, u8)> for Colour {
     fn from((r, g, b): (u8, u8, u8)) -> Self { Colour::from_rgb(r, g, b) }
 }
 
+impl FromStr for Colour {
+    type Err = ParseColourError;
+
+    /// Parses a hex string into a Colour.
+    ///
+    /// Refer to [`from_hex_str`] for the accepted formats.
+    ///
+    /// [`from_hex_str`]: #method.from_hex_str
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> { Colour::from_hex_str(s) }
+}
+
 colour! {
-    /// Creates a new `Colour`, setting its RGB value to `(111, 198, 226)`.
-    blitz_blue, 0x6FC6E2;
-    /// Creates a new `Colour`, setting its RGB value to `(52, 152, 219)`.
-    blue, 0x3498DB;
-    /// Creates a new `Colour`, setting its RGB value to `(114, 137, 218)`.
-    blurple, 0x7289DA;
-    /// Creates a new `Colour`, setting its RGB value to `(32, 102, 148)`.
-    dark_blue, 0x206694;
-    /// Creates a new `Colour`, setting its RGB value to `(194, 124, 14)`.
-    dark_gold, 0xC27C0E;
-    /// Creates a new `Colour`, setting its RGB value to `(31, 139, 76)`.
-    dark_green, 0x1F8B4C;
-    /// Creates a new `Colour`, setting its RGB value to `(96, 125, 139)`.
-    dark_grey, 0x607D8B;
-    /// Creates a new `Colour`, setting its RGB value to `(173, 20, 87)`.
-    dark_magenta, 0xAD1457;
-    /// Creates a new `Colour`, setting its RGB value to `(168, 67, 0)`.
-    dark_orange, 0xA84300;
-    /// Creates a new `Colour`, setting its RGB value to `(113, 54, 138)`.
-    dark_purple, 0x71368A;
-    /// Creates a new `Colour`, setting its RGB value to `(153, 45, 34)`.
-    dark_red, 0x992D22;
-    /// Creates a new `Colour`, setting its RGB value to `(17, 128, 106)`.
-    dark_teal, 0x11806A;
-    /// Creates a new `Colour`, setting its RGB value to `(84, 110, 122)`.
-    darker_grey, 0x546E7A;
-    /// Creates a new `Colour`, setting its RGB value to `(250, 177, 237)`.
-    fabled_pink, 0xFAB1ED;
-    /// Creates a new `Colour`, setting its RGB value to `(136, 130, 196)`.`
-    faded_purple, 0x8882C4;
-    /// Creates a new `Colour`, setting its RGB value to `(17, 202, 128)`.
-    fooyoo, 0x11CA80;
-    /// Creates a new `Colour`, setting its RGB value to `(241, 196, 15)`.
-    gold, 0xF1C40F;
-    /// Creates a new `Colour`, setting its RGB value to `(186, 218, 85)`.
-    kerbal, 0xBADA55;
-    /// Creates a new `Colour`, setting its RGB value to `(151, 156, 159)`.
-    light_grey, 0x979C9F;
-    /// Creates a new `Colour`, setting its RGB value to `(149, 165, 166)`.
-    lighter_grey, 0x95A5A6;
-    /// Creates a new `Colour`, setting its RGB value to `(233, 30, 99)`.
-    magenta, 0xE91E63;
-    /// Creates a new `Colour`, setting its RGB value to `(230, 131, 151)`.
-    meibe_pink, 0xE68397;
-    /// Creates a new `Colour`, setting its RGB value to `(230, 126, 34)`.
-    orange, 0xE67E22;
-    /// Creates a new `Colour`, setting its RGB value to `(155, 89, 182)`.
-    purple, 0x9B59B6;
-    /// Creates a new `Colour`, setting its RGB value to `(231, 76, 60)`.
-    red, 0xE74C3C;
-    /// Creates a new `Colour`, setting its RGB value to `(117, 150, 255)`.
-    rohrkatze_blue, 0x7596FF;
-    /// Creates a new `Colour`, setting its RGB value to `(246, 219, 216)`.
-    rosewater, 0xF6DBD8;
-    /// Creates a new `Colour`, setting its RGB value to `(26, 188, 156)`.
-    teal, 0x1ABC9C;
+    /// A preset `Colour` with the RGB value of `(111, 198, 226)`.
+    blitz_blue, BLITZ_BLUE, 0x6FC6E2;
+    /// A preset `Colour` with the RGB value of `(52, 152, 219)`.
+    blue, BLUE, 0x3498DB;
+    /// A preset `Colour` with the RGB value of `(114, 137, 218)`.
+    blurple, BLURPLE, 0x7289DA;
+    /// A preset `Colour` with the RGB value of `(32, 102, 148)`.
+    dark_blue, DARK_BLUE, 0x206694;
+    /// A preset `Colour` with the RGB value of `(194, 124, 14)`.
+    dark_gold, DARK_GOLD, 0xC27C0E;
+    /// A preset `Colour` with the RGB value of `(31, 139, 76)`.
+    dark_green, DARK_GREEN, 0x1F8B4C;
+    /// A preset `Colour` with the RGB value of `(96, 125, 139)`.
+    dark_grey, DARK_GREY, 0x607D8B;
+    /// A preset `Colour` with the RGB value of `(173, 20, 87)`.
+    dark_magenta, DARK_MAGENTA, 0xAD1457;
+    /// A preset `Colour` with the RGB value of `(168, 67, 0)`.
+    dark_orange, DARK_ORANGE, 0xA84300;
+    /// A preset `Colour` with the RGB value of `(113, 54, 138)`.
+    dark_purple, DARK_PURPLE, 0x71368A;
+    /// A preset `Colour` with the RGB value of `(153, 45, 34)`.
+    dark_red, DARK_RED, 0x992D22;
+    /// A preset `Colour` with the RGB value of `(17, 128, 106)`.
+    dark_teal, DARK_TEAL, 0x11806A;
+    /// A preset `Colour` with the RGB value of `(84, 110, 122)`.
+    darker_grey, DARKER_GREY, 0x546E7A;
+    /// A preset `Colour` with the RGB value of `(250, 177, 237)`.
+    fabled_pink, FABLED_PINK, 0xFAB1ED;
+    /// A preset `Colour` with the RGB value of `(136, 130, 196)`.
+    faded_purple, FADED_PURPLE, 0x8882C4;
+    /// A preset `Colour` with the RGB value of `(17, 202, 128)`.
+    fooyoo, FOOYOO, 0x11CA80;
+    /// A preset `Colour` with the RGB value of `(241, 196, 15)`.
+    gold, GOLD, 0xF1C40F;
+    /// A preset `Colour` with the RGB value of `(186, 218, 85)`.
+    kerbal, KERBAL, 0xBADA55;
+    /// A preset `Colour` with the RGB value of `(151, 156, 159)`.
+    light_grey, LIGHT_GREY, 0x979C9F;
+    /// A preset `Colour` with the RGB value of `(149, 165, 166)`.
+    lighter_grey, LIGHTER_GREY, 0x95A5A6;
+    /// A preset `Colour` with the RGB value of `(233, 30, 99)`.
+    magenta, MAGENTA, 0xE91E63;
+    /// A preset `Colour` with the RGB value of `(230, 131, 151)`.
+    meibe_pink, MEIBE_PINK, 0xE68397;
+    /// A preset `Colour` with the RGB value of `(230, 126, 34)`.
+    orange, ORANGE, 0xE67E22;
+    /// A preset `Colour` with the RGB value of `(155, 89, 182)`.
+    purple, PURPLE, 0x9B59B6;
+    /// A preset `Colour` with the RGB value of `(231, 76, 60)`.
+    red, RED, 0xE74C3C;
+    /// A preset `Colour` with the RGB value of `(117, 150, 255)`.
+    rohrkatze_blue, ROHRKATZE_BLUE, 0x7596FF;
+    /// A preset `Colour` with the RGB value of `(246, 219, 216)`.
+    rosewater, ROSEWATER, 0xF6DBD8;
+    /// A preset `Colour` with the RGB value of `(26, 188, 156)`.
+    teal, TEAL, 0x1ABC9C;
 }
 
 impl Default for Colour {
     /// Creates a default value for a `Colour`, setting the inner value to `0`.
     fn default() -> Colour { Colour(0) }
 }
+
+/// An error returned when a string could not be parsed into a [`Colour`].
+///
+/// [`Colour`]: struct.Colour.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseColourError {
+    /// A character other than a hex digit was given.
+    InvalidCharacter,
+    /// A number of hex digits other than 6 was given.
+    InvalidLength,
+}
+
+impl fmt::Display for ParseColourError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.description()) }
+}
+
+impl StdError for ParseColourError {
+    fn description(&self) -> &str {
+        use self::ParseColourError::*;
+
+        match *self {
+            InvalidCharacter => "invalid hex digit in colour",
+            InvalidLength => "colour must have 6 hex digits",
+        }
+    }
+}
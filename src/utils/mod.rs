@@ -5,7 +5,7 @@ mod colour;
 
 mod message_builder;
 
-pub use self::colour::Colour;
+pub use self::colour::{Colour, ParseColourError};
 pub use self::message_builder::{Content, ContentModifier, MessageBuilder};
 
 // Note: Here for BC purposes.
@@ -0,0 +1,37 @@
+#![cfg(feature = "model")]
+
+#[macro_use]
+extern crate serde_json;
+extern crate serenity;
+
+use serde_json::Value;
+use serenity::model::Attachment;
+
+fn attachment(filename: &str, width: Value, height: Value) -> Attachment {
+    serde_json::from_value(json!({
+        "id": "1",
+        "filename": filename,
+        "height": height,
+        "proxy_url": "https://media.discordapp.net/a",
+        "size": 1,
+        "url": "https://cdn.discordapp.com/a",
+        "width": width,
+    })).unwrap()
+}
+
+#[test]
+fn image_detection() {
+    let image = attachment("cat.png", json!(2), json!(3));
+    assert!(image.is_image());
+    assert_eq!(image.content_type, None);
+
+    let file = attachment("notes.txt", Value::Null, Value::Null);
+    assert!(!file.is_image());
+}
+
+#[test]
+fn extension() {
+    assert_eq!(attachment("cat.tar.gz", Value::Null, Value::Null).extension(), Some("gz"));
+    assert_eq!(attachment("README", Value::Null, Value::Null).extension(), None);
+    assert_eq!(attachment(".gitignore", Value::Null, Value::Null).extension(), None);
+}
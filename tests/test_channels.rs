@@ -1,3 +1,6 @@
+extern crate serde;
+#[macro_use]
+extern crate serde_json;
 extern crate serenity;
 
 #[cfg(feature = "utils")]
@@ -30,6 +33,7 @@ mod utils {
             name: "nsfw-stuff".to_string(),
             permission_overwrites: vec![],
             position: 0,
+            rate_limit_per_user: None,
             topic: None,
             user_limit: None,
             nsfw: false,
@@ -42,13 +46,17 @@ mod utils {
             last_message_id: None,
             last_pin_timestamp: None,
             kind: ChannelType::Private,
-            recipient: Arc::new(RwLock::new(User {
-                id: UserId(2),
-                avatar: None,
-                bot: false,
-                discriminator: 1,
-                name: "ab".to_string(),
-            })),
+            recipient: Arc::new(RwLock::new(user(2, "ab"))),
+        }
+    }
+
+    fn user(id: u64, name: &str) -> User {
+        User {
+            id: UserId(id),
+            avatar: None,
+            bot: false,
+            discriminator: 1,
+            name: name.to_string(),
         }
     }
 
@@ -84,4 +92,694 @@ mod utils {
         let private_channel = private_channel();
         assert!(!private_channel.is_nsfw());
     }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn news_channels() {
+        let mut channel = guild_channel();
+        assert!(!channel.is_news());
+
+        channel.kind = ChannelType::News;
+        assert!(channel.is_news());
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn group_owner() {
+        let mut group = group();
+        assert!(group.owner().is_none());
+
+        let owner = Arc::new(RwLock::new(user(2, "ab")));
+        group.recipients.insert(UserId(2), Arc::clone(&owner));
+
+        assert!(Arc::ptr_eq(&group.owner().unwrap(), &owner));
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn group_full() {
+        use serenity::Error;
+
+        let mut group = group();
+        assert_eq!(group.recipient_count(), 1);
+
+        for id in 10..19 {
+            group.recipients.insert(UserId(id), Arc::new(RwLock::new(user(id, "a"))));
+        }
+
+        assert_eq!(group.recipient_count(), 10);
+        assert!(group.add_recipient(UserId(10)).is_ok());
+
+        match group.add_recipient(UserId(20)) {
+            Err(Error::Model(ModelError::GroupFull)) => {},
+            other => panic!("Expected the group to be full: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn group_name_sorted() {
+        let mut group = group();
+        assert_eq!(group.name(), "Empty Group");
+
+        for &(id, name) in &[(30, "c"), (10, "a"), (20, "b")] {
+            group.recipients.insert(UserId(id), Arc::new(RwLock::new(user(id, name))));
+        }
+
+        let ids = group
+            .recipients_sorted()
+            .iter()
+            .map(|recipient| recipient.read().unwrap().id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec![UserId(10), UserId(20), UserId(30)]);
+        assert_eq!(group.name(), "a, b, c");
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn group_call_participants() {
+        use serenity::CACHE;
+
+        let mut group = group();
+        group.channel_id = ChannelId(381880193251409931);
+        assert!(!group.has_active_call());
+
+        {
+            let mut cache = CACHE.write().unwrap();
+
+            let states = [(30, Some(group.channel_id)), (10, Some(group.channel_id)), (20, None)];
+
+            for &(user_id, channel_id) in &states {
+                cache.voice_states.insert(UserId(user_id), VoiceState {
+                    channel_id: channel_id,
+                    deaf: false,
+                    mute: false,
+                    self_deaf: false,
+                    self_mute: false,
+                    session_id: String::new(),
+                    suppress: false,
+                    token: None,
+                    user_id: UserId(user_id),
+                });
+            }
+        }
+
+        assert!(group.has_active_call());
+        assert_eq!(group.call_participants(), vec![UserId(10), UserId(30)]);
+    }
+
+    #[cfg(all(feature = "cache", feature = "model"))]
+    #[test]
+    fn can_delete_without_guild() {
+        use serenity::CACHE;
+
+        let channel = Channel::Private(Arc::new(RwLock::new(private_channel())));
+        assert!(channel.can_delete().unwrap());
+
+        let mut group = group();
+        let current_user_id = CACHE.read().unwrap().user.id;
+        group.owner_id = UserId(current_user_id.0 + 1);
+        let channel = Channel::Group(Arc::new(RwLock::new(group.clone())));
+        assert!(!channel.can_delete().unwrap());
+
+        group.owner_id = current_user_id;
+        let channel = Channel::Group(Arc::new(RwLock::new(group)));
+        assert!(channel.can_delete().unwrap());
+    }
+
+    #[cfg(all(feature = "cache", feature = "model"))]
+    #[test]
+    fn recent_messages_from_cache() {
+        use serde_json::{self, Value};
+        use serenity::CACHE;
+        use std::fs::File;
+
+        let mut channel = guild_channel();
+        channel.id = ChannelId(381880193700069377);
+        assert!(channel.recent_messages(5).is_empty());
+
+        let f = File::open("./tests/resources/message_footer_1.json").unwrap();
+        let v = serde_json::from_reader::<File, Value>(f).unwrap();
+
+        {
+            let mut cache = CACHE.write().unwrap();
+            let messages = cache.messages.entry(channel.id).or_insert_with(Default::default);
+
+            for id in 1..4 {
+                let mut message = serde_json::from_value::<Message>(v.clone()).unwrap();
+                message.id = MessageId(id);
+                message.channel_id = channel.id;
+                messages.push_back(message);
+            }
+        }
+
+        let ids = |messages: Vec<Message>| messages.iter().map(|m| m.id).collect::<Vec<_>>();
+
+        assert_eq!(ids(channel.recent_messages(2)), vec![MessageId(2), MessageId(3)]);
+        assert_eq!(ids(channel.recent_messages(10)).len(), 3);
+        assert!(channel.recent_messages(0).is_empty());
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn cache_remove_channel() {
+        use serenity::cache::Cache;
+        use std::collections::VecDeque;
+
+        let mut cache = Cache::default();
+
+        let channel = guild_channel();
+        cache.channels.insert(channel.id, Arc::new(RwLock::new(channel)));
+        cache.messages.insert(ChannelId(1), VecDeque::new());
+
+        let mut private_channel = private_channel();
+        private_channel.id = ChannelId(3);
+        cache.private_channels.insert(ChannelId(3), Arc::new(RwLock::new(private_channel)));
+
+        cache.remove_channel(ChannelId(1));
+        assert!(cache.guild_channel(ChannelId(1)).is_none());
+        assert!(cache.channel(ChannelId(1)).is_none());
+        assert!(!cache.messages.contains_key(&ChannelId(1)));
+        assert!(cache.private_channel(ChannelId(3)).is_some());
+
+        cache.remove_channel(ChannelId(3));
+        assert!(cache.private_channel(ChannelId(3)).is_none());
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn private_channel_recipient() {
+        let channel = private_channel();
+
+        assert_eq!(channel.recipient_id(), UserId(2));
+        assert!(channel.is_recipient(2));
+        assert!(!channel.is_recipient(UserId(3)));
+    }
+
+    #[test]
+    fn channel_eq() {
+        let inner = Arc::new(RwLock::new(guild_channel()));
+        let channel = Channel::Guild(Arc::clone(&inner));
+        let other = Channel::Guild(Arc::clone(&inner));
+        assert_eq!(channel, other);
+
+        let copy = Channel::Guild(Arc::new(RwLock::new(guild_channel())));
+        assert_eq!(channel, copy);
+
+        let group = Channel::Group(Arc::new(RwLock::new(group())));
+        assert_ne!(channel, group);
+    }
+
+    #[test]
+    fn channel_variant_accessors() {
+        let inner = Arc::new(RwLock::new(guild_channel()));
+        let channel = Channel::Guild(Arc::clone(&inner));
+        assert!(Arc::ptr_eq(&channel.guild().unwrap(), &inner));
+        assert!(channel.group().is_none());
+        assert!(channel.private().is_none());
+
+        let inner = Arc::new(RwLock::new(group()));
+        let channel = Channel::Group(Arc::clone(&inner));
+        assert!(Arc::ptr_eq(&channel.group().unwrap(), &inner));
+        assert!(channel.guild().is_none());
+
+        let inner = Arc::new(RwLock::new(private_channel()));
+        let channel = Channel::Private(Arc::clone(&inner));
+        assert!(Arc::ptr_eq(&channel.private().unwrap(), &inner));
+        assert!(channel.guild().is_none());
+    }
+
+    #[test]
+    fn channel_kind() {
+        let mut channel = guild_channel();
+        channel.kind = ChannelType::Voice;
+        let channel = Channel::Guild(Arc::new(RwLock::new(channel)));
+        assert_eq!(channel.kind(), ChannelType::Voice);
+
+        let group = Channel::Group(Arc::new(RwLock::new(group())));
+        assert_eq!(group.kind(), ChannelType::Group);
+
+        let private_channel = Channel::Private(Arc::new(RwLock::new(private_channel())));
+        assert_eq!(private_channel.kind(), ChannelType::Private);
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn channel_kind_predicates() {
+        let mut channel = guild_channel();
+        channel.kind = ChannelType::News;
+        let channel = Channel::Guild(Arc::new(RwLock::new(channel)));
+        assert!(channel.is_text());
+        assert!(!channel.is_voice());
+        assert!(!channel.is_category());
+
+        let group = Channel::Group(Arc::new(RwLock::new(group())));
+        assert!(group.is_group());
+        assert!(!group.is_private());
+        assert!(!group.is_text());
+
+        let private_channel = Channel::Private(Arc::new(RwLock::new(private_channel())));
+        assert!(private_channel.is_private());
+        assert!(!private_channel.is_group());
+    }
+
+    #[test]
+    fn channel_id_conversions() {
+        let mut channel = guild_channel();
+        channel.id = ChannelId(3);
+        assert_eq!(ChannelId::from(&channel), ChannelId(3));
+
+        let group = group();
+        assert_eq!(ChannelId::from(&group), ChannelId(1));
+        assert_eq!(ChannelId::from(&private_channel()), private_channel().id);
+    }
+
+    #[test]
+    fn channel_guild_id() {
+        let channel = Channel::Guild(Arc::new(RwLock::new(guild_channel())));
+        assert_eq!(channel.guild_id(), Some(GuildId(2)));
+
+        let group = Channel::Group(Arc::new(RwLock::new(group())));
+        assert_eq!(group.guild_id(), None);
+
+        let private_channel = Channel::Private(Arc::new(RwLock::new(private_channel())));
+        assert_eq!(private_channel.guild_id(), None);
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn channel_name() {
+        let channel = Channel::Guild(Arc::new(RwLock::new(guild_channel())));
+        assert_eq!(channel.name(), "nsfw-stuff");
+
+        let group = Channel::Group(Arc::new(RwLock::new(group())));
+        assert_eq!(group.name(), "Empty Group");
+
+        let private_channel = Channel::Private(Arc::new(RwLock::new(private_channel())));
+        assert_eq!(private_channel.name(), "DM with ab#0001");
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn slowmode() {
+        let mut channel = guild_channel();
+        assert_eq!(channel.slowmode(), None);
+
+        channel.rate_limit_per_user = Some(0);
+        assert_eq!(channel.slowmode(), None);
+
+        channel.rate_limit_per_user = Some(10);
+        assert_eq!(channel.slowmode(), Some(10));
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn voice_setting_limits() {
+        use serenity::Error;
+
+        let mut channel = guild_channel();
+
+        match channel.set_bitrate(64000) {
+            Err(Error::Model(ModelError::InvalidChannelType(ChannelType::Text))) => {},
+            other => panic!("Expected an invalid channel type: {:?}", other),
+        }
+
+        channel.kind = ChannelType::Voice;
+
+        for &bitrate in &[0, 7999, 96001] {
+            match channel.set_bitrate(bitrate) {
+                Err(Error::Model(ModelError::BitrateAmount(amount))) => {
+                    assert_eq!(amount, bitrate);
+                },
+                other => panic!("Expected an invalid bitrate: {:?}", other),
+            }
+        }
+
+        match channel.set_user_limit(100) {
+            Err(Error::Model(ModelError::UserLimitAmount(100))) => {},
+            other => panic!("Expected an invalid user limit: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn follow_requires_news_channel() {
+        use serenity::Error;
+
+        let channel = guild_channel();
+
+        match channel.follow(ChannelId(5)) {
+            Err(Error::Model(ModelError::InvalidChannelType(ChannelType::Text))) => {},
+            other => panic!("Expected an invalid channel type: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn sync_permissions_without_category() {
+        use serenity::Error;
+
+        let mut channel = guild_channel();
+
+        match channel.sync_permissions() {
+            Err(Error::Model(ModelError::NoCategory)) => {},
+            other => panic!("Expected a missing category: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn topic() {
+        use serenity::Error;
+
+        let mut channel = guild_channel();
+        assert_eq!(channel.topic(), None);
+
+        channel.topic = Some(String::new());
+        assert_eq!(channel.topic(), None);
+
+        channel.topic = Some("rules".to_string());
+        assert_eq!(channel.topic(), Some("rules"));
+
+        match channel.set_topic("a".repeat(1025)) {
+            Err(Error::Model(ModelError::TopicTooLong(1))) => {},
+            other => panic!("Expected the topic to be too long: {:?}", other),
+        }
+        assert_eq!(channel.topic(), Some("rules"));
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn position_updates() {
+        let channels = [(1, 0), (2, 4), (3, 9)]
+            .iter()
+            .map(|&(id, position)| {
+                let mut channel = guild_channel();
+                channel.id = ChannelId(id);
+                channel.position = position;
+
+                channel
+            })
+            .collect::<Vec<_>>();
+
+        // Moving the last channel up to the top.
+        assert_eq!(
+            channels[2].position_updates(&channels, 0),
+            vec![(ChannelId(3), 0), (ChannelId(1), 1), (ChannelId(2), 2)]
+        );
+
+        // Moving the first channel down past the end.
+        assert_eq!(
+            channels[0].position_updates(&channels, 10),
+            vec![(ChannelId(2), 0), (ChannelId(3), 1), (ChannelId(1), 2)]
+        );
+
+        // Channels of other kinds are not siblings.
+        let mut voice = guild_channel();
+        voice.id = ChannelId(4);
+        voice.kind = ChannelType::Voice;
+        voice.position = 0;
+        let mut with_voice = channels.clone();
+        with_voice.push(voice);
+
+        assert_eq!(
+            channels[1].position_updates(&with_voice, 1),
+            vec![(ChannelId(2), 1), (ChannelId(3), 2)]
+        );
+    }
+}
+
+#[test]
+fn channel_type_from_name() {
+    use serenity::model::ChannelType;
+
+    let kinds = [
+        ChannelType::Text,
+        ChannelType::Private,
+        ChannelType::Voice,
+        ChannelType::Group,
+        ChannelType::Category,
+        ChannelType::News,
+    ];
+
+    for kind in &kinds {
+        assert_eq!(kind.name().parse::<ChannelType>(), Ok(*kind));
+    }
+
+    assert_eq!(ChannelType::from_name("Text"), None);
+    assert!("unknown".parse::<ChannelType>().is_err());
+}
+
+#[test]
+fn channel_type_groups() {
+    use serenity::model::ChannelType;
+
+    assert!(ChannelType::Text.is_guild());
+    assert!(ChannelType::News.is_guild());
+    assert!(ChannelType::Category.is_guild());
+    assert!(!ChannelType::Private.is_guild());
+    assert!(ChannelType::Group.is_dm());
+    assert!(!ChannelType::Voice.is_dm());
+
+    assert!(ChannelType::Private.supports_messages());
+    assert!(ChannelType::News.supports_messages());
+    assert!(!ChannelType::Voice.supports_messages());
+    assert!(!ChannelType::Category.supports_messages());
+}
+
+#[test]
+fn permission_overwrite_round_trip() {
+    use serde::de::Deserialize;
+    use serenity::model::{PermissionOverwrite, PermissionOverwriteType, UserId};
+
+    let value = json!({
+        "allow": 1024,
+        "deny": 2048,
+        "id": "7",
+        "type": "member",
+    });
+
+    let overwrite = PermissionOverwrite::deserialize(value.clone()).unwrap();
+    assert_eq!(overwrite.kind, PermissionOverwriteType::Member(UserId(7)));
+    assert_eq!(serde_json::to_value(&overwrite).unwrap(), value);
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn edit_channel_permissions() {
+    use serenity::builder::EditChannel;
+    use serenity::model::{PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
+
+    let overwrite = PermissionOverwrite {
+        allow: Permissions::SEND_MESSAGES,
+        deny: Permissions::empty(),
+        kind: PermissionOverwriteType::Role(RoleId(7)),
+    };
+
+    let builder = EditChannel::default().permissions(vec![overwrite]);
+
+    assert_eq!(builder.0["permission_overwrites"], json!([{
+        "allow": Permissions::SEND_MESSAGES.bits(),
+        "deny": 0,
+        "id": "7",
+        "type": "role"
+    }]));
+
+    let builder = builder.permissions(vec![]);
+    assert_eq!(builder.0["permission_overwrites"], json!([]));
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn edit_channel_category() {
+    use serenity::builder::EditChannel;
+    use serenity::model::ChannelId;
+
+    let builder = EditChannel::default().category(ChannelId(7));
+    assert_eq!(builder.0["parent_id"], json!("7"));
+
+    let builder = builder.category(None);
+    assert_eq!(builder.0["parent_id"], json!(null));
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn edit_channel_merges_fields() {
+    use serenity::builder::EditChannel;
+    use serenity::model::ChannelId;
+
+    let builder = EditChannel::default()
+        .topic("a topic")
+        .nsfw(true)
+        .slowmode(30)
+        .category(ChannelId(7));
+
+    let mut keys = builder.0.keys().cloned().collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, vec!["nsfw", "parent_id", "rate_limit_per_user", "topic"]);
+    assert_eq!(builder.0["topic"], json!("a topic"));
+    assert_eq!(builder.0["nsfw"], json!(true));
+    assert_eq!(builder.0["rate_limit_per_user"], json!(30));
+
+    let builder = EditChannel::default()
+        .bitrate(64_000)
+        .user_limit(10)
+        .user_limit(5);
+
+    assert_eq!(builder.0.len(), 2);
+    assert_eq!(builder.0["bitrate"], json!(64_000));
+    assert_eq!(builder.0["user_limit"], json!(5));
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn edit_channel_slowmode_capped() {
+    use serenity::builder::EditChannel;
+
+    let builder = EditChannel::default().slowmode(100_000);
+    assert_eq!(builder.0["rate_limit_per_user"], json!(21_600));
+}
+
+#[cfg(feature = "builder")]
+#[test]
+fn create_channel_fields() {
+    use serenity::builder::CreateChannel;
+
+    let builder = CreateChannel::default()
+        .topic("Support ticket")
+        .slowmode(100_000)
+        .position(3);
+
+    let mut keys = builder.0.keys().cloned().collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, vec!["position", "rate_limit_per_user", "topic"]);
+    assert_eq!(builder.0["rate_limit_per_user"], json!(21_600));
+    assert_eq!(builder.0["position"], json!(3));
+}
+
+#[test]
+fn followed_channel_deser() {
+    use serenity::model::{ChannelId, FollowedChannel, WebhookId};
+
+    let followed = serde_json::from_value::<FollowedChannel>(json!({
+        "channel_id": "1",
+        "webhook_id": "2",
+    })).unwrap();
+
+    assert_eq!(followed.channel_id, ChannelId(1));
+    assert_eq!(followed.webhook_id, WebhookId(2));
+}
+
+#[test]
+fn permission_overwrite_merging() {
+    use serenity::model::{PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
+
+    let mut overwrite = PermissionOverwrite {
+        allow: Permissions::empty(),
+        deny: Permissions::ADD_REACTIONS,
+        kind: PermissionOverwriteType::Role(RoleId(7)),
+    };
+
+    overwrite.grant(Permissions::SEND_MESSAGES);
+    assert_eq!(overwrite.allow, Permissions::SEND_MESSAGES);
+    assert_eq!(overwrite.deny, Permissions::ADD_REACTIONS);
+
+    overwrite.forbid(Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES);
+    assert_eq!(overwrite.allow, Permissions::empty());
+    assert_eq!(
+        overwrite.deny,
+        Permissions::ADD_REACTIONS | Permissions::ATTACH_FILES | Permissions::SEND_MESSAGES
+    );
+}
+
+#[test]
+fn permission_overwrite_builder() {
+    use serenity::model::{PermissionOverwrite, PermissionOverwriteType, Permissions, UserId};
+
+    let kind = PermissionOverwriteType::Member(UserId(7));
+
+    let built = PermissionOverwrite::builder(kind)
+        .allow(Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES)
+        .deny(Permissions::ADD_REACTIONS | Permissions::ATTACH_FILES)
+        .build();
+    let literal = PermissionOverwrite {
+        allow: Permissions::SEND_MESSAGES,
+        deny: Permissions::ADD_REACTIONS | Permissions::ATTACH_FILES,
+        kind: kind,
+    };
+    assert_eq!(built, literal);
+
+    let allowed = PermissionOverwrite::allow_only(kind, Permissions::SEND_MESSAGES);
+    assert_eq!(allowed, PermissionOverwrite {
+        allow: Permissions::SEND_MESSAGES,
+        deny: Permissions::empty(),
+        kind: kind,
+    });
+
+    let denied = PermissionOverwrite::deny_only(kind, Permissions::SEND_MESSAGES);
+    assert_eq!(denied, PermissionOverwrite {
+        allow: Permissions::empty(),
+        deny: Permissions::SEND_MESSAGES,
+        kind: kind,
+    });
+}
+
+#[test]
+fn permission_overwrite_type_accessors() {
+    use serenity::model::{PermissionOverwriteType, RoleId, UserId};
+
+    let member = PermissionOverwriteType::Member(UserId(7));
+    assert_eq!(member.id(), 7);
+    assert_eq!(member.kind_str(), "member");
+
+    let role = PermissionOverwriteType::Role(RoleId(8));
+    assert_eq!(role.id(), 8);
+    assert_eq!(role.kind_str(), "role");
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn edit_message_too_long() {
+    use serenity::Error;
+    use serenity::model::{ChannelId, MessageId, ModelError};
+
+    let content = "a".repeat(2001);
+
+    match ChannelId(7).edit_message(MessageId(8), |m| m.content(&content)) {
+        Err(Error::Model(ModelError::MessageTooLong(1))) => {},
+        other => panic!("Expected the message to be too long: {:?}", other),
+    }
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn send_message_counts_code_points() {
+    use serenity::Error;
+    use serenity::model::{ChannelId, Message, ModelError};
+
+    // Each emoji is a single code point, but four bytes long.
+    let content = "\u{1F600}".repeat(2000);
+    assert_eq!(Message::overflow_length(&content), None);
+
+    let content = "\u{1F600}".repeat(2001);
+    assert_eq!(Message::overflow_length(&content), Some(1));
+
+    match ChannelId(7).send_message(|m| m.content(&content)) {
+        Err(Error::Model(ModelError::MessageTooLong(1))) => {},
+        other => panic!("Expected the message to be too long: {:?}", other),
+    }
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn delete_messages_partitioned_skips_old() {
+    use serenity::model::{ChannelId, MessageId};
+
+    // Ids from 2015 are well over 2 weeks old, so nothing is sent.
+    let ids = [MessageId(1), MessageId(2)];
+
+    let (deleted, skipped) = ChannelId(1).delete_messages_partitioned(&ids).unwrap();
+    assert_eq!(deleted, 0);
+    assert_eq!(skipped, ids.to_vec());
 }
@@ -51,3 +51,29 @@ fn from() {
     assert_eq!(Colour::from(7u32).0, 7);
     assert_eq!(Colour::from(7u64).0, 7);
 }
+
+#[test]
+fn constants() {
+    assert_eq!(Colour::BLURPLE, Colour::blurple());
+    assert_eq!(Colour::DARK_GREEN.0, 0x1F8B4C);
+    assert_eq!(Colour::from((255, 0, 0)).0, 0xFF0000);
+}
+
+#[test]
+fn from_hex_str() {
+    assert_eq!(Colour::from_hex_str("#7289DA"), Ok(Colour::new(0x7289DA)));
+    assert_eq!(Colour::from_hex_str("7289da"), Ok(Colour::new(0x7289DA)));
+    assert_eq!(Colour::from_hex_str("0x7289DA"), Ok(Colour::new(0x7289DA)));
+    assert_eq!("#000000".parse::<Colour>(), Ok(Colour::new(0)));
+}
+
+#[test]
+fn from_hex_str_invalid() {
+    use serenity::utils::ParseColourError;
+
+    assert_eq!(Colour::from_hex_str("#7289D"), Err(ParseColourError::InvalidLength));
+    assert_eq!(Colour::from_hex_str(""), Err(ParseColourError::InvalidLength));
+    assert_eq!(Colour::from_hex_str("#7289DAA"), Err(ParseColourError::InvalidLength));
+    assert_eq!(Colour::from_hex_str("#7289DG"), Err(ParseColourError::InvalidCharacter));
+    assert_eq!(Colour::from_hex_str("+72_89D"), Err(ParseColourError::InvalidCharacter));
+}
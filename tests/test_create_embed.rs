@@ -1,13 +1,16 @@
 #![cfg_attr(feature = "cargo-clippy", allow(unreadable_literal))]
 #![cfg(feature = "utils")]
 
+extern crate chrono;
 #[macro_use]
 extern crate serde_json;
 extern crate serenity;
 
+use chrono::{TimeZone, Utc};
+
 use serde_json::Value;
-use serenity::model::{Embed, EmbedField, EmbedImage};
-use serenity::utils::builder::CreateEmbed;
+use serenity::model::{Embed, EmbedField, EmbedFooter, EmbedImage, EmbedProvider};
+use serenity::utils::builder::{CreateEmbed, Timestamp};
 use serenity::utils::Colour;
 
 #[test]
@@ -44,6 +47,14 @@ fn test_from_embed() {
         video: None,
     };
 
+    assert_eq!(embed.colour(), Some(Colour::new(0xFF0011)));
+    assert_eq!(embed.field("c").map(|field| &field.value[..]), Some("z"));
+    assert!(embed.has_field("a"));
+    assert!(!embed.has_field("A"));
+    assert_eq!(embed.image_url(), Some("https://i.imgur.com/XfWpfCV.gif"));
+    assert_eq!(embed.thumbnail_url(), None);
+    assert_eq!(embed.footer_text(), None);
+
     let builder = CreateEmbed::from(embed)
         .colour(0xFF0011)
         .description("This is a hakase description")
@@ -78,3 +89,227 @@ fn test_from_embed() {
 
     assert_eq!(built, obj);
 }
+
+#[test]
+fn test_from_embed_footer_and_computed() {
+    let embed = Embed {
+        author: None,
+        colour: Colour::new(0),
+        description: None,
+        fields: vec![],
+        footer: Some(EmbedFooter {
+            icon_url: Some("https://example.com/icon.png".to_string()),
+            proxy_icon_url: Some("https://proxy.example.com/icon.png".to_string()),
+            text: "footer".to_string(),
+        }),
+        image: None,
+        kind: "link".to_string(),
+        provider: Some(EmbedProvider {
+            name: "provider".to_string(),
+            url: None,
+        }),
+        thumbnail: None,
+        timestamp: None,
+        title: None,
+        url: None,
+        video: None,
+    };
+
+    assert_eq!(embed.colour(), None);
+    assert_eq!(embed.footer_text(), Some("footer"));
+    assert_eq!(embed.image_url(), None);
+
+    let built = Value::Object(CreateEmbed::from(embed).0);
+
+    let obj = json!({
+        "color": 0,
+        "type": "rich",
+        "footer": {
+            "icon_url": "https://example.com/icon.png",
+            "text": "footer",
+        },
+    });
+
+    assert_eq!(built, obj);
+}
+
+#[test]
+fn test_fields_and_colour() {
+    let builder = CreateEmbed::default()
+        .colour(0x5865F2)
+        .field("name", "value", false)
+        .field("other", 2, true);
+
+    let obj = json!({
+        "color": 0x5865F2,
+        "type": "rich",
+        "fields": [
+            {
+                "inline": false,
+                "name": "name",
+                "value": "value",
+            },
+            {
+                "inline": true,
+                "name": "other",
+                "value": "2",
+            },
+        ],
+    });
+
+    assert_eq!(Value::Object(builder.0), obj);
+}
+
+#[test]
+fn test_colour_rgb() {
+    let builder = CreateEmbed::default().color(Colour::from_rgb(255, 0, 0));
+
+    assert_eq!(builder.0["color"], json!(0xFF0000));
+}
+
+#[test]
+fn test_timestamp_string() {
+    let builder = CreateEmbed::default().timestamp("2004-06-08T16:04:23");
+
+    assert_eq!(builder.0["timestamp"], json!("2004-06-08T16:04:23"));
+    assert!(Timestamp::from("2004-06-08T16:04:23").is_valid());
+    assert!(Timestamp::from("2004-06-08T16:04:23.123+02:00").is_valid());
+    assert!(!Timestamp::from("2004-06-08").is_valid());
+    assert!(!Timestamp::from("not a timestamp").is_valid());
+}
+
+#[test]
+fn test_timestamp_chrono() {
+    let datetime = Utc.ymd(2004, 6, 8).and_hms(16, 4, 23);
+    let builder = CreateEmbed::default().timestamp(&datetime);
+
+    assert_eq!(builder.0["timestamp"], json!("2004-06-08T16:04:23+00:00"));
+    assert!(Timestamp::from(&datetime).is_valid());
+}
+
+#[test]
+fn test_from_json() {
+    let template = json!({
+        "author": {"name": "ferris", "proxy_icon_url": "ignored"},
+        "color": 0x5865F2,
+        "fields": [{"name": "name", "value": "value"}],
+        "footer": {"text": "footer", "icon_url": "https://example.com/icon.png"},
+        "image": {"url": "https://example.com/image.png", "width": 10},
+        "provider": {"name": "ignored"},
+        "title": "title",
+        "type": "video",
+        "unknown": true,
+    });
+
+    let builder = CreateEmbed::from_json(template).unwrap();
+
+    assert_eq!(builder.to_value(), json!({
+        "author": {"name": "ferris"},
+        "color": 0x5865F2,
+        "fields": [{"inline": false, "name": "name", "value": "value"}],
+        "footer": {"text": "footer", "icon_url": "https://example.com/icon.png"},
+        "image": {"url": "https://example.com/image.png"},
+        "title": "title",
+        "type": "rich",
+    }));
+
+    assert!(CreateEmbed::from_json(json!([])).is_err());
+    assert!(CreateEmbed::from_json(json!({"fields": [{"value": "value"}]})).is_err());
+}
+
+#[test]
+fn test_total_length() {
+    use serenity::model::EmbedAuthor;
+
+    let embed = Embed {
+        author: Some(EmbedAuthor {
+            icon_url: None,
+            name: "ab".to_string(),
+            proxy_icon_url: None,
+            url: Some("https://example.com".to_string()),
+        }),
+        colour: Colour::new(0),
+        description: Some("ééé".to_string()),
+        fields: vec![
+            EmbedField {
+                inline: false,
+                name: "a".to_string(),
+                value: "b".to_string(),
+            },
+            EmbedField {
+                inline: true,
+                name: "cd".to_string(),
+                value: "efg".to_string(),
+            },
+        ],
+        footer: Some(EmbedFooter {
+            icon_url: None,
+            proxy_icon_url: None,
+            text: "footer".to_string(),
+        }),
+        image: None,
+        kind: "rich".to_string(),
+        provider: None,
+        thumbnail: None,
+        timestamp: None,
+        title: Some("hakase".to_string()),
+        url: Some("https://i.imgur.com/XfWpfCV.gif".to_string()),
+        video: None,
+    };
+
+    // 2 (author) + 3 (description) + 7 (fields) + 6 (footer) + 6 (title)
+    assert_eq!(embed.total_length(), 24);
+    assert_eq!(CreateEmbed::from(embed).len(), 24);
+    assert_eq!(CreateEmbed::default().len(), 0);
+}
+
+#[test]
+fn test_to_plain_text() {
+    use serenity::model::EmbedAuthor;
+
+    let mut embed = Embed {
+        author: Some(EmbedAuthor {
+            icon_url: None,
+            name: "zeyla".to_string(),
+            proxy_icon_url: None,
+            url: None,
+        }),
+        colour: Colour::new(0),
+        description: Some("A description".to_string()),
+        fields: vec![
+            EmbedField {
+                inline: false,
+                name: "a".to_string(),
+                value: "b".to_string(),
+            },
+            EmbedField {
+                inline: true,
+                name: "c".to_string(),
+                value: "d".to_string(),
+            },
+        ],
+        footer: Some(EmbedFooter {
+            icon_url: None,
+            proxy_icon_url: None,
+            text: "footer".to_string(),
+        }),
+        image: None,
+        kind: "rich".to_string(),
+        provider: None,
+        thumbnail: None,
+        timestamp: None,
+        title: Some("hakase".to_string()),
+        url: Some("https://i.imgur.com/XfWpfCV.gif".to_string()),
+        video: None,
+    };
+
+    assert_eq!(
+        embed.to_plain_text(),
+        "zeyla\nhakase\nA description\na: b\nc: d\nfooter"
+    );
+
+    embed.author = None;
+    embed.description = None;
+    embed.footer = None;
+    assert_eq!(embed.to_plain_text(), "hakase\na: b\nc: d");
+}
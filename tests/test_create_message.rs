@@ -0,0 +1,274 @@
+#![cfg(feature = "model")]
+
+#[macro_use]
+extern crate serde_json;
+extern crate serenity;
+
+use serde_json::Value;
+use serenity::builder::{CreateAllowedMentions, CreateEmbed, CreateMessage};
+use serenity::model::{MessageId, ModelError, RoleId, UserId};
+
+#[test]
+fn validate_collects_all_errors() {
+    let message = CreateMessage::default()
+        .content("a".repeat(2005))
+        .embed(|e| e.description("a".repeat(6010)).timestamp("yesterday"));
+
+    let errors = message.validate().unwrap_err();
+
+    assert_eq!(errors.len(), 4);
+    assert!(errors.contains(&ModelError::MessageTooLong(5)));
+    assert!(errors.contains(&ModelError::EmbedTooLarge {
+        field: "total",
+        limit: 6000,
+        actual: 6010,
+    }));
+    assert!(errors.contains(&ModelError::EmbedTooLarge {
+        field: "description",
+        limit: 4096,
+        actual: 6010,
+    }));
+    assert!(errors.contains(&ModelError::EmbedTimestampInvalid("yesterday".to_string())));
+}
+
+#[test]
+fn validate_valid_message() {
+    let message = CreateMessage::default()
+        .content("hello")
+        .embed(|e| e.title("title"));
+
+    assert!(message.validate().is_ok());
+}
+
+#[test]
+fn allowed_mentions_suppress_everything() {
+    let message = CreateMessage::default()
+        .allowed_mentions(|am| am.everyone(true).users(vec![UserId(1)]).empty());
+
+    assert_eq!(message.0["allowed_mentions"], json!({"parse": []}));
+}
+
+#[test]
+fn allowed_mentions_only_users() {
+    let message = CreateMessage::default()
+        .allowed_mentions(|am| am.users(vec![UserId(1), UserId(2)]));
+
+    assert_eq!(
+        message.0["allowed_mentions"],
+        json!({"parse": [], "users": ["1", "2"]})
+    );
+}
+
+#[test]
+fn allowed_mentions_everyone() {
+    let mentions = CreateAllowedMentions::default()
+        .everyone(true)
+        .roles(vec![RoleId(3)]);
+
+    assert_eq!(
+        Value::Object(mentions.0),
+        json!({"parse": ["everyone"], "roles": ["3"]})
+    );
+}
+
+#[test]
+fn reference_message() {
+    let message = CreateMessage::default()
+        .content("hello")
+        .reference_message(MessageId(7));
+
+    assert_eq!(message.0["message_reference"]["message_id"], json!(7));
+    assert!(message.0.get("allowed_mentions").is_none());
+}
+
+#[test]
+fn mention_reply_keeps_default_parsing() {
+    let message = CreateMessage::default()
+        .reference_message(MessageId(7))
+        .mention_reply(false);
+
+    assert_eq!(message.0["allowed_mentions"], json!({
+        "parse": ["everyone", "roles", "users"],
+        "replied_user": false
+    }));
+}
+
+#[test]
+fn mention_reply_with_allowed_mentions() {
+    let message = CreateMessage::default()
+        .reference_message(MessageId(7))
+        .mention_reply(true)
+        .allowed_mentions(|am| am.users(vec![UserId(8)]));
+
+    assert_eq!(message.0["allowed_mentions"], json!({
+        "parse": [],
+        "replied_user": true,
+        "users": ["8"]
+    }));
+}
+
+#[test]
+fn tts_and_nonce() {
+    let message = CreateMessage::default()
+        .content("hello")
+        .tts(true)
+        .nonce("a1b2");
+
+    assert_eq!(message.0["tts"], json!(true));
+    assert_eq!(message.0["nonce"], json!("a1b2"));
+}
+
+#[test]
+fn embed_appends() {
+    let message = CreateMessage::default()
+        .embed(|e| e.title("first"))
+        .embed(|e| e.title("second"));
+
+    assert_eq!(message.0["embeds"][0]["title"], json!("first"));
+    assert_eq!(message.0["embeds"][1]["title"], json!("second"));
+    assert!(message.0.get("embed").is_none());
+}
+
+#[test]
+fn embeds_replace_and_clear() {
+    let message = CreateMessage::default()
+        .embed(|e| e.title("first"))
+        .embeds(vec![CreateEmbed::default().title("second")]);
+
+    assert_eq!(message.0["embeds"].as_array().map(|embeds| embeds.len()), Some(1));
+
+    let message = message.embeds(vec![]);
+
+    assert_eq!(message.0["embeds"], json!([]));
+    assert!(message.validate().is_ok());
+}
+
+#[test]
+fn validate_counts_all_embeds() {
+    let message = CreateMessage::default()
+        .embed(|e| e.description("a".repeat(3000)))
+        .embed(|e| e.description("a".repeat(3005)));
+
+    let error = ModelError::EmbedTooLarge {
+        field: "total",
+        limit: 6000,
+        actual: 6005,
+    };
+
+    assert_eq!(message.validate().unwrap_err(), vec![error]);
+}
+
+#[test]
+fn validate_embed_part_limits() {
+    let cases = [
+        (CreateEmbed::default().author(|a| a.name(&"a".repeat(257))), "author name", 256),
+        (CreateEmbed::default().description("a".repeat(4097)), "description", 4096),
+        (CreateEmbed::default().field("a".repeat(257), "value", false), "field name", 256),
+        (CreateEmbed::default().field("name", "a".repeat(1025), false), "field value", 1024),
+        (CreateEmbed::default().footer(|f| f.text("a".repeat(2049))), "footer text", 2048),
+        (CreateEmbed::default().title("a".repeat(257)), "title", 256),
+    ];
+
+    for &(ref embed, part, limit) in &cases {
+        let message = CreateMessage::default().embeds(vec![embed.clone()]);
+        let error = ModelError::EmbedTooLarge {
+            field: part,
+            limit: limit,
+            actual: limit + 1,
+        };
+
+        assert_eq!(message.validate().unwrap_err(), vec![error]);
+    }
+}
+
+#[test]
+fn validate_embed_total_length() {
+    let message = CreateMessage::default().embed(|e| e
+        .title("a".repeat(256))
+        .description("a".repeat(4096))
+        .field("a".repeat(256), "a".repeat(1024), false)
+        .footer(|f| f.text("a".repeat(369))));
+
+    let error = ModelError::EmbedTooLarge {
+        field: "total",
+        limit: 6000,
+        actual: 6001,
+    };
+
+    assert_eq!(message.validate().unwrap_err(), vec![error]);
+}
+
+#[test]
+fn validate_file_count() {
+    let message = CreateMessage::default().content("some files");
+    assert!(message.validate_with_files(10).is_ok());
+    assert_eq!(message.validate_with_files(12).unwrap_err(), vec![ModelError::TooManyFiles(2)]);
+
+    let message = message.content("a".repeat(2001));
+    let errors = message.validate_with_files(11).unwrap_err();
+    assert_eq!(errors, vec![ModelError::MessageTooLong(1), ModelError::TooManyFiles(1)]);
+}
+
+#[test]
+fn validate_allowed_mentions_conflict() {
+    let message = CreateMessage::default()
+        .allowed_mentions(|am| am.everyone(true).users(vec![UserId(1)]).roles(vec![RoleId(2)]));
+    assert!(message.validate().is_ok());
+
+    let mut message = CreateMessage::default();
+    message.0.insert(
+        "allowed_mentions".to_string(),
+        json!({"parse": ["users", "roles"], "users": ["1"]}),
+    );
+    assert_eq!(
+        message.validate().unwrap_err(),
+        vec![ModelError::AllowedMentionsConflict("users")]
+    );
+
+    message.0.insert(
+        "allowed_mentions".to_string(),
+        json!({"parse": ["roles"], "roles": ["2"]}),
+    );
+    assert_eq!(
+        message.validate().unwrap_err(),
+        vec![ModelError::AllowedMentionsConflict("roles")]
+    );
+}
+
+#[test]
+fn content_line_appends() {
+    let message = CreateMessage::default()
+        .content_line("a")
+        .content_line("b");
+    assert_eq!(message.0["content"], json!("a\nb"));
+
+    let message = message.content("c").content_line("d");
+    assert_eq!(message.0["content"], json!("c\nd"));
+
+    let message = CreateMessage::default()
+        .content_line("a".repeat(1000))
+        .content_line("a".repeat(1000));
+    assert_eq!(message.validate().unwrap_err(), vec![ModelError::MessageTooLong(1)]);
+}
+
+#[test]
+fn validate_embed_icon_urls() {
+    let message = CreateMessage::default().embed(|e| e
+        .author(|a| a.name("a").icon_url("attachment://logo.png"))
+        .footer(|f| f.text("a").icon_url("https://example.com/logo.png")));
+    assert!(message.validate().is_ok());
+
+    let message = CreateMessage::default()
+        .embed(|e| e.author(|a| a.name("a").icon_url("javascript:alert(1)")));
+    assert_eq!(
+        message.validate().unwrap_err(),
+        vec![ModelError::InvalidUrl("javascript:alert(1)".to_string())]
+    );
+
+    let message = CreateMessage::default()
+        .embed(|e| e.footer(|f| f.text("a").icon_url("attachment://")));
+    assert_eq!(
+        message.validate().unwrap_err(),
+        vec![ModelError::InvalidUrl("attachment://".to_string())]
+    );
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "builder")]
+
+#[macro_use]
+extern crate serde_json;
+extern crate serenity;
+
+use serde_json::Value;
+use serenity::builder::ExecuteWebhook;
+
+#[test]
+fn embeds_are_appended() {
+    let builder = ExecuteWebhook::default()
+        .content("hello")
+        .username("hakase")
+        .embed(|e| e.title("first"))
+        .embed(|e| e.title("second"));
+
+    let embeds = json!([
+        {"title": "first", "type": "rich"},
+        {"title": "second", "type": "rich"}
+    ]);
+
+    assert_eq!(builder.0["embeds"], embeds);
+    assert_eq!(builder.0["username"], Value::String("hakase".to_string()));
+}
@@ -28,6 +28,7 @@ fn test_mention() {
         name: "a".to_string(),
         permission_overwrites: vec![],
         position: 1,
+        rate_limit_per_user: None,
         topic: None,
         user_limit: None,
         nsfw: false,
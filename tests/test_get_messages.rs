@@ -0,0 +1,55 @@
+#![cfg(feature = "builder")]
+
+extern crate serenity;
+
+use serenity::builder::GetMessages;
+use serenity::model::{MessageId, ModelError};
+
+#[test]
+fn most_recent_anchor_wins() {
+    let builder = GetMessages::default()
+        .before(MessageId(1))
+        .after(MessageId(2))
+        .around(MessageId(3))
+        .limit(150);
+
+    assert_eq!(builder.0.get("around"), Some(&3));
+    assert!(!builder.0.contains_key("after"));
+    assert!(!builder.0.contains_key("before"));
+    assert_eq!(builder.0.get("limit"), Some(&100));
+
+    let builder = builder.most_recent();
+
+    assert!(!builder.0.contains_key("around"));
+    assert_eq!(builder.0.get("limit"), Some(&100));
+}
+
+#[test]
+fn clear_anchor() {
+    let builder = GetMessages::default();
+    assert_eq!(builder.get_anchor(), None);
+    assert_eq!(builder.get_limit(), 50);
+
+    let builder = builder.around(MessageId(3)).limit(20);
+    assert_eq!(builder.get_anchor(), Some(("around", MessageId(3))));
+
+    let builder = builder.clear_anchor();
+    assert_eq!(builder.get_anchor(), None);
+    assert_eq!(builder.get_limit(), 20);
+}
+
+#[test]
+fn limit_checked() {
+    assert_eq!(GetMessages::default().get_limit(), 50);
+    assert_eq!(GetMessages::default().limit(150).get_limit(), 100);
+    assert_eq!(GetMessages::default().limit_checked(100).unwrap().get_limit(), 100);
+
+    assert_eq!(
+        GetMessages::default().limit_checked(0).unwrap_err(),
+        ModelError::MessageLimitAmount(0)
+    );
+    assert_eq!(
+        GetMessages::default().limit_checked(101).unwrap_err(),
+        ModelError::MessageLimitAmount(101)
+    );
+}
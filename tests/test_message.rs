@@ -1,3 +1,4 @@
+extern crate chrono;
 extern crate serde;
 extern crate serde_json;
 extern crate serenity;
@@ -16,6 +17,15 @@ macro_rules! p {
     })
 }
 
+// Deserializes the footer message with one of its fields replaced.
+fn message_with(key: &str, value: Value) -> Message {
+    let f = File::open("./tests/resources/message_footer_1.json").unwrap();
+    let mut v = serde_json::from_reader::<File, Value>(f).unwrap();
+    v.as_object_mut().unwrap().insert(key.to_owned(), value);
+
+    Message::deserialize(v).unwrap()
+}
+
 #[test]
 fn test_footer_deser() {
     let mut message = p!(Message, "message_footer_1");
@@ -27,3 +37,365 @@ fn test_footer_deser() {
 
     p!(Message, "message_footer_2");
 }
+
+#[cfg(feature = "model")]
+#[test]
+fn test_ephemeral_flags() {
+    let message = p!(Message, "message_footer_1");
+    assert!(!message.is_ephemeral());
+
+    let message = message_with("flags", Value::from((1u64 << 6) | (1u64 << 40)));
+    assert!(message.is_ephemeral());
+    assert_eq!(message.flags.unwrap().bits(), (1 << 6) | (1 << 40));
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_link() {
+    use serenity::model::GuildId;
+
+    let mut message = p!(Message, "message_footer_1");
+    message.guild_id = None;
+    assert_eq!(
+        message.link(),
+        format!("https://discord.com/channels/@me/{}/{}", message.channel_id, message.id)
+    );
+
+    message.guild_id = Some(GuildId(1));
+    assert_eq!(
+        message.link(),
+        format!("https://discord.com/channels/1/{}/{}", message.channel_id, message.id)
+    );
+}
+
+#[test]
+fn test_flags_round_trip() {
+    use serenity::model::MessageFlags;
+
+    let flags = message_with("flags", Value::from(2u64 | 4 | (1 << 30))).flags.unwrap();
+    assert!(flags.contains(MessageFlags::IS_CROSSPOST | MessageFlags::SUPPRESS_EMBEDS));
+    assert!(!flags.contains(MessageFlags::CROSSPOSTED));
+    assert_eq!(serde_json::to_value(&flags).unwrap(), Value::from(2u64 | 4 | (1 << 30)));
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_reaction_folding() {
+    use serenity::model::{EmojiId, ReactionType};
+
+    let mut message = p!(Message, "message_footer_1");
+    message.reactions.clear();
+
+    let custom = |name: &str| ReactionType::Custom {
+        animated: false,
+        id: EmojiId(1),
+        name: Some(name.to_string()),
+    };
+
+    message.apply_reaction_add('🍎', true);
+    message.apply_reaction_add('🍎', false);
+    message.apply_reaction_add(custom("a"), false);
+    message.apply_reaction_add(custom("b"), false);
+    assert_eq!(message.reactions.len(), 2);
+    assert_eq!(message.reactions[0].count, 2);
+    assert!(message.reactions[0].me);
+    assert_eq!(message.reactions[1].count, 2);
+
+    message.apply_reaction_remove('🍎', true);
+    assert_eq!(message.reactions[0].count, 1);
+    assert!(!message.reactions[0].me);
+
+    message.apply_reaction_remove('🍎', false);
+    assert_eq!(message.reactions.len(), 1);
+
+    message.clear_reaction_emoji(custom("c"));
+    assert!(message.reactions.is_empty());
+
+    message.apply_reaction_add('🍎', false);
+    message.clear_reactions();
+    assert!(message.reactions.is_empty());
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_reaction_count() {
+    use serenity::model::{EmojiId, ReactionType};
+
+    let mut message = p!(Message, "message_footer_1");
+    message.reactions.clear();
+
+    message.apply_reaction_add('✅', false);
+    message.apply_reaction_add('✅', false);
+    message.apply_reaction_add(ReactionType::custom(false, EmojiId(1), Some("a".to_string())), false);
+
+    assert_eq!(message.reaction_count('✅'), Some(2));
+    assert_eq!(message.reaction_count('❎'), None);
+    assert!(message.reacted_with(ReactionType::custom(true, EmojiId(1), None)));
+    assert!(!message.reacted_with(ReactionType::custom(false, EmojiId(2), None)));
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_reaction_map() {
+    use serenity::model::{EmojiId, ReactionType};
+
+    let mut message = p!(Message, "message_footer_1");
+    message.reactions.clear();
+
+    message.apply_reaction_add('✅', false);
+    message.apply_reaction_add('✅', false);
+    let custom = ReactionType::custom(false, EmojiId(1), Some("a".to_string()));
+    message.apply_reaction_add(custom, false);
+
+    let map = message.reaction_map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&ReactionType::from('✅')), Some(&2));
+    assert_eq!(map.get(&ReactionType::custom(false, EmojiId(1), None)), Some(&1));
+    assert_eq!(map.get(&ReactionType::from('❎')), None);
+}
+
+#[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
+#[test]
+fn test_content_safe_unresolved() {
+    use serenity::model::RoleId;
+
+    let mut message = p!(Message, "message_footer_1");
+    message.mentions.clear();
+    message.mention_roles = vec![RoleId(7)];
+    message.content = "<@&7> see <#5> and <#x>, @everyone".to_string();
+
+    assert_eq!(
+        message.content_safe(),
+        "@7 see #5 and <#x>, @\u{200B}everyone"
+    );
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_mentions_user() {
+    use serenity::model::UserId;
+
+    let mut message = p!(Message, "message_footer_1");
+    message.mentions.clear();
+    assert!(!message.mentions_user(message.author.id));
+
+    let author = message.author.clone();
+    message.mentions.push(author);
+    assert!(message.mentions_user(message.author.id));
+    assert!(!message.mentions_user(UserId(message.author.id.0 + 1)));
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_is_pinned() {
+    let mut message = p!(Message, "message_footer_1");
+
+    message.pinned = true;
+    assert!(message.is_pinned());
+
+    message.pinned = false;
+    assert!(!message.is_pinned());
+}
+
+#[test]
+fn test_channel_id_conversion() {
+    use serenity::model::ChannelId;
+
+    let message = p!(Message, "message_footer_1");
+    assert_ne!(message.channel_id.0, message.id.0);
+    assert_eq!(ChannelId::from(&message), message.channel_id);
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_edited_at() {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    let mut message = p!(Message, "message_footer_1");
+
+    message.edited_timestamp = None;
+    assert!(!message.was_edited());
+    assert_eq!(message.edited_at(), None);
+
+    let edited = DateTime::parse_from_rfc3339("2017-10-20T12:00:00+02:00").unwrap();
+    message.edited_timestamp = Some(edited);
+    assert!(message.was_edited());
+    assert_eq!(message.edited_at(), Some(Utc.ymd(2017, 10, 20).and_hms(10, 0, 0).naive_utc()));
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_mentioned_ids() {
+    use serenity::model::{ChannelId, RoleId, UserId};
+
+    let mut message = p!(Message, "message_footer_1");
+    message.content = "<@1> <@!2> <@1> <@&3> <#4> <#x> <@+5> <@&> <#4> <@6".to_string();
+
+    assert_eq!(message.mentioned_user_ids(), vec![UserId(1), UserId(2)]);
+    assert_eq!(message.mentioned_role_ids(), vec![RoleId(3)]);
+    assert_eq!(message.mentioned_channel_ids(), vec![ChannelId(4)]);
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_message_reference() {
+    use serenity::model::{ChannelId, MessageId};
+
+    let message = p!(Message, "message_footer_1");
+    assert!(message.message_reference.is_none());
+    assert!(message.referenced_message().unwrap().is_none());
+
+    let reference = r#"{"channel_id": "1", "message_id": "2"}"#;
+    let reference = serde_json::from_str::<Value>(reference).unwrap();
+    let message = message_with("message_reference", reference);
+    let reference = message.message_reference.unwrap();
+    assert_eq!(reference.channel_id, Some(ChannelId(1)));
+    assert_eq!(reference.message_id, Some(MessageId(2)));
+    assert_eq!(reference.guild_id, None);
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_system_message_kind() {
+    use serenity::model::MessageType;
+
+    let message = p!(Message, "message_footer_1");
+    assert_eq!(message.kind(), MessageType::Regular);
+    assert!(!message.is_system());
+
+    let message = message_with("type", Value::from(6));
+    assert_eq!(message.kind(), MessageType::PinsAdd);
+    assert!(message.is_system());
+
+    let message = message_with("type", Value::from(8));
+    assert_eq!(message.kind(), MessageType::NitroBoost);
+    assert!(message.kind().is_deletable());
+}
+
+#[test]
+fn test_components_deser() {
+    use serenity::model::{ActionRowComponent, ButtonStyle};
+
+    let message = p!(Message, "message_footer_1");
+    assert!(message.components.is_empty());
+
+    let components = r#"[{
+        "type": 1,
+        "components": [
+            {"type": 2, "style": 1, "label": "Accept", "custom_id": "accept"},
+            {"type": 2, "style": 5, "label": "Docs", "url": "https://example.com",
+             "emoji": {"id": null, "name": "📖"}}
+        ]
+    }]"#;
+    let components = serde_json::from_str::<Value>(components).unwrap();
+
+    let mut message = message_with("components", components);
+    assert_eq!(message.components.len(), 1);
+
+    let mut buttons = message.components.remove(0).components.into_iter();
+
+    match buttons.next() {
+        Some(ActionRowComponent::Button(button)) => {
+            assert_eq!(button.custom_id, Some("accept".to_string()));
+            assert_eq!(button.label, Some("Accept".to_string()));
+            assert_eq!(button.style, ButtonStyle::Primary);
+            assert_eq!(button.url, None);
+        },
+        other => panic!("Expected a button: {:?}", other),
+    }
+
+    match buttons.next() {
+        Some(ActionRowComponent::Button(button)) => {
+            assert_eq!(button.custom_id, None);
+            assert_eq!(button.style, ButtonStyle::Link);
+            assert_eq!(button.url, Some("https://example.com".to_string()));
+            assert!(button.emoji.is_some());
+        },
+        other => panic!("Expected a button: {:?}", other),
+    }
+
+    assert!(buttons.next().is_none());
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_attachment_filters() {
+    let attachments = r#"[
+        {"id": "1", "filename": "report.CSV", "size": 1, "url": "a", "proxy_url": "a"},
+        {"id": "2", "filename": "cat.png", "size": 1, "url": "b", "proxy_url": "b",
+         "width": 2, "height": 3},
+        {"id": "3", "filename": "backup.tar.gz", "size": 1, "url": "c", "proxy_url": "c"}
+    ]"#;
+    let attachments = serde_json::from_str::<Value>(attachments).unwrap();
+
+    let message = message_with("attachments", attachments);
+
+    let csvs = message.attachments_with_extension("csv");
+    assert_eq!(csvs.len(), 1);
+    assert_eq!(csvs[0].filename, "report.CSV");
+
+    assert_eq!(message.attachments_with_extension(".tar.gz").len(), 1);
+    assert!(message.attachments_with_extension("gif").is_empty());
+
+    let images = message.image_attachments();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].filename, "cat.png");
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn test_apply_suppress_embeds() {
+    use serenity::model::MessageFlags;
+
+    let mut message = p!(Message, "message_footer_1");
+    message.flags = Some(MessageFlags::CROSSPOSTED);
+    assert!(!message.embeds.is_empty());
+
+    message.apply_suppress_embeds(true);
+    assert_eq!(
+        message.flags,
+        Some(MessageFlags::CROSSPOSTED | MessageFlags::SUPPRESS_EMBEDS)
+    );
+    assert!(message.embeds.is_empty());
+
+    message.apply_suppress_embeds(false);
+    assert_eq!(message.flags, Some(MessageFlags::CROSSPOSTED));
+    assert!(message.embeds.is_empty());
+
+    message.flags = None;
+    message.apply_suppress_embeds(false);
+    assert_eq!(message.flags, Some(MessageFlags::empty()));
+}
+
+#[cfg(all(feature = "cache", feature = "model"))]
+#[test]
+fn test_resolved_mentions_cold_cache() {
+    use serenity::model::{ChannelId, RoleId, UserId};
+
+    let mut message = p!(Message, "message_footer_1");
+    message.guild_id = None;
+    message.content = "<@71> <@&72> <#73> <@71>".to_string();
+
+    let resolved = message.resolved_mentions();
+    assert_eq!(
+        resolved.users,
+        vec![(UserId(71), "71".to_string())]
+    );
+    assert_eq!(
+        resolved.roles,
+        vec![(RoleId(72), "72".to_string())]
+    );
+    assert_eq!(
+        resolved.channels,
+        vec![(ChannelId(73), "73".to_string())]
+    );
+
+    let author = message.author.clone();
+    message.content = format!("<@!{}>", author.id.0);
+    message.mentions = vec![author.clone()];
+
+    let resolved = message.resolved_mentions();
+    assert_eq!(resolved.users, vec![(author.id, author.name)]);
+    assert!(resolved.roles.is_empty());
+    assert!(resolved.channels.is_empty());
+}
@@ -0,0 +1,308 @@
+#![cfg_attr(feature = "cargo-clippy", allow(unreadable_literal))]
+
+extern crate serde;
+#[macro_use]
+extern crate serde_json;
+extern crate serenity;
+
+use serde::de::Deserialize;
+use serenity::model::{EmojiId, MessageReaction, ReactionTarget, ReactionType, UserId};
+
+#[cfg(feature = "model")]
+#[test]
+fn animated_custom_data() {
+    let reaction = ReactionType::deserialize(json!({
+        "animated": true,
+        "id": "302233550662713344",
+        "name": "ferris",
+    })).unwrap();
+
+    assert_eq!(
+        reaction,
+        ReactionType::Custom {
+            animated: true,
+            id: EmojiId(302233550662713344),
+            name: Some("ferris".to_string()),
+        }
+    );
+    assert_eq!(reaction.as_data(), "ferris:302233550662713344");
+    assert_eq!(reaction.as_url_encoded(), "ferris:302233550662713344");
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn static_custom_data() {
+    let reaction = ReactionType::Custom {
+        animated: false,
+        id: EmojiId(302233550662713344),
+        name: Some("ferris".to_string()),
+    };
+
+    assert_eq!(reaction.as_data(), "ferris:302233550662713344");
+    assert_eq!(reaction.as_url_encoded(), "ferris:302233550662713344");
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn unicode_flag_data() {
+    let reaction = ReactionType::from("🇳🇿");
+
+    assert_eq!(reaction.as_data(), "🇳🇿");
+    assert_eq!(reaction.as_url_encoded(), "%F0%9F%87%B3%F0%9F%87%BF");
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn write_url_encoded_matches() {
+    let reactions = vec![
+        ReactionType::from("🇳🇿"),
+        ReactionType::from("❤️"),
+        ReactionType::Custom {
+            animated: false,
+            id: EmojiId(302233550662713344),
+            name: Some("ferris".to_string()),
+        },
+        ReactionType::Custom {
+            animated: true,
+            id: EmojiId(7),
+            name: None,
+        },
+    ];
+
+    let mut buffer = String::new();
+
+    for reaction in &reactions {
+        buffer.clear();
+        reaction.write_url_encoded(&mut buffer).unwrap();
+
+        assert_eq!(buffer, reaction.as_url_encoded());
+    }
+
+    assert_eq!(buffer, ":7");
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn data_and_display_forms() {
+    let reaction = ReactionType::Custom {
+        animated: false,
+        id: EmojiId(302233550662713344),
+        name: Some("ferris".to_string()),
+    };
+
+    assert_eq!(reaction.to_string(), "<:ferris:302233550662713344>");
+    assert_eq!(String::from(reaction), "ferris:302233550662713344");
+}
+
+#[test]
+fn display_forms() {
+    let animated = ReactionType::Custom {
+        animated: true,
+        id: EmojiId(302233550662713344),
+        name: Some("ferris".to_string()),
+    };
+    assert_eq!(animated.to_string(), "<a:ferris:302233550662713344>");
+
+    let unicode = ReactionType::Unicode("👍".to_string());
+    assert_eq!(format!("React with {} to vote", unicode), "React with 👍 to vote");
+}
+
+#[test]
+fn parse_round_trip() {
+    for token in &["<:ferris:302233550662713344>", "<a:ferris:302233550662713344>", "👍"] {
+        let reaction = token.parse::<ReactionType>().unwrap();
+        assert_eq!(reaction.to_string(), *token);
+    }
+
+    assert_eq!(
+        "<:ferris:302233550662713344>".parse::<ReactionType>(),
+        Ok(ReactionType::Custom {
+            animated: false,
+            id: EmojiId(302233550662713344),
+            name: Some("ferris".to_string()),
+        })
+    );
+}
+
+#[test]
+fn parse_malformed() {
+    let tokens = [
+        "",
+        "ferris",
+        "<:ferris:>",
+        "<::1>",
+        "<:ferris:1",
+        "<b:ferris:1>",
+        "<:a:b>",
+        "👍 👍",
+    ];
+
+    for token in &tokens {
+        assert!(token.parse::<ReactionType>().is_err(), "{:?} parsed", token);
+    }
+}
+
+#[test]
+fn reaction_target_user_id() {
+    assert_eq!(ReactionTarget::Me.user_id(), None);
+    assert_eq!(ReactionTarget::from(UserId(7)).user_id(), Some(UserId(7)));
+}
+
+#[test]
+fn constructors() {
+    assert_eq!(
+        ReactionType::custom(true, EmojiId(1), Some("a".to_string())),
+        ReactionType::Custom {
+            animated: true,
+            id: EmojiId(1),
+            name: Some("a".to_string()),
+        }
+    );
+    assert_eq!(ReactionType::unicode("👍"), ReactionType::Unicode("👍".to_string()));
+}
+
+#[test]
+fn hash_by_identity() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(reaction_type: &ReactionType) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        reaction_type.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    let a = ReactionType::custom(false, EmojiId(1), Some("a".to_string()));
+    let b = ReactionType::custom(true, EmojiId(1), Some("b".to_string()));
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+
+    let other = ReactionType::custom(false, EmojiId(2), Some("a".to_string()));
+    assert_ne!(a, other);
+
+    let plain = ReactionType::unicode("\u{2764}");
+    let selected = ReactionType::unicode("\u{2764}\u{FE0F}");
+    assert_eq!(plain, selected);
+    assert_eq!(hash(&plain), hash(&selected));
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn reaction_is_emoji() {
+    use serenity::model::{ChannelId, MessageId, Reaction};
+
+    let mut reaction = Reaction {
+        channel_id: ChannelId(1),
+        emoji: ReactionType::Custom {
+            animated: false,
+            id: EmojiId(302233550662713344),
+            name: Some("ferris".to_string()),
+        },
+        message_id: MessageId(2),
+        user_id: UserId(3),
+    };
+
+    assert!(reaction.is_emoji(ReactionType::Custom {
+        animated: true,
+        id: EmojiId(302233550662713344),
+        name: Some("old_ferris".to_string()),
+    }));
+    assert!(reaction.is_emoji(ReactionType::custom(false, EmojiId(302233550662713344), None)));
+    let name = Some("ferris".to_string());
+    assert!(!reaction.is_emoji(ReactionType::custom(false, EmojiId(1), name)));
+    assert!(!reaction.is_emoji("ferris"));
+
+    reaction.emoji = ReactionType::from("👍");
+    assert!(reaction.is_emoji("👍"));
+    assert!(reaction.is_emoji('👍'));
+    assert!(!reaction.is_emoji("👎"));
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn reaction_variation_selectors() {
+    use serenity::model::{ChannelId, MessageId, Reaction};
+
+    let mut reaction = Reaction {
+        channel_id: ChannelId(1),
+        emoji: ReactionType::from("\u{2764}\u{FE0F}"),
+        message_id: MessageId(2),
+        user_id: UserId(3),
+    };
+
+    assert!(reaction.is_emoji("\u{2764}"));
+    assert!(reaction.is_emoji('\u{2764}'));
+    assert!(reaction.is_emoji("\u{2764}\u{FE0F}"));
+    assert!(!reaction.is_emoji("\u{1F494}"));
+
+    // The variation selector is sent as given.
+    assert_eq!(reaction.emoji.as_url_encoded(), "%E2%9D%A4%EF%B8%8F");
+
+    // Skin tone modifiers are kept, and distinguish emojis.
+    reaction.emoji = ReactionType::from("\u{1F44D}\u{1F3FD}");
+    assert!(reaction.is_emoji("\u{1F44D}\u{1F3FD}"));
+    assert!(!reaction.is_emoji("\u{1F44D}"));
+    assert_eq!(reaction.emoji.as_data(), "\u{1F44D}\u{1F3FD}");
+    assert_eq!(reaction.emoji.as_url_encoded(), "%F0%9F%91%8D%F0%9F%8F%BD");
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn unicode_normalized_when_sent() {
+    // A heart is sent the same with or without its variation selector.
+    let heart = "\u{2764}\u{FE0F}";
+    assert_eq!(ReactionType::from("\u{2764}").as_data(), heart);
+    assert_eq!(ReactionType::from('\u{2764}').as_data(), heart);
+    assert_eq!(ReactionType::from("\u{2764}\u{FE0E}").as_data(), heart);
+    assert_eq!(ReactionType::from("\u{2764}").as_url_encoded(), "%E2%9D%A4%EF%B8%8F");
+
+    // Symbols displayed as emojis by default are left alone.
+    assert_eq!(ReactionType::from("\u{2705}").as_data(), "\u{2705}");
+    assert_eq!(ReactionType::from("\u{1F34E}").as_data(), "\u{1F34E}");
+
+    // Skin tone modifiers directly follow the symbol they modify.
+    let victory = ReactionType::from("\u{270C}\u{FE0F}\u{1F3FD}");
+    assert_eq!(victory.as_data(), "\u{270C}\u{1F3FD}");
+    assert_eq!(victory.as_url_encoded(), "%E2%9C%8C%F0%9F%8F%BD");
+
+    // Joined sequences are sent as given.
+    let fire_heart = "\u{2764}\u{FE0F}\u{200D}\u{1F525}";
+    assert_eq!(ReactionType::from(fire_heart).as_data(), fire_heart);
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn message_reaction_deser() {
+    let reaction = MessageReaction::deserialize(json!({
+        "count": 3,
+        "me": true,
+        "emoji": {
+            "animated": true,
+            "id": "302233550662713344",
+            "name": "ferris",
+        },
+    })).unwrap();
+
+    assert_eq!(reaction.count, 3);
+    assert!(reaction.me);
+    assert_eq!(
+        reaction.reaction_type,
+        ReactionType::custom(true, EmojiId(302233550662713344), Some("ferris".to_string()))
+    );
+    assert!(reaction.is_emoji(ReactionType::custom(false, EmojiId(302233550662713344), None)));
+    assert!(!reaction.is_emoji("🦀"));
+}
+
+#[cfg(feature = "model")]
+#[test]
+fn reaction_users_limit() {
+    use serenity::constants::{REACTION_USERS_DEFAULT_LIMIT, REACTION_USERS_MAX_LIMIT};
+    use serenity::model::Reaction;
+
+    assert_eq!(Reaction::users_limit(None), REACTION_USERS_DEFAULT_LIMIT);
+    assert_eq!(Reaction::users_limit(Some(1)), 1);
+    assert_eq!(Reaction::users_limit(Some(100)), 100);
+    assert_eq!(Reaction::users_limit(Some(200)), REACTION_USERS_MAX_LIMIT);
+    assert_eq!(REACTION_USERS_MAX_LIMIT, 100);
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(feature = "cargo-clippy", allow(unreadable_literal))]
+
+extern crate chrono;
+extern crate serenity;
+
+use chrono::NaiveDate;
+use serenity::model::{ChannelId, MessageId, UserId};
+
+#[test]
+fn created_at() {
+    let expected = NaiveDate::from_ymd(2016, 4, 30).and_hms_milli(11, 18, 25, 796);
+
+    assert_eq!(MessageId(175928847299117063).created_at(), expected);
+    assert_eq!(ChannelId(175928847299117063).created_at(), expected);
+    assert_eq!(UserId(175928847299117063).created_at(), expected);
+}